@@ -0,0 +1,45 @@
+// So sánh chi phí lấy mẫu: refresh_all() (cách cũ) với refresh chỉ RAM/CPU theo ProcessRefreshKind
+// Chạy: cargo bench --bench sampling
+
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, ProcessRefreshKind, System};
+
+const ITERATIONS: u32 = 50;
+
+fn measure(name: &str, mut sample: impl FnMut()) -> Duration {
+    // Lượt đầu để khởi tạo cache của sysinfo, không tính
+    sample();
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        sample();
+    }
+    let per_sample = start.elapsed() / ITERATIONS;
+    println!("{:<40} {:>10.3} ms / sample", name, per_sample.as_secs_f64() * 1000.0);
+    per_sample
+}
+
+fn main() {
+    let kind = ProcessRefreshKind::new().with_memory().with_cpu();
+
+    let mut system = System::new_all();
+    println!("Số tiến trình: {}\n", system.processes().len());
+
+    let full = measure("refresh_all", || system.refresh_all());
+
+    let mut system = System::new();
+    let list = measure("refresh_processes_specifics (RAM+CPU)", || {
+        system.refresh_memory();
+        system.refresh_processes_specifics(kind);
+    });
+
+    let pids: Vec<Pid> = system.processes().keys().copied().collect();
+    let targeted = measure("refresh_pids_specifics (PID đã biết)", || {
+        system.refresh_memory();
+        system.refresh_pids_specifics(&pids, kind);
+    });
+
+    println!();
+    println!("Quét danh sách nhanh hơn refresh_all: {:.1}x", full.as_secs_f64() / list.as_secs_f64());
+    println!("Refresh theo PID nhanh hơn refresh_all: {:.1}x", full.as_secs_f64() / targeted.as_secs_f64());
+}
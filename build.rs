@@ -20,13 +20,13 @@ fn main() {
         res.set("ProductVersion", "1.0.0");
         res.set("FileVersion", "1.0.0");
         
-        // Require admin privileges
+        // Không tự xin quyền admin: các thao tác cần quyền có thể chạy qua helper (--helper)
         res.set_manifest(r#"
 <assembly xmlns="urn:schemas-microsoft-com:asm.v1" manifestVersion="1.0">
   <trustInfo xmlns="urn:schemas-microsoft-com:asm.v3">
     <security>
       <requestedPrivileges>
-        <requestedExecutionLevel level="requireAdministrator" uiAccess="false" />
+        <requestedExecutionLevel level="asInvoker" uiAccess="false" />
       </requestedPrivileges>
     </security>
   </trustInfo>
@@ -0,0 +1,582 @@
+# Process status
+status-normal = Normal
+status-pinned = Pinned
+status-trimmed = Trimmed
+status-limited = Limited
+status-high-priority = High priority
+status-frozen = Frozen
+status-launched = Launched with limits
+status-swap-limited = Swap limited
+
+# Actions
+action-pin = Pin
+action-trim = Trim
+action-limit = Limit
+action-swap-limit = Swap limit
+action-freeze = Freeze
+action-hibernate = Hibernate
+action-restore = Restore
+
+# Windows API errors
+err-open-process = Cannot open process: { $error }
+err-set-working-set = Cannot set working set: { $error }
+err-set-priority = Cannot set priority: { $error }
+err-trim-working-set = Cannot trim working set: { $error }
+err-trim-unsupported = On Linux, Trim uses process_madvise (Pageout) instead of EmptyWorkingSet
+err-limit-working-set = Cannot limit working set: { $error }
+err-reset-working-set = Cannot reset working set: { $error }
+err-memory-info = Cannot read memory information: { $error }
+err-process-gone = Process is no longer running
+
+# Action results
+msg-pinned = ✅ Pinned PID { $pid } to RAM ({ $mb } MB)
+msg-trimmed =
+    ✅ Trimmed PID { $pid }
+    📉 Before: { $before } MB → After: { $after } MB
+    💾 Freed: { $freed } MB
+msg-limited = ✅ Limited PID { $pid } (Max: { $mb } MB, Priority: IDLE)
+msg-limited-hard = ✅ Hard-capped PID { $pid } at { $mb } MB (on breach: { $breach })
+msg-cgroup-applied = ✅ { $action } applied to cgroup { $cgroup }
+msg-cgroup-restored = ✅ Restored the original limits of cgroup { $cgroup }
+msg-cgroup-trimmed = ✅ Trimmed cgroup { $cgroup }: { $before } → { $after }
+msg-swap-limited = ✅ Set swap limit for PID { $pid }: swap { $swap }, zswap { $zswap }
+msg-restored = ✅ Restored PID { $pid } to normal
+msg-frozen = 🧊 Froze PID { $pid }
+msg-launched = 🚀 Launched "{ $command }" (PID { $pid })
+msg-hibernated =
+    🧊 Froze PID { $pid }, then trimmed it
+    { $message }
+msg-priority-set = ✅ Set priority of PID { $pid } to { $priority }
+msg-with-priority = { $message } (priority { $priority })
+msg-restored-profile = ✅ Restored PID { $pid } to its state before the profile
+msg-action-failed-all = { $action } failed for all { $count ->
+        [one] { $count } process
+       *[other] { $count } processes
+    }
+msg-action-summary = ✅ { $action }: { $succeeded }/{ $total } processes succeeded
+msg-errors = ⚠️ Errors:
+group-summary =
+    { $icon } { $action } group { $group } ({ $succeeded }/{ $total } processes)
+    📉 Before: { $before } MB → After: { $after } MB
+    💾 Freed: { $freed } MB
+batch-summary = { $icon } { $action }: { $succeeded } succeeded, { $failed } failed / { $total } processes
+
+# Profiles
+profile-event-applied = Applied
+profile-event-reverted = Reverted
+profile-summary = { $icon } { $event } profile "{ $profile }": { $succeeded } processes succeeded, { $failed } failed
+profile-gaming-desc = Push browser/chat to the background; add a pin rule with your game's executable name
+profile-compile-desc = Prioritize the IDE, soft-limit dockerd
+profile-meeting-desc = Keep Teams/Zoom smooth, trim background apps
+err-no-active-profile = No profile is active
+err-profile-not-found = Profile "{ $profile }" not found
+err-cli-args = Invalid arguments: { $args }. Usage: --profile <name> | --revert-profile
+err-serialize-session = Cannot save profile session: { $error }
+err-serialize-effects = Cannot export effect reports: { $error }
+
+# Files
+err-read-file = Cannot read { $path }: { $error }
+err-syntax = Syntax error in { $path }: { $error }
+err-invalid-config = Invalid configuration in { $path }: { $error }
+err-create-dir = Cannot create directory { $path }: { $error }
+err-cgroup-v2 = PID { $pid } has no cgroup v2 (no 0:: line in /proc/{ $pid }/cgroup)
+err-cgroup-no-memory-controller = The memory controller is not enabled for child cgroups of { $path }
+err-size-too-large = { $mb } MB exceeds the largest representable memory size
+err-cgroup-tree-v2 = { $path } is not a cgroup v2 hierarchy
+err-cgroup-tree-unsupported = The cgroup tree is only available on Linux
+err-cgroup-path = { $path } is not a cgroup below the root cgroup
+err-cgroup-action-unsupported = { $action } cannot be applied to a whole cgroup
+err-cgroup-nothing-saved = Nothing to restore: no limits of cgroup { $cgroup } were changed from the cgroup tree
+err-cgroup-restore-value = Refused to restore { $file } = "{ $value }"
+err-swap-control-missing = { $path } has no { $file } (swap accounting is off or the kernel is too old)
+err-swap-limit-unsupported = Per-process swap limits are only available on Linux (cgroup v2)
+err-job-object = Job object error: { $error }
+err-remove-dir = Cannot remove directory { $path }: { $error }
+err-write-file = Cannot write { $path }: { $error }
+err-delete-file = Cannot delete { $path }: { $error }
+err-serialize-config = Cannot serialize config: { $error }
+
+# config.toml validation
+cfg-pin-range = sliders.pin_min_mb ({ $min }) must be > 0 and less than sliders.pin_max_mb ({ $max })
+cfg-limit-range = sliders.limit_min_mb ({ $min }) must be > 0 and less than sliders.limit_max_mb ({ $max })
+cfg-pin-default = actions.pin_working_set_mb ({ $value }) must be within { $min }..={ $max }
+cfg-limit-default = actions.limit_max_ws_mb ({ $value }) must be within { $min }..={ $max }
+cfg-refresh-interval = sampling.refresh_interval_secs ({ $value }) must be within { $min }..={ $max } seconds
+cfg-full-refresh = sampling.full_refresh_interval_secs ({ $value }) must be between refresh_interval_secs ({ $min }) and { $max }
+cfg-thresholds = expected 0 < thresholds.ram_warning_percent ({ $warning }) < ram_critical_percent ({ $critical }) <= 100
+cfg-thrashing = thresholds.thrashing_major_faults_per_sec ({ $major }) and thrashing_faults_per_sec ({ $total }) must be > 0
+cfg-effects = effects.window_secs ({ $window }) must be between sample_interval_secs ({ $interval }) and { $max }, sample_interval_secs must be >= { $min }
+cfg-alert-cooldown = alerts: cooldown_secs ({ $value }) must be between 0 and { $max }
+cfg-alert-webhook = alerts.webhook_url "{ $url }" is invalid: { $error }
+cfg-alert-name-empty = Alert rule #{ $index } has no name
+cfg-alert-duplicate = Duplicate alert rule name "{ $rule }"
+cfg-alert-threshold = Invalid threshold for alert rule "{ $rule }": { $value }
+cfg-alert-target = Alert rule "{ $rule }" is system-wide and cannot have a target
+cfg-profile-name-empty = profiles[{ $index }].name must not be empty
+cfg-profile-duplicate = profile "{ $profile }" is declared more than once
+cfg-ui-scale = ui.scale ({ $value }) must be within { $min }..={ $max }
+cfg-profile-no-rules = profile "{ $profile }" has no rules
+
+# Administrator warning
+admin-required = This tool needs Administrator rights!
+admin-please = Please:
+admin-step-close = 1. Close the tool
+admin-step-run-as = 2. Right click - Run as Administrator
+
+# Top bar / status bar
+status-ready = 🟢 Ready - Tool is running
+status-config-fallback = ❌ { $error } - using default configuration
+status-config-saved = ✅ Saved configuration to { $path }
+status-refreshed = ✅ Process list refreshed
+top-processes = 📊 Processes: { $count }
+top-auto = 🔄 Auto
+top-interval-hint = RAM/CPU sampling interval
+top-refresh-hint = Refresh now
+top-theme-hint = Switch theme
+top-stats-hint = Statistics
+top-save-hint = Save configuration to { $path }
+top-display-hint = Font and UI scale
+top-language-hint = Language
+language-auto = Automatic ({ $language })
+
+# Font and UI scale
+display-heading = 🔤 Display
+display-font = Font
+font-dejavu = DejaVu Sans (bundled, full Vietnamese coverage)
+font-egui-default = egui default font
+font-file = From file:
+font-file-hint = Path to a .ttf/.otf file, e.g. C:\Windows\Fonts\segoeui.ttf
+font-apply = Apply
+display-scale = UI scale
+status-font-applied = ✅ Font changed
+bottom-admin = ⚠️ Run as Administrator
+
+# Process list
+list-heading = 📋 Processes
+list-filter-hint = Search by name or PID
+list-clear-filter = Clear filter
+list-sort = Sort:
+sort-name = 📝 Name
+sort-status = 🏷️ Status
+list-view = View:
+view-list = 📄 List
+view-tree = 🌳 Process tree
+view-group = 📦 By application
+view-cgroup = 🗂️ Cgroup
+stats-heading = 📊 Statistics
+stats-pinned = 📌 Pinned: { $count }
+stats-trimmed = 🗜️ Trimmed: { $count }
+stats-limited = ⚠️ Limited: { $count }
+stats-frozen = 🧊 Frozen: { $count }
+stats-launched = 🚀 Launched: { $count }
+select-all = ☑️ Select all
+select-all-hint = Select every visible process
+select-none = ⬜ Clear selection
+selected-count = Selected: { $count }
+selection-hint = Ctrl+click: add/remove, Shift+click: select a range
+status-selected-one = 🎯 Selected: { $name } (PID: { $pid })
+status-selected-many = 🎯 Selected { $count } processes
+status-selected-group = 🎯 Selected group: { $group } ({ $count ->
+        [one] { $count } process
+       *[other] { $count } processes
+    })
+col-name = Process name
+col-status = Status
+col-result = Result
+col-before = Before (MB)
+col-after = After (MB)
+col-faults = Faults/s
+col-faults-hint = Page faults per second: major faults (read from disk) on Linux, all page faults on Windows
+list-showing = 📊 Showing { $shown } / { $total } processes
+process-count = { $count ->
+        [one] { $count } process
+       *[other] { $count } processes
+    }
+tree-subtree-summary = 💾 { $mb } MB  ⚙️ { $cpu }%  ({ $count } processes, own: { $own } MB)
+
+# Control panel
+panel-heading = ⚙️ Control panel
+selected-process = Selected process:
+status-label = 🏷️ Status:
+subtree-apply = 🌳 Apply to the whole subtree ({ $count } processes, { $mb } MB, CPU { $cpu }%)
+process-faults = 📄 Page faults: { $total }/s (major: { $major }/s)
+fault-history-hint = Last { $samples } samples, peak { $max }/s
+thrashing-hint = Thrashing: the process is limited but keeps faulting pages back in
+thrashing-warning = 🔥 Thrashing after the limit — raise the limit or restore the process
+pin-title = 🔒 1. Keep in physical RAM
+pin-desc = Raise working set and priority to keep the process in physical memory
+pin-button = 🔒 Pin to RAM
+trim-title = 🗜️ 2. Shrink working set / Push to background
+trim-desc = Shrink the memory in use and lower priority
+limit-title = ⚠️ 3. Limit resources
+limit-desc = Set a maximum working set
+limit-label = Limit:
+limit-button = ⚠️ Apply limit
+limit-mode = Mode:
+limit-mode-working-set = Working set
+limit-mode-working-set-hint = Windows: hard maximum working set, pages above it go to the pagefile. Linux: memory.high, the kernel reclaims and slows the process above the limit
+limit-mode-hard = Hard cap
+limit-mode-hard-hint = Caps total memory: job object on Windows, memory.max on Linux (the process moves into its own cgroup until Restore)
+limit-breach = On breach:
+swap-limit-desc = Swap limit: 0 MB never swaps the process out, unchecked leaves swap unlimited. Restore removes it
+swap-limit-swap = Swap (memory.swap.max)
+swap-limit-zswap = Zswap (memory.zswap.max)
+swap-limit-button = 💽 Apply swap limit
+swap-limit-never = never swap
+swap-limit-mb = { $mb } MB
+swap-limit-unlimited = unlimited
+breach-deny = Deny
+breach-deny-hint = Windows: allocations above the limit fail. Linux: the kernel reclaims and swaps, OOM-killing only when nothing can be reclaimed
+breach-terminate = Terminate
+breach-terminate-hint = Windows: the whole job is terminated as soon as it exceeds the limit. Linux: no swap (memory.swap.max = 0) and memory.oom.group = 1 so the OOM killer ends the whole cgroup
+freeze-title = 🧊 4. Freeze / Hibernate
+freeze-desc = Pause the process (no CPU, no memory faulted back in). Hibernate = freeze, then trim. Restore to resume it
+freeze-button = 🧊 Freeze
+hibernate-button = 💤 Hibernate
+restore-title = ♻️ Restore to normal
+restore-desc = Reset all settings to default
+restore-button = ♻️ Restore
+process-gone = ❌ Process no longer exists
+refresh-button = 🔄 Refresh
+pick-process = 👈 Pick a process from the list
+pick-process-hint = Click a PID to get started
+status-running-action = ⏳ Running { $action } on { $count ->
+        [one] { $count } process
+       *[other] { $count } processes
+    }...
+
+# Batch
+batch-selected = Multiple processes selected:
+total-ram = 💾 Total RAM: { $mb } MB
+total-cpu = ⚙️ Total CPU: { $cpu }%
+batch-actions = 📋 Batch actions
+batch-pin-all = 🔒 Pin all
+batch-limit-all = ⚠️ Limit all
+batch-swap-limit-all = 💽 Swap limit all
+batch-trim-all = 🗜️ Trim all
+batch-freeze-all = 🧊 Freeze all
+batch-hibernate-all = 💤 Hibernate all
+batch-restore-all = ♻️ Restore all
+batch-progress-heading = 📊 Batch { $action }
+
+# Application groups
+group-gone = ❌ Application is no longer running
+cgroup-gone = ❌ The cgroup no longer exists
+selected-cgroup = Selected cgroup:
+cgroup-rescan = 🔄 Rescan cgroups
+cgroup-not-loaded = Cgroup tree has not been read yet
+cgroup-summary = 💾 { $memory }  ({ $count } processes)
+cgroup-current = 💾 memory.current: { $memory }
+cgroup-swap = 💽 Swap: { $memory }
+cgroup-stat = 📊 memory.stat breakdown
+cgroup-limits = 📏 Limits
+cgroup-members = 👥 Member processes ({ $count })
+cgroup-actions = 🗂️ Cgroup actions
+cgroup-actions-desc = Applies to every process in the cgroup and its child cgroups, including ones started later
+cgroup-pin = 📌 Pin (memory.min)
+cgroup-limit = ⚠️ Limit cgroup
+cgroup-swap-limit = 💽 Swap limit cgroup
+cgroup-trim = 🗜️ Trim (memory.reclaim)
+cgroup-restore = ♻️ Restore cgroup
+cgroup-restore-hint = Resets memory.min, memory.high, memory.max and swap limits to kernel defaults; systemd rewrites its own limits on daemon-reload
+status-selected-cgroup = 🎯 Selected cgroup: { $cgroup }
+status-cgroups-read = ✅ Read cgroup tree ({ $count } processes)
+selected-app = Selected application:
+group-instances = 📋 Instances
+group-actions = 📦 Group actions
+group-pin = 🔒 Pin group
+group-limit = ⚠️ Limit group
+group-swap-limit = 💽 Swap limit group
+group-trim = 🗜️ Trim group
+group-freeze = 🧊 Freeze group
+group-hibernate = 💤 Hibernate group
+group-restore = ♻️ Restore group
+status-running-group = ⏳ Running { $action } on group { $group }...
+status-running-cgroup = ⏳ Running { $action } on cgroup { $cgroup }...
+group-result-heading = 📊 { $action } result for group { $group }
+group-before-after = 📉 Before: { $before } MB → After: { $after } MB  💾 Freed: { $freed } MB
+
+# Memory map
+inspector-title = 🔬 Memory map
+inspector-desc = The process's memory regions, grouped by mapping type
+inspector-load = 🔍 Read memory map
+inspector-reload = 🔃 Read again
+inspector-filter-hint = Click to show only regions of this type
+inspector-all = All
+region-heap = Heap
+region-stack = Stack
+region-anonymous = Anonymous
+region-file = File-backed
+region-library = Shared library
+region-other = Other
+col-kind = Type
+col-regions = Regions
+col-size = Size
+col-address = Address
+col-permissions = Permissions
+col-path = Path
+status-reading-memory-map = ⏳ Reading memory map of PID { $pid }...
+status-memory-map-read = ✅ Read { $count ->
+        [one] { $count } memory region
+       *[other] { $count } memory regions
+    } of PID { $pid }
+err-query-working-set = Cannot read working set: { $error }
+
+# System memory
+breakdown-heading = 🧮 System memory
+breakdown-anon = Anonymous (process memory)
+breakdown-page-cache = Page cache
+breakdown-buffers = Buffers
+breakdown-shmem = Shared memory (shmem)
+breakdown-slab-reclaimable = Slab / kernel pool (reclaimable)
+breakdown-slab-unreclaimable = Slab / kernel pool (unreclaimable)
+breakdown-dirty = Dirty
+breakdown-writeback = Writeback
+breakdown-free = Free
+breakdown-available = Available
+breakdown-no-swap = No swap / page file
+breakdown-commit = Committed / limit
+breakdown-commit-hint = Memory promised to processes versus RAM + swap the system may commit
+breakdown-hugepages = { $free } free / { $total } pages × { $size }
+breakdown-faults = Page faults
+breakdown-faults-hint = All page faults and major faults (read from disk) per second across the system
+breakdown-faults-value = { $total } (major: { $major })
+breakdown-reclaim = Reclaim
+breakdown-reclaim-hint = Pages the kernel scanned (pgscan) and reclaimed (pgsteal) per second; high values mean memory pressure
+breakdown-reclaim-value = scan { $scan }, reclaimed { $steal }
+err-performance-info = Cannot read system performance information: { $error }
+
+# Swap and compressed memory
+swap-heading = 💽 Swap and compressed memory
+swap-in-rate = ⬇️ Swap-in: { $rate }
+swap-out-rate = ⬆️ Swap-out: { $rate }
+swap-rate-hint = A swap-out spike right after a trim means the trimmed pages were written to disk
+swap-device = Device
+swap-used = Used
+swap-priority = Priority
+swap-compressed = 🗜️ Compressed memory
+swap-original = Original
+swap-compressed-size = Compressed
+swap-ratio = Ratio
+swap-ram-used = RAM used
+swap-top-processes = Processes with the most memory in swap
+err-page-files = Cannot list page files: { $error }
+
+# Action effects
+effect-heading = 📈 Action effects
+effect-window = Measured for { $window } s after each action, every { $interval } s
+effect-export = 💾 Export reports
+effect-export-hint = Write all reports to effect_reports.toml next to config.toml
+status-effects-exported = ✅ Exported { $count ->
+    [one] { $count } report
+   *[other] { $count } reports
+} to { $path }
+effect-empty = No action has been measured yet
+effect-action = Action
+effect-before = Before
+effect-lowest = Lowest
+effect-freed = Freed
+effect-refaulted = Faulted back
+effect-refault-rate = Refault rate
+effect-measuring = ⏳ Measuring
+effect-no-effect = ⚪ Nothing freed
+effect-effective = ✅ Effective
+effect-partial = ⚠️ Partly faulted back
+effect-reverted = ❌ Mostly faulted back
+effect-interrupted = Stopped early: the process exited or a new action was applied
+effect-samples = { $count ->
+    [one] { $count } sample
+   *[other] { $count } samples
+} over { $seconds } s
+
+# Alerts
+alert-heading = 🔔 Alerts
+alert-rules = Rules
+alert-no-rules = No rules yet, add [[alerts.rules]] to config.toml
+alert-cond-system-usage = System RAM > { $threshold }%
+alert-cond-available = Available RAM < { $threshold } GB
+alert-cond-process-rss = Process uses > { $threshold } MB
+alert-cond-growth = Process grows > { $threshold } MB/min
+alert-desktop-on = 🖥️ Desktop notifications: on
+alert-desktop-off = 🖥️ Desktop notifications: off
+alert-webhook = 🌐 Webhook: { $url }
+alert-webhook-off = 🌐 Webhook: not used
+alert-cooldown = ⏱️ Cooldown: { $seconds } s
+alert-history = 📜 Alert history
+alert-history-empty = No alerts yet
+alert-history-entry = [{ $rule }] { $message } ({ $seconds } s ago)
+alert-notification-title = ⚠️ RAM Manager: { $rule }
+alert-msg-system-usage = System RAM usage is { $value }% (threshold { $threshold }%)
+alert-msg-available = Only { $value } GB of RAM available (threshold { $threshold } GB)
+alert-msg-process-rss = { $name } (PID { $pid }) uses { $value } MB (threshold { $threshold } MB)
+alert-msg-growth = { $name } (PID { $pid }) grows { $value } MB/min (threshold { $threshold } MB/min)
+err-alert-notification = Cannot show desktop notification: { $error }
+err-alert-webhook = Cannot deliver webhook to { $url }: { $error }
+err-webhook-scheme = only http:// URLs are supported
+err-webhook-host = host is missing or cannot be resolved
+err-webhook-port = invalid port "{ $port }"
+err-webhook-status = server responded { $status }
+
+# Privileged helper
+helper-connected = 🔐 Helper: connected
+helper-unreachable = 🔐 Helper: unreachable
+helper-start = 🚀 Start helper
+status-helper-launched = ⏳ Starting helper with admin rights...
+cfg-helper-address = [helper] address "{ $address }" is invalid: { $error }
+cfg-helper-max-pin = [helper] max_pin_mb = { $value } is invalid (allowed 1 - { $max })
+err-helper-not-loopback = only loopback addresses (127.0.0.1 or ::1) are accepted
+err-helper-args = Invalid helper arguments: { $args }. Usage: --helper [--token-file <path>] [--listen <address>] [--max-pin-mb <MB>]
+err-helper-bind = Helper cannot listen on { $address }: { $error }
+err-helper-connect = Cannot reach helper at { $address }: { $error }
+err-helper-launch = Cannot start helper: { $error }
+err-helper-auth = Helper refused: wrong token
+err-helper-token = Cannot generate helper token: { $error }
+err-helper-version = Helper speaks protocol v{ $expected }, client sent v{ $actual }
+err-helper-protocol = Malformed data from helper
+err-helper-closed = Helper closed the connection
+err-helper-bad-request = Helper rejected a malformed request: { $error }
+err-helper-pid = Helper refused to act on PID { $pid }
+err-helper-size = Helper refused size { $mb } MB (allowed 1 - { $max } MB)
+err-helper-pin-size = Helper refused to pin { $mb } MB (limit { $max } MB, set with --max-pin-mb)
+err-helper-oom-negative = Helper refused oom_score_adj = { $value } for PID { $pid }: negative values are only allowed to restore the original value
+err-helper-priority = Helper refused priority { $value }: outside the levels this program can set
+err-helper-peer = Helper cannot identify the user on the other end of the connection
+err-helper-not-owner = Helper refused PID { $pid }: the process does not belong to the connected user
+err-helper-kernel-thread = Helper refused PID { $pid }: kernel/system process
+err-helper-cgroup-owner = Helper refused cgroup { $path }: not delegated to the connected user or contains other users' processes
+
+# Control API of the running GUI
+cfg-api-address = [api] address "{ $address }" is invalid: { $error }
+err-api-args = Invalid API request: { $args }. Usage: --api effect-reports | apply-profile <name> | revert-profile
+err-api-bind = API cannot listen on { $address }: { $error }
+err-api-connect = Cannot reach the running RAM Manager at { $address } (is [api] enabled?): { $error }
+err-api-auth = API refused: wrong token
+err-api-version = API speaks protocol v{ $expected }, client sent v{ $actual }
+err-api-bad-request = API rejected a malformed request: { $error }
+err-api-protocol = Malformed data from the API
+err-api-closed = API closed the connection
+err-api-worker = RAM Manager did not answer the API request in time
+
+# Linux permissions
+perm-heading = 🛡️ Permissions (Linux)
+perm-uid = Effective UID: { $uid }
+perm-capabilities = Capabilities
+perm-cap-sys-nice = Raise priority, process_madvise on other processes
+perm-cap-sys-resource = Override resource limits, set rlimits of other processes
+perm-cap-sys-ptrace = Read memory / memory maps of other users' processes
+perm-cgroup = Current cgroup
+perm-cgroup-v1 = Not on cgroup v2, cgroup based actions are unavailable
+perm-delegated = ✅ Delegated subtree { $path }
+perm-not-delegated = ❌ No cgroup subtree is delegated to this user
+perm-file-writable = writable
+perm-file-read-only = read-only
+perm-file-missing = missing (memory controller not enabled)
+perm-actions = Actions
+perm-req-cgroup = write access to { $file }
+perm-req-same-user = same user as the process
+perm-req-cgroup-release = permission to move the process back to its original cgroup and remove its own cgroup
+perm-pid-missing = PID { $pid }: { $reason }
+perm-select-process = Select one process to see what each action needs
+perm-target = For PID { $pid }:
+perm-limit-cgroup = Own cgroup (Pin/Limit/Swap Limit): { $path }
+perm-or = { " or " }
+perm-action-missing = { $action }: needs { $missing }
+perm-priority-missing = CAP_SYS_NICE is required to raise priority to { $priority }
+perm-oom-missing = CAP_SYS_RESOURCE is required to lower oom_score_adj; Pin still runs but the OOM score stays the same
+
+# Linux page-out (process_madvise)
+pageout-hint = Hint:
+pageout-hint-cold = ❄️ Cold (reclaim before RAM runs low)
+pageout-hint-pageout = 📤 Pageout (reclaim now)
+pageout-regions = Regions:
+pageout-report = Last trim ({ $hint })
+pageout-col-kind = Kind
+pageout-col-rss = RSS before → after
+pageout-col-swap = Swap before → after
+pageout-freed = Freed { $freed } of RSS
+msg-paged-out = ✅ Trimmed PID { $pid } with { $hint }: freed { $freed } of RSS
+cfg-pageout-regions = [pageout] regions must list at least one region kind
+err-pidfd-open = Cannot open a pidfd for PID { $pid }: { $error }
+err-pidfd-gone = PID { $pid } exited or was reused by another process while its memory map was read: { $error }
+err-process-madvise = process_madvise on PID { $pid } failed: { $error }
+err-process-madvise-unsupported = process_madvise requires Linux 5.10 or newer
+err-signal = Cannot send a signal to PID { $pid }: { $error }
+err-suspend = Cannot suspend/resume process: { $error }
+err-freeze-pid = Refusing to freeze PID { $pid } (system PID or this program itself)
+
+# Profiles in the GUI
+status-applying-profile = ⏳ Applying profile "{ $profile }"...
+status-launching = ⏳ Launching "{ $command }"...
+status-profile-not-found = ❌ Profile "{ $profile }" not found
+status-reverting-profile = ⏳ Reverting profile...
+profile-heading = 🎭 Profile
+profile-active = Active:
+profile-none = none
+profile-apply = ▶ Apply
+profile-revert = ↩ Revert
+profile-revert-hint = Return processes to exactly the state they had before the profile was applied
+profile-history = 📜 History
+profile-history-entry = { $summary } ({ $seconds }s ago)
+
+# Help
+help-heading = ❓ Help
+help-pin = 🔒 Pin: Keep the process in physical RAM
+help-trim = 🗜️ Trim: Release unused RAM
+help-limit = ⚠️ Limit: Cap maximum RAM
+help-freeze = 🧊 Freeze / 💤 Hibernate: Pause the process (optionally trimmed), Restore resumes it
+help-restore = ♻️ Restore: Return to the original state
+help-launch = 🚀 Launch with policy: Start a program inside a new cgroup (Linux) / job object (Windows) with its limits already in place
+help-cgroup = 🗂️ Cgroup (Linux): Browse systemd slices and containers, apply Pin/Limit/Trim/Restore to a whole cgroup
+help-multi-select = ☑️ Ctrl+click / Shift+click: select several processes for batch actions
+help-inspector = 🔬 Memory map: heap/stack/anonymous/file/library regions of the selected process with RSS, PSS and swap
+
+# OOM score (Linux)
+col-oom-hint = OOM score (/proc/<pid>/oom_score): the OOM killer picks the highest score first
+oom-score-adj = oom_score_adj: { $value }
+process-oom = 💀 OOM: { $score } (adj { $adj })
+oom-protect-pinned = 🛡️ Lower oom_score_adj to
+oom-expose-limited = 🎯 Raise oom_score_adj to
+oom-option-hint = -1000 = never killed by the OOM killer, 1000 = killed first. The previous value comes back on Restore
+msg-oom-adjusted = 🛡️ oom_score_adj = { $value }
+msg-oom-failed = ⚠️ Could not change oom_score_adj: { $error }
+err-oom-score-adj = Cannot write oom_score_adj of PID { $pid }: { $error }
+err-oom-unsupported = oom_score_adj only exists on Linux
+cfg-oom-score-adj = [oom] oom_score_adj { $value } must be between { $min } and { $max }
+
+# Early-OOM
+early-oom-heading = 💀 Early-OOM
+early-oom-enabled = Terminate a process when RAM is about to run out
+early-oom-enabled-hint = When both available RAM and free swap fall below the thresholds, the unprotected process with the highest OOM score gets SIGTERM, or SIGKILL below half the thresholds
+early-oom-thresholds = 📉 Thresholds: available RAM < { $available }% and free swap < { $swap }%
+early-oom-timing = ⏱️ Checks every { $poll } s, waits { $cooldown } s after each termination
+early-oom-protect-pinned = pinned processes
+early-oom-protected = 🛡️ Protected: { $list }
+early-oom-log = 📝 Log: { $path }
+early-oom-history = 📜 Terminations
+early-oom-history-empty = No process terminated yet
+early-oom-history-entry = { $message } ({ $seconds } s ago)
+early-oom-terminated = 💀 Sent SIGTERM to { $name } (PID { $pid }, score { $badness }): { $available }% RAM available, { $swap }% swap free
+early-oom-killed = 💀 Sent SIGKILL to { $name } (PID { $pid }, score { $badness }): { $available }% RAM available, { $swap }% swap free
+early-oom-failed = ❌ Could not terminate { $name } (PID { $pid }, score { $badness }): { $available }% RAM available, { $swap }% swap free
+err-terminate = Cannot terminate process: { $error }
+err-terminate-pid = Refusing to terminate PID { $pid } (system PID or this program itself)
+cfg-early-oom-percent = [early_oom] threshold { $value }% must be greater than 0 and less than 100
+cfg-early-oom-interval = [early_oom] poll_interval_secs = { $poll } must be between { $min } and { $max }, cooldown_secs = { $cooldown } between 0 and { $max_cooldown }
+launch-heading = 🚀 Launch with policy
+launch-desc = Starts the program inside a new cgroup (Linux) or job object (Windows) so the limits apply from its very first instruction. The program runs as the current user, even when a helper is enabled.
+launch-command = Command:
+launch-command-hint = e.g. firefox --private-window or "C:\Program Files\App\app.exe"
+launch-memory-max = Hard memory limit (memory.max / job memory)
+launch-memory-high = Throttle above (memory.high)
+launch-swap-max = Swap limit (memory.swap.max)
+launch-priority = Priority:
+launch-priority-default = Unchanged
+launch-button = 🚀 Launch
+launch-history = 📜 Exited programs
+launch-history-entry = { $message } ({ $seconds } s ago)
+launch-exited = 🏁 "{ $command }" (PID { $pid }) exited with code { $code }
+launch-signaled = 💀 "{ $command }" (PID { $pid }) was terminated: { $status }
+launch-wait-failed = ❌ Could not wait for "{ $command }" (PID { $pid }): { $error }
+err-launch-empty = No command to launch
+err-launch-quote = Command has an unclosed quote
+err-launch-spawn = Cannot start program: { $error }
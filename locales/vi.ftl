@@ -0,0 +1,561 @@
+# Trạng thái tiến trình
+status-normal = Bình thường
+status-pinned = Đã ghim
+status-trimmed = Đã trim
+status-limited = Giới hạn
+status-high-priority = Ưu tiên cao
+status-frozen = Đóng băng
+status-launched = Khởi chạy có giới hạn
+status-swap-limited = Giới hạn swap
+
+# Hành động
+action-pin = Ghim
+action-trim = Trim
+action-limit = Giới hạn
+action-swap-limit = Giới hạn swap
+action-freeze = Đóng băng
+action-hibernate = Ngủ đông
+action-restore = Khôi phục
+
+# Lỗi Windows API
+err-open-process = Không thể mở tiến trình: { $error }
+err-set-working-set = Không thể đặt working set: { $error }
+err-set-priority = Không thể đặt priority: { $error }
+err-trim-working-set = Không thể trim working set: { $error }
+err-trim-unsupported = Trên Linux, Trim dùng process_madvise (Pageout) thay cho EmptyWorkingSet
+err-limit-working-set = Không thể giới hạn working set: { $error }
+err-reset-working-set = Không thể reset working set: { $error }
+err-memory-info = Không thể lấy thông tin bộ nhớ: { $error }
+err-process-gone = Tiến trình không còn chạy
+
+# Kết quả hành động
+msg-pinned = ✅ Đã ghim PID { $pid } vào RAM ({ $mb } MB)
+msg-trimmed =
+    ✅ Đã trim PID { $pid }
+    📉 Trước: { $before } MB → Sau: { $after } MB
+    💾 Giải phóng: { $freed } MB
+msg-limited = ✅ Đã giới hạn PID { $pid } (Max: { $mb } MB, Priority: IDLE)
+msg-limited-hard = ✅ Đã giới hạn cứng PID { $pid } ở { $mb } MB (khi vượt: { $breach })
+msg-cgroup-applied = ✅ Đã { $action } cgroup { $cgroup }
+msg-cgroup-restored = ✅ Đã trả lại giới hạn gốc của cgroup { $cgroup }
+msg-cgroup-trimmed = ✅ Đã trim cgroup { $cgroup }: { $before } → { $after }
+msg-swap-limited = ✅ Đã giới hạn swap cho PID { $pid }: swap { $swap }, zswap { $zswap }
+msg-restored = ✅ Đã khôi phục PID { $pid } về trạng thái bình thường
+msg-frozen = 🧊 Đã đóng băng PID { $pid }
+msg-launched = 🚀 Đã khởi chạy "{ $command }" (PID { $pid })
+msg-hibernated =
+    🧊 Đã đóng băng PID { $pid } rồi trim
+    { $message }
+msg-priority-set = ✅ Đã đặt priority PID { $pid } = { $priority }
+msg-with-priority = { $message } (priority { $priority })
+msg-restored-profile = ✅ Đã khôi phục PID { $pid } về trạng thái trước profile
+msg-action-failed-all = { $action } thất bại cho cả { $count } tiến trình
+msg-action-summary = ✅ { $action }: thành công { $succeeded }/{ $total } tiến trình
+msg-errors = ⚠️ Lỗi:
+group-summary =
+    { $icon } { $action } nhóm { $group } ({ $succeeded }/{ $total } tiến trình)
+    📉 Trước: { $before } MB → Sau: { $after } MB
+    💾 Giải phóng: { $freed } MB
+batch-summary = { $icon } { $action }: thành công { $succeeded }, thất bại { $failed } / { $total } tiến trình
+
+# Profile
+profile-event-applied = Áp dụng
+profile-event-reverted = Hoàn tác
+profile-summary = { $icon } { $event } profile "{ $profile }": { $succeeded } tiến trình thành công, { $failed } lỗi
+profile-gaming-desc = Đẩy browser/chat ra nền; thêm rule pin với đúng tên file chạy của game
+profile-compile-desc = Ưu tiên IDE, giới hạn mềm dockerd
+profile-meeting-desc = Giữ Teams/Zoom mượt, trim các app nền
+err-no-active-profile = Không có profile nào đang chạy
+err-profile-not-found = Không tìm thấy profile "{ $profile }"
+err-cli-args = Tham số không hợp lệ: { $args }. Dùng: --profile <tên> | --revert-profile
+err-serialize-session = Không thể lưu profile session: { $error }
+err-serialize-effects = Không thể xuất báo cáo hiệu quả: { $error }
+
+# File
+err-read-file = Không thể đọc { $path }: { $error }
+err-syntax = Lỗi cú pháp trong { $path }: { $error }
+err-invalid-config = Cấu hình không hợp lệ trong { $path }: { $error }
+err-create-dir = Không thể tạo thư mục { $path }: { $error }
+err-cgroup-v2 = PID { $pid } không thuộc cgroup v2 nào (/proc/{ $pid }/cgroup không có dòng 0::)
+err-cgroup-no-memory-controller = Controller memory chưa được bật cho cgroup con của { $path }
+err-size-too-large = { $mb } MB vượt quá kích thước bộ nhớ biểu diễn được
+err-cgroup-tree-v2 = { $path } không phải cây cgroup v2
+err-cgroup-tree-unsupported = Cây cgroup chỉ có trên Linux
+err-cgroup-path = { $path } không phải cgroup nằm dưới cgroup gốc
+err-cgroup-action-unsupported = Không thể { $action } cả cgroup
+err-cgroup-nothing-saved = Không có gì để khôi phục: chưa đổi giới hạn nào của cgroup { $cgroup } từ cây cgroup
+err-cgroup-restore-value = Từ chối khôi phục { $file } = "{ $value }"
+err-swap-control-missing = { $path } không có { $file } (chưa bật swap accounting hoặc kernel quá cũ)
+err-swap-limit-unsupported = Giới hạn swap theo tiến trình chỉ có trên Linux (cgroup v2)
+err-job-object = Lỗi job object: { $error }
+err-remove-dir = Không xoá được thư mục { $path }: { $error }
+err-write-file = Không thể ghi { $path }: { $error }
+err-delete-file = Không thể xóa { $path }: { $error }
+err-serialize-config = Không thể tạo nội dung config: { $error }
+
+# Kiểm tra config.toml
+cfg-pin-range = sliders.pin_min_mb ({ $min }) phải > 0 và nhỏ hơn sliders.pin_max_mb ({ $max })
+cfg-limit-range = sliders.limit_min_mb ({ $min }) phải > 0 và nhỏ hơn sliders.limit_max_mb ({ $max })
+cfg-pin-default = actions.pin_working_set_mb ({ $value }) phải nằm trong khoảng { $min }..={ $max }
+cfg-limit-default = actions.limit_max_ws_mb ({ $value }) phải nằm trong khoảng { $min }..={ $max }
+cfg-refresh-interval = sampling.refresh_interval_secs ({ $value }) phải nằm trong khoảng { $min }..={ $max } giây
+cfg-full-refresh = sampling.full_refresh_interval_secs ({ $value }) phải nằm trong khoảng refresh_interval_secs ({ $min }) đến { $max }
+cfg-thresholds = cần 0 < thresholds.ram_warning_percent ({ $warning }) < ram_critical_percent ({ $critical }) <= 100
+cfg-thrashing = thresholds.thrashing_major_faults_per_sec ({ $major }) và thrashing_faults_per_sec ({ $total }) phải > 0
+cfg-effects = effects.window_secs ({ $window }) phải nằm trong khoảng sample_interval_secs ({ $interval }) .. { $max }, sample_interval_secs phải >= { $min }
+cfg-alert-cooldown = alerts: cooldown_secs ({ $value }) phải nằm trong khoảng 0 đến { $max }
+cfg-alert-webhook = alerts.webhook_url "{ $url }" không hợp lệ: { $error }
+cfg-alert-name-empty = Rule cảnh báo thứ { $index } không có tên
+cfg-alert-duplicate = Trùng tên rule cảnh báo "{ $rule }"
+cfg-alert-threshold = Ngưỡng của rule cảnh báo "{ $rule }" không hợp lệ: { $value }
+cfg-alert-target = Rule cảnh báo "{ $rule }" áp dụng cho toàn hệ thống, không dùng được target
+cfg-profile-name-empty = profiles[{ $index }].name không được để trống
+cfg-profile-duplicate = profile "{ $profile }" bị khai báo trùng tên
+cfg-ui-scale = ui.scale ({ $value }) phải nằm trong khoảng { $min }..={ $max }
+cfg-profile-no-rules = profile "{ $profile }" chưa có rule nào
+
+# Cảnh báo quyền Administrator
+admin-required = Tool cần quyền Administrator!
+admin-please = Vui lòng:
+admin-step-close = 1. Đóng tool
+admin-step-run-as = 2. Chuột phải - Run as Administrator
+
+# Thanh trên cùng / status bar
+status-ready = 🟢 Sẵn sàng - Tool đang chạy
+status-config-fallback = ❌ { $error } - đang dùng cấu hình mặc định
+status-config-saved = ✅ Đã lưu cấu hình vào { $path }
+status-refreshed = ✅ Đã làm mới danh sách
+top-processes = 📊 Tiến trình: { $count }
+top-auto = 🔄 Auto
+top-interval-hint = Chu kỳ lấy mẫu RAM/CPU
+top-refresh-hint = Refresh ngay
+top-theme-hint = Đổi theme
+top-stats-hint = Thống kê
+top-save-hint = Lưu cấu hình vào { $path }
+top-display-hint = Font và cỡ giao diện
+top-language-hint = Ngôn ngữ
+language-auto = Tự động ({ $language })
+
+# Font và UI scale
+display-heading = 🔤 Hiển thị
+display-font = Font
+font-dejavu = DejaVu Sans (nhúng sẵn, đủ dấu tiếng Việt)
+font-egui-default = Font mặc định của egui
+font-file = Từ file:
+font-file-hint = Đường dẫn tới file .ttf/.otf, VD: C:\Windows\Fonts\segoeui.ttf
+font-apply = Áp dụng
+display-scale = Cỡ giao diện
+status-font-applied = ✅ Đã đổi font
+bottom-admin = ⚠️ Chạy với quyền Administrator
+
+# Danh sách tiến trình
+list-heading = 📋 Danh sách tiến trình
+list-filter-hint = Tìm theo tên hoặc PID
+list-clear-filter = Xóa filter
+list-sort = Sắp xếp:
+sort-name = 📝 Tên
+sort-status = 🏷️ Trạng thái
+list-view = Hiển thị:
+view-list = 📄 Danh sách
+view-tree = 🌳 Cây tiến trình
+view-group = 📦 Theo ứng dụng
+view-cgroup = 🗂️ Cgroup
+stats-heading = 📊 Thống kê
+stats-pinned = 📌 Pinned: { $count }
+stats-trimmed = 🗜️ Trimmed: { $count }
+stats-limited = ⚠️ Limited: { $count }
+stats-frozen = 🧊 Đóng băng: { $count }
+stats-launched = 🚀 Đã khởi chạy: { $count }
+select-all = ☑️ Chọn tất cả
+select-all-hint = Chọn mọi tiến trình đang hiển thị
+select-none = ⬜ Bỏ chọn
+selected-count = Đã chọn: { $count }
+selection-hint = Ctrl+click: chọn thêm/bỏ chọn, Shift+click: chọn một dải
+status-selected-one = 🎯 Đã chọn: { $name } (PID: { $pid })
+status-selected-many = 🎯 Đã chọn { $count } tiến trình
+status-selected-group = 🎯 Đã chọn nhóm: { $group } ({ $count } tiến trình)
+col-name = Tên tiến trình
+col-status = Trạng thái
+col-result = Kết quả
+col-before = Trước (MB)
+col-after = Sau (MB)
+col-faults = Fault/s
+col-faults-hint = Page fault mỗi giây: major fault (phải đọc đĩa) trên Linux, tổng page fault trên Windows
+list-showing = 📊 Hiển thị { $shown } / { $total } tiến trình
+process-count = { $count } tiến trình
+tree-subtree-summary = 💾 { $mb } MB  ⚙️ { $cpu }%  ({ $count } tiến trình, riêng: { $own } MB)
+
+# Bảng điều khiển
+panel-heading = ⚙️ Bảng điều khiển
+selected-process = Tiến trình đã chọn:
+status-label = 🏷️ Trạng thái:
+subtree-apply = 🌳 Áp dụng cho cả nhánh ({ $count } tiến trình, { $mb } MB, CPU { $cpu }%)
+process-faults = 📄 Page fault: { $total }/s (major: { $major }/s)
+fault-history-hint = { $samples } sample gần nhất, cao nhất { $max }/s
+thrashing-hint = Thrashing: tiến trình bị giới hạn nhưng liên tục phải nạp lại page
+thrashing-warning = 🔥 Thrashing sau khi Limit — hãy nâng giới hạn hoặc khôi phục tiến trình
+pin-title = 🔒 1. Ưu tiên giữ trong RAM vật lý
+pin-desc = Tăng working set và priority để giữ tiến trình trong bộ nhớ thật
+pin-button = 🔒 Ghim vào RAM
+trim-title = 🗜️ 2. Giảm working set / Đẩy ra nền
+trim-desc = Thu nhỏ bộ nhớ đang dùng và hạ priority
+limit-title = ⚠️ 3. Giới hạn tài nguyên
+limit-desc = Đặt giới hạn working set tối đa
+limit-label = Giới hạn:
+limit-button = ⚠️ Áp dụng giới hạn
+limit-mode = Chế độ:
+limit-mode-working-set = Working set
+limit-mode-working-set-hint = Windows: working set tối đa cứng, phần vượt bị đẩy ra pagefile. Linux: memory.high, kernel reclaim và làm chậm tiến trình khi vượt
+limit-mode-hard = Giới hạn cứng
+limit-mode-hard-hint = Giới hạn tổng bộ nhớ: job object trên Windows, memory.max trên Linux (tiến trình được chuyển vào cgroup riêng cho tới khi Restore)
+limit-breach = Khi vượt:
+swap-limit-desc = Giới hạn swap: 0 MB là không bao giờ đẩy tiến trình ra swap, bỏ chọn là không giới hạn. Khôi phục sẽ bỏ giới hạn
+swap-limit-swap = Swap (memory.swap.max)
+swap-limit-zswap = Zswap (memory.zswap.max)
+swap-limit-button = 💽 Áp dụng giới hạn swap
+swap-limit-never = không bao giờ swap
+swap-limit-mb = { $mb } MB
+swap-limit-unlimited = không giới hạn
+breach-deny = Từ chối
+breach-deny-hint = Windows: cấp phát vượt giới hạn thất bại. Linux: kernel reclaim và swap, chỉ OOM kill khi không thu hồi được gì
+breach-terminate = Kết thúc
+breach-terminate-hint = Windows: kết thúc cả job ngay khi vượt giới hạn. Linux: không cho swap (memory.swap.max = 0) và memory.oom.group = 1 để OOM killer kết thúc cả cgroup
+freeze-title = 🧊 4. Đóng băng / Ngủ đông
+freeze-desc = Tạm dừng tiến trình (không dùng CPU, không nạp lại bộ nhớ). Ngủ đông = đóng băng rồi trim. Khôi phục để chạy tiếp
+freeze-button = 🧊 Đóng băng
+hibernate-button = 💤 Ngủ đông
+restore-title = ♻️ Khôi phục về bình thường
+restore-desc = Reset tất cả cài đặt về mặc định
+restore-button = ♻️ Khôi phục
+process-gone = ❌ Tiến trình không tồn tại
+refresh-button = 🔄 Làm mới
+pick-process = 👈 Chọn một tiến trình từ danh sách
+pick-process-hint = Click vào PID để bắt đầu
+status-running-action = ⏳ Đang { $action } { $count } tiến trình...
+
+# Hàng loạt
+batch-selected = Đã chọn nhiều tiến trình:
+total-ram = 💾 Tổng RAM: { $mb } MB
+total-cpu = ⚙️ Tổng CPU: { $cpu }%
+batch-actions = 📋 Hành động hàng loạt
+batch-pin-all = 🔒 Ghim tất cả
+batch-limit-all = ⚠️ Giới hạn tất cả
+batch-swap-limit-all = 💽 Giới hạn swap tất cả
+batch-trim-all = 🗜️ Trim tất cả
+batch-freeze-all = 🧊 Đóng băng tất cả
+batch-hibernate-all = 💤 Ngủ đông tất cả
+batch-restore-all = ♻️ Khôi phục tất cả
+batch-progress-heading = 📊 { $action } hàng loạt
+
+# Nhóm ứng dụng
+group-gone = ❌ Ứng dụng không còn chạy
+cgroup-gone = ❌ Cgroup không còn tồn tại
+selected-cgroup = Cgroup đã chọn:
+cgroup-rescan = 🔄 Đọc lại cgroup
+cgroup-not-loaded = Chưa đọc cây cgroup
+cgroup-summary = 💾 { $memory }  ({ $count } tiến trình)
+cgroup-current = 💾 memory.current: { $memory }
+cgroup-swap = 💽 Swap: { $memory }
+cgroup-stat = 📊 Phân tích memory.stat
+cgroup-limits = 📏 Giới hạn
+cgroup-members = 👥 Tiến trình thành viên ({ $count })
+cgroup-actions = 🗂️ Hành động cho cả cgroup
+cgroup-actions-desc = Áp dụng cho mọi tiến trình trong cgroup và các cgroup con, kể cả tiến trình sinh ra sau này
+cgroup-pin = 📌 Pin (memory.min)
+cgroup-limit = ⚠️ Giới hạn cgroup
+cgroup-swap-limit = 💽 Giới hạn swap cgroup
+cgroup-trim = 🗜️ Trim (memory.reclaim)
+cgroup-restore = ♻️ Khôi phục cgroup
+cgroup-restore-hint = Đưa memory.min, memory.high, memory.max và giới hạn swap về mặc định của kernel; systemd ghi lại giới hạn của nó khi daemon-reload
+status-selected-cgroup = 🎯 Đã chọn cgroup: { $cgroup }
+status-cgroups-read = ✅ Đã đọc cây cgroup ({ $count } tiến trình)
+selected-app = Ứng dụng đã chọn:
+group-instances = 📋 Từng instance
+group-actions = 📦 Hành động cho cả nhóm
+group-pin = 🔒 Ghim nhóm
+group-limit = ⚠️ Giới hạn nhóm
+group-swap-limit = 💽 Giới hạn swap nhóm
+group-trim = 🗜️ Trim nhóm
+group-freeze = 🧊 Đóng băng nhóm
+group-hibernate = 💤 Ngủ đông nhóm
+group-restore = ♻️ Khôi phục nhóm
+status-running-group = ⏳ Đang { $action } nhóm { $group }...
+status-running-cgroup = ⏳ Đang { $action } cgroup { $cgroup }...
+group-result-heading = 📊 Kết quả { $action } nhóm { $group }
+group-before-after = 📉 Trước: { $before } MB → Sau: { $after } MB  💾 Giải phóng: { $freed } MB
+
+# Bản đồ bộ nhớ
+inspector-title = 🔬 Bản đồ bộ nhớ
+inspector-desc = Các vùng nhớ của tiến trình, nhóm theo loại ánh xạ
+inspector-load = 🔍 Đọc memory map
+inspector-reload = 🔃 Đọc lại
+inspector-filter-hint = Bấm để chỉ hiện các vùng thuộc loại này
+inspector-all = Tất cả
+region-heap = Heap
+region-stack = Stack
+region-anonymous = Ẩn danh
+region-file = Ánh xạ file
+region-library = Thư viện dùng chung
+region-other = Khác
+col-kind = Loại
+col-regions = Số vùng
+col-size = Kích thước
+col-address = Địa chỉ
+col-permissions = Quyền
+col-path = Đường dẫn
+status-reading-memory-map = ⏳ Đang đọc memory map của PID { $pid }...
+status-memory-map-read = ✅ Đã đọc { $count } vùng nhớ của PID { $pid }
+err-query-working-set = Không thể đọc working set: { $error }
+
+# RAM hệ thống
+breakdown-heading = 🧮 RAM hệ thống
+breakdown-anon = Ẩn danh (bộ nhớ tiến trình)
+breakdown-page-cache = Page cache
+breakdown-buffers = Buffers
+breakdown-shmem = Bộ nhớ dùng chung (shmem)
+breakdown-slab-reclaimable = Slab / kernel pool (thu hồi được)
+breakdown-slab-unreclaimable = Slab / kernel pool (không thu hồi được)
+breakdown-dirty = Dirty
+breakdown-writeback = Writeback
+breakdown-free = Trống
+breakdown-available = Khả dụng
+breakdown-no-swap = Không có swap / page file
+breakdown-commit = Đã commit / giới hạn
+breakdown-commit-hint = Bộ nhớ đã hứa cấp cho các tiến trình so với RAM + swap mà hệ thống cho phép commit
+breakdown-hugepages = { $free } trống / { $total } page × { $size }
+breakdown-faults = Page fault
+breakdown-faults-hint = Tổng page fault và major fault (phải đọc đĩa) mỗi giây trên toàn hệ thống
+breakdown-faults-value = { $total } (major: { $major })
+breakdown-reclaim = Thu hồi page
+breakdown-reclaim-hint = Số page kernel quét (pgscan) và thu hồi (pgsteal) mỗi giây; cao nghĩa là đang thiếu RAM
+breakdown-reclaim-value = quét { $scan }, thu hồi { $steal }
+err-performance-info = Không thể đọc thông tin hiệu năng hệ thống: { $error }
+
+# Swap và bộ nhớ nén
+swap-heading = 💽 Swap và bộ nhớ nén
+swap-in-rate = ⬇️ Swap-in: { $rate }
+swap-out-rate = ⬆️ Swap-out: { $rate }
+swap-rate-hint = Swap-out tăng vọt ngay sau khi trim nghĩa là các page vừa trim đã bị ghi ra đĩa
+swap-device = Thiết bị
+swap-used = Đã dùng
+swap-priority = Ưu tiên
+swap-compressed = 🗜️ Bộ nhớ nén
+swap-original = Gốc
+swap-compressed-size = Sau nén
+swap-ratio = Tỉ lệ
+swap-ram-used = RAM sử dụng
+swap-top-processes = Tiến trình có nhiều bộ nhớ trong swap nhất
+err-page-files = Không thể liệt kê page file: { $error }
+
+# Hiệu quả hành động
+effect-heading = 📈 Hiệu quả hành động
+effect-window = Đo trong { $window } giây sau mỗi hành động, { $interval } giây một lần
+effect-export = 💾 Xuất báo cáo
+effect-export-hint = Ghi mọi báo cáo ra effect_reports.toml cạnh config.toml
+status-effects-exported = ✅ Đã xuất { $count } báo cáo vào { $path }
+effect-empty = Chưa có hành động nào được đo
+effect-action = Hành động
+effect-before = Trước
+effect-lowest = Thấp nhất
+effect-freed = Giải phóng
+effect-refaulted = Bị nạp lại
+effect-refault-rate = Tốc độ nạp lại
+effect-measuring = ⏳ Đang đo
+effect-no-effect = ⚪ Không giải phóng
+effect-effective = ✅ Hiệu quả
+effect-partial = ⚠️ Nạp lại một phần
+effect-reverted = ❌ Nạp lại gần hết
+effect-interrupted = Dừng đo sớm: tiến trình đã thoát hoặc có hành động mới
+effect-samples = { $count } lần đo trong { $seconds } giây
+
+# Cảnh báo
+alert-heading = 🔔 Cảnh báo
+alert-rules = Rule
+alert-no-rules = Chưa có rule nào, thêm [[alerts.rules]] trong config.toml
+alert-cond-system-usage = RAM hệ thống > { $threshold }%
+alert-cond-available = RAM trống < { $threshold } GB
+alert-cond-process-rss = Tiến trình dùng > { $threshold } MB
+alert-cond-growth = Tiến trình tăng > { $threshold } MB/phút
+alert-desktop-on = 🖥️ Thông báo desktop: bật
+alert-desktop-off = 🖥️ Thông báo desktop: tắt
+alert-webhook = 🌐 Webhook: { $url }
+alert-webhook-off = 🌐 Webhook: không dùng
+alert-cooldown = ⏱️ Cooldown: { $seconds } giây
+alert-history = 📜 Lịch sử cảnh báo
+alert-history-empty = Chưa có cảnh báo nào
+alert-history-entry = [{ $rule }] { $message } ({ $seconds } giây trước)
+alert-notification-title = ⚠️ RAM Manager: { $rule }
+alert-msg-system-usage = RAM hệ thống đang dùng { $value }% (ngưỡng { $threshold }%)
+alert-msg-available = Chỉ còn { $value } GB RAM trống (ngưỡng { $threshold } GB)
+alert-msg-process-rss = { $name } (PID { $pid }) đang dùng { $value } MB (ngưỡng { $threshold } MB)
+alert-msg-growth = { $name } (PID { $pid }) tăng { $value } MB/phút (ngưỡng { $threshold } MB/phút)
+err-alert-notification = Không thể hiện thông báo desktop: { $error }
+err-alert-webhook = Không thể gửi webhook tới { $url }: { $error }
+err-webhook-scheme = chỉ hỗ trợ URL http://
+err-webhook-host = thiếu hoặc không phân giải được host
+err-webhook-port = cổng "{ $port }" không hợp lệ
+err-webhook-status = server trả về { $status }
+
+# Helper có quyền
+helper-connected = 🔐 Helper: đã kết nối
+helper-unreachable = 🔐 Helper: không kết nối được
+helper-start = 🚀 Khởi động helper
+status-helper-launched = ⏳ Đang khởi động helper với quyền admin...
+cfg-helper-address = [helper] address "{ $address }" không hợp lệ: { $error }
+cfg-helper-max-pin = [helper] max_pin_mb = { $value } không hợp lệ (cho phép 1 - { $max })
+err-helper-not-loopback = chỉ chấp nhận địa chỉ loopback (127.0.0.1 hoặc ::1)
+err-helper-args = Tham số helper không hợp lệ: { $args }. Cú pháp: --helper [--token-file <đường dẫn>] [--listen <địa chỉ>] [--max-pin-mb <MB>]
+err-helper-bind = Helper không lắng nghe được trên { $address }: { $error }
+err-helper-connect = Không kết nối được tới helper ở { $address }: { $error }
+err-helper-launch = Không khởi động được helper: { $error }
+err-helper-auth = Helper từ chối: token không đúng
+err-helper-token = Không tạo được token cho helper: { $error }
+err-helper-version = Helper dùng giao thức v{ $expected }, client gửi v{ $actual }
+err-helper-protocol = Dữ liệu từ helper không đúng giao thức
+err-helper-closed = Helper đã đóng kết nối
+err-helper-bad-request = Helper từ chối lệnh không hợp lệ: { $error }
+err-helper-pid = Helper từ chối thao tác trên PID { $pid }
+err-helper-size = Helper từ chối kích thước { $mb } MB (cho phép 1 - { $max } MB)
+err-helper-pin-size = Helper từ chối Pin { $mb } MB (tối đa { $max } MB, đặt bằng --max-pin-mb)
+err-helper-oom-negative = Helper từ chối đặt oom_score_adj = { $value } cho PID { $pid }: giá trị âm chỉ được dùng để trả lại giá trị gốc
+err-helper-priority = Helper từ chối priority { $value }: ngoài các mức chương trình này đặt được
+err-helper-peer = Helper không xác định được người dùng ở đầu kia kết nối
+err-helper-not-owner = Helper từ chối PID { $pid }: tiến trình không thuộc người dùng đang kết nối
+err-helper-kernel-thread = Helper từ chối PID { $pid }: tiến trình của kernel/hệ thống
+err-helper-cgroup-owner = Helper từ chối cgroup { $path }: chưa được uỷ quyền cho người dùng đang kết nối hoặc chứa tiến trình của người khác
+
+# API điều khiển GUI đang chạy
+cfg-api-address = [api] address "{ $address }" không hợp lệ: { $error }
+err-api-args = Yêu cầu API không hợp lệ: { $args }. Cách dùng: --api effect-reports | apply-profile <tên> | revert-profile
+err-api-bind = API không lắng nghe được trên { $address }: { $error }
+err-api-connect = Không kết nối được tới RAM Manager đang chạy ở { $address } ([api] đã bật chưa?): { $error }
+err-api-auth = API từ chối: token không đúng
+err-api-version = API dùng giao thức v{ $expected }, client gửi v{ $actual }
+err-api-bad-request = API từ chối yêu cầu sai định dạng: { $error }
+err-api-protocol = Dữ liệu từ API không đúng giao thức
+err-api-closed = API đã đóng kết nối
+err-api-worker = RAM Manager không trả lời yêu cầu API kịp thời
+
+# Quyền trên Linux
+perm-heading = 🛡️ Quyền (Linux)
+perm-uid = UID hiệu dụng: { $uid }
+perm-capabilities = Capability
+perm-cap-sys-nice = Tăng priority, process_madvise lên tiến trình khác
+perm-cap-sys-resource = Vượt giới hạn tài nguyên, đặt rlimit cho tiến trình khác
+perm-cap-sys-ptrace = Đọc bộ nhớ / memory map của tiến trình thuộc người dùng khác
+perm-cgroup = Cgroup hiện tại
+perm-cgroup-v1 = Không dùng cgroup v2, các hành động qua cgroup không khả dụng
+perm-delegated = ✅ Được uỷ quyền nhánh { $path }
+perm-not-delegated = ❌ Không có nhánh cgroup nào được uỷ quyền cho người dùng này
+perm-file-writable = ghi được
+perm-file-read-only = chỉ đọc
+perm-file-missing = không có (controller memory chưa bật)
+perm-actions = Hành động
+perm-req-cgroup = quyền ghi { $file }
+perm-req-same-user = cùng người dùng với tiến trình
+perm-req-cgroup-release = quyền trả tiến trình về cgroup gốc và xoá cgroup riêng
+perm-pid-missing = PID { $pid }: { $reason }
+perm-select-process = Chọn một tiến trình để xem quyền cần cho từng hành động
+perm-target = Với PID { $pid }:
+perm-limit-cgroup = Cgroup riêng (Pin/Limit/Swap Limit): { $path }
+perm-or = { " hoặc " }
+perm-action-missing = { $action }: cần { $missing }
+perm-priority-missing = Cần CAP_SYS_NICE để tăng priority lên { $priority }
+perm-oom-missing = Cần CAP_SYS_RESOURCE để hạ oom_score_adj; Ghim vẫn chạy nhưng điểm OOM không đổi
+
+# Page-out trên Linux (process_madvise)
+pageout-hint = Gợi ý:
+pageout-hint-cold = ❄️ Cold (reclaim trước khi thiếu RAM)
+pageout-hint-pageout = 📤 Pageout (reclaim ngay)
+pageout-regions = Vùng nhớ:
+pageout-report = Lần trim gần nhất ({ $hint })
+pageout-col-kind = Loại
+pageout-col-rss = RSS trước → sau
+pageout-col-swap = Swap trước → sau
+pageout-freed = Đã giải phóng { $freed } RSS
+msg-paged-out = ✅ Đã trim PID { $pid } bằng { $hint }: giải phóng { $freed } RSS
+cfg-pageout-regions = [pageout] regions phải có ít nhất một loại vùng nhớ
+err-pidfd-open = Không mở được pidfd cho PID { $pid }: { $error }
+err-pidfd-gone = PID { $pid } đã thoát hoặc được dùng lại cho tiến trình khác trong lúc đọc memory map: { $error }
+err-process-madvise = process_madvise lên PID { $pid } thất bại: { $error }
+err-process-madvise-unsupported = Cần Linux 5.10 trở lên để dùng process_madvise
+err-signal = Không thể gửi tín hiệu tới PID { $pid }: { $error }
+err-suspend = Không thể đóng băng/rã đông tiến trình: { $error }
+err-freeze-pid = Không thể đóng băng PID { $pid } (PID hệ thống hoặc chính chương trình này)
+
+# Profile trên GUI
+status-applying-profile = ⏳ Đang áp dụng profile "{ $profile }"...
+status-launching = ⏳ Đang khởi chạy "{ $command }"...
+status-profile-not-found = ❌ Không tìm thấy profile "{ $profile }"
+status-reverting-profile = ⏳ Đang hoàn tác profile...
+profile-heading = 🎭 Profile
+profile-active = Đang chạy:
+profile-none = không có
+profile-apply = ▶ Áp dụng
+profile-revert = ↩ Hoàn tác
+profile-revert-hint = Đưa các tiến trình về đúng trạng thái trước khi áp dụng profile
+profile-history = 📜 Lịch sử
+profile-history-entry = { $summary } ({ $seconds } giây trước)
+
+# Hướng dẫn
+help-heading = ❓ Hướng dẫn
+help-pin = 🔒 Pin: Giữ tiến trình trong RAM vật lý
+help-trim = 🗜️ Trim: Giải phóng RAM không dùng
+help-limit = ⚠️ Limit: Giới hạn RAM tối đa
+help-freeze = 🧊 Freeze / 💤 Hibernate: Tạm dừng tiến trình (kèm trim), Restore để chạy tiếp
+help-restore = ♻️ Restore: Đưa về trạng thái ban đầu
+help-launch = 🚀 Khởi chạy có giới hạn: Chạy chương trình trong cgroup (Linux) / job object (Windows) mới với giới hạn đặt sẵn
+help-cgroup = 🗂️ Cgroup (Linux): Xem các systemd slice và container, Pin/Limit/Trim/Restore cả cgroup
+help-multi-select = ☑️ Ctrl+click / Shift+click: chọn nhiều tiến trình để xử lý hàng loạt
+help-inspector = 🔬 Memory map: các vùng heap/stack/ẩn danh/file/thư viện của tiến trình đang chọn kèm RSS, PSS, swap
+
+# OOM score (Linux)
+col-oom-hint = Điểm OOM (/proc/<pid>/oom_score): tiến trình điểm cao nhất bị OOM killer giết trước
+oom-score-adj = oom_score_adj: { $value }
+process-oom = 💀 OOM: { $score } (adj { $adj })
+oom-protect-pinned = 🛡️ Hạ oom_score_adj xuống
+oom-expose-limited = 🎯 Tăng oom_score_adj lên
+oom-option-hint = -1000 = không bao giờ bị OOM killer giết, 1000 = bị giết đầu tiên. Giá trị cũ được trả lại khi Khôi phục
+msg-oom-adjusted = 🛡️ oom_score_adj = { $value }
+msg-oom-failed = ⚠️ Không đổi được oom_score_adj: { $error }
+err-oom-score-adj = Không thể ghi oom_score_adj của PID { $pid }: { $error }
+err-oom-unsupported = oom_score_adj chỉ có trên Linux
+cfg-oom-score-adj = [oom] oom_score_adj { $value } phải nằm trong khoảng { $min } đến { $max }
+
+# Early-OOM
+early-oom-heading = 💀 Early-OOM
+early-oom-enabled = Tự kết thúc tiến trình khi sắp hết RAM
+early-oom-enabled-hint = Khi cả RAM còn dùng được và swap còn trống cùng dưới ngưỡng, tiến trình có điểm OOM cao nhất (không được bảo vệ) nhận SIGTERM, dưới một nửa ngưỡng thì SIGKILL
+early-oom-thresholds = 📉 Ngưỡng: RAM khả dụng < { $available }% và swap trống < { $swap }%
+early-oom-timing = ⏱️ Kiểm tra mỗi { $poll } s, chờ { $cooldown } s sau mỗi lần kết thúc
+early-oom-protect-pinned = tiến trình đã ghim
+early-oom-protected = 🛡️ Bảo vệ: { $list }
+early-oom-log = 📝 Log: { $path }
+early-oom-history = 📜 Các lần đã ra tay
+early-oom-history-empty = Chưa kết thúc tiến trình nào
+early-oom-history-entry = { $message } ({ $seconds } s trước)
+early-oom-terminated = 💀 Đã gửi SIGTERM tới { $name } (PID { $pid }, điểm { $badness }): RAM khả dụng { $available }%, swap trống { $swap }%
+early-oom-killed = 💀 Đã gửi SIGKILL tới { $name } (PID { $pid }, điểm { $badness }): RAM khả dụng { $available }%, swap trống { $swap }%
+early-oom-failed = ❌ Không kết thúc được { $name } (PID { $pid }, điểm { $badness }): RAM khả dụng { $available }%, swap trống { $swap }%
+err-terminate = Không thể kết thúc tiến trình: { $error }
+err-terminate-pid = Không kết thúc PID { $pid } (PID hệ thống hoặc chính chương trình này)
+cfg-early-oom-percent = [early_oom] ngưỡng { $value }% phải lớn hơn 0 và nhỏ hơn 100
+cfg-early-oom-interval = [early_oom] poll_interval_secs = { $poll } phải nằm trong khoảng { $min } đến { $max }, cooldown_secs = { $cooldown } trong khoảng 0 đến { $max_cooldown }
+launch-heading = 🚀 Khởi chạy có giới hạn
+launch-desc = Chạy chương trình trong một cgroup (Linux) hoặc job object (Windows) mới nên giới hạn có hiệu lực ngay từ lệnh đầu tiên. Chương trình chạy dưới quyền người dùng hiện tại, kể cả khi đang dùng helper.
+launch-command = Lệnh:
+launch-command-hint = VD: firefox --private-window hoặc "C:\Program Files\App\app.exe"
+launch-memory-max = Giới hạn cứng (memory.max / job memory)
+launch-memory-high = Kìm lại khi vượt (memory.high)
+launch-swap-max = Giới hạn swap (memory.swap.max)
+launch-priority = Priority:
+launch-priority-default = Giữ nguyên
+launch-button = 🚀 Khởi chạy
+launch-history = 📜 Chương trình đã thoát
+launch-history-entry = { $message } ({ $seconds } giây trước)
+launch-exited = 🏁 "{ $command }" (PID { $pid }) đã thoát với mã { $code }
+launch-signaled = 💀 "{ $command }" (PID { $pid }) bị kết thúc: { $status }
+launch-wait-failed = ❌ Không chờ được "{ $command }" (PID { $pid }): { $error }
+err-launch-empty = Chưa nhập lệnh để khởi chạy
+err-launch-quote = Lệnh có dấu nháy chưa đóng
+err-launch-spawn = Không khởi chạy được chương trình: { $error }
@@ -0,0 +1,548 @@
+use std::collections::{HashMap, VecDeque};
+use std::io::{Read, Write};
+use std::net::{IpAddr, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use crate::i18n::{num, t};
+use crate::profile::TargetSelector;
+use crate::ram_manager::{ProcessInfo, SystemInfo};
+
+const MAX_HISTORY: usize = 100;
+const GROWTH_WINDOW: Duration = Duration::from_secs(60);
+// Cần ít nhất chừng này dữ liệu thì tốc độ tăng mới không bị nhiễu bởi một lần nhảy vọt
+const MIN_GROWTH_SPAN: Duration = Duration::from_secs(15);
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(3);
+// Một tuần; lớn hơn (hoặc vô cực) thì Duration::from_secs_f32 panic
+const MAX_COOLDOWN_SECS: f32 = 604_800.0;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AlertConfig {
+    pub desktop_notifications: bool,
+    // Chỉ hỗ trợ http:// tới host loopback (dịch vụ chạy trên máy), nhận JSON qua POST
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    // Cùng một rule (và cùng tiến trình) không báo lại trong khoảng này
+    pub cooldown_secs: f32,
+    pub rules: Vec<AlertRule>,
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        AlertConfig {
+            desktop_notifications: true,
+            webhook_url: None,
+            cooldown_secs: 300.0,
+            rules: vec![
+                AlertRule {
+                    name: "ram-critical".to_string(),
+                    condition: AlertCondition::SystemUsagePercent { above: 90.0 },
+                    target: None,
+                    cooldown_secs: None,
+                },
+                AlertRule {
+                    name: "low-available".to_string(),
+                    condition: AlertCondition::AvailableGb { below: 1.0 },
+                    target: None,
+                    cooldown_secs: None,
+                },
+            ],
+        }
+    }
+}
+
+impl AlertConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        let valid_cooldown = |secs: f32| (0.0..=MAX_COOLDOWN_SECS).contains(&secs);
+        if !valid_cooldown(self.cooldown_secs) {
+            return Err(t!("cfg-alert-cooldown", value = self.cooldown_secs.to_string(), max = MAX_COOLDOWN_SECS.to_string()));
+        }
+        if let Some(url) = &self.webhook_url {
+            parse_http_url(url).map_err(|e| t!("cfg-alert-webhook", url = url.as_str(), error = e))?;
+        }
+        for (i, rule) in self.rules.iter().enumerate() {
+            if rule.name.trim().is_empty() {
+                return Err(t!("cfg-alert-name-empty", index = i));
+            }
+            if self.rules[..i].iter().any(|r| r.name == rule.name) {
+                return Err(t!("cfg-alert-duplicate", rule = rule.name.as_str()));
+            }
+            let threshold = rule.condition.threshold();
+            let valid = match rule.condition {
+                AlertCondition::SystemUsagePercent { above } => above > 0.0 && above < 100.0,
+                _ => threshold > 0.0,
+            };
+            if !valid {
+                return Err(t!("cfg-alert-threshold", rule = rule.name.as_str(), value = threshold.to_string()));
+            }
+            if rule.target.is_some() && !rule.condition.is_per_process() {
+                return Err(t!("cfg-alert-target", rule = rule.name.as_str()));
+            }
+            if let Some(secs) = rule.cooldown_secs.filter(|secs| !valid_cooldown(*secs)) {
+                return Err(t!("cfg-alert-cooldown", value = secs.to_string(), max = MAX_COOLDOWN_SECS.to_string()));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AlertRule {
+    pub name: String,
+    #[serde(flatten)]
+    pub condition: AlertCondition,
+    // Chỉ dùng cho điều kiện theo tiến trình, bỏ trống = mọi tiến trình
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<TargetSelector>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cooldown_secs: Option<f32>,
+}
+
+// condition = "system_usage_percent", above = 90.0 | condition = "available_gb", below = 1.0 |
+// condition = "process_rss_mb", above = 4096 | condition = "growth_mb_per_min", above = 200
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "condition", rename_all = "snake_case")]
+pub enum AlertCondition {
+    SystemUsagePercent { above: f64 },
+    AvailableGb { below: f64 },
+    ProcessRssMb { above: f64 },
+    GrowthMbPerMin { above: f64 },
+}
+
+impl AlertCondition {
+    pub fn is_per_process(&self) -> bool {
+        matches!(self, AlertCondition::ProcessRssMb { .. } | AlertCondition::GrowthMbPerMin { .. })
+    }
+
+    pub fn threshold(&self) -> f64 {
+        match self {
+            AlertCondition::SystemUsagePercent { above } => *above,
+            AlertCondition::AvailableGb { below } => *below,
+            AlertCondition::ProcessRssMb { above } => *above,
+            AlertCondition::GrowthMbPerMin { above } => *above,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        let threshold = num(self.threshold());
+        match self {
+            AlertCondition::SystemUsagePercent { .. } => t!("alert-cond-system-usage", threshold = threshold),
+            AlertCondition::AvailableGb { .. } => t!("alert-cond-available", threshold = threshold),
+            AlertCondition::ProcessRssMb { .. } => t!("alert-cond-process-rss", threshold = threshold),
+            AlertCondition::GrowthMbPerMin { .. } => t!("alert-cond-growth", threshold = threshold),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AlertEvent {
+    pub id: u64,
+    pub rule: String,
+    pub message: String,
+    pub at: SystemTime,
+    // Lỗi gửi thông báo desktop / webhook, được điền sau vì việc gửi chạy trên thread riêng
+    pub delivery_errors: Vec<String>,
+}
+
+// Nội dung POST tới webhook
+#[derive(Serialize)]
+struct WebhookPayload<'a> {
+    rule: &'a str,
+    message: &'a str,
+    timestamp: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pid: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    process: Option<&'a str>,
+    value: f64,
+    threshold: f64,
+}
+
+struct Delivery {
+    id: u64,
+    title: String,
+    body: String,
+    desktop: bool,
+    webhook: Option<(String, String)>,
+}
+
+// Đánh giá rule trên mỗi snapshot, thuộc về worker thread; việc gửi thông báo chạy trên thread riêng
+// để webhook chậm không làm trễ việc lấy mẫu
+pub struct AlertMonitor {
+    config: AlertConfig,
+    history: Vec<AlertEvent>,
+    next_id: u64,
+    last_fired: HashMap<(usize, Option<u32>), Instant>,
+    growth: HashMap<u32, VecDeque<(Instant, f64)>>,
+    deliveries: Sender<Delivery>,
+    results: Receiver<(u64, String)>,
+}
+
+impl AlertMonitor {
+    pub fn new(config: AlertConfig) -> Self {
+        let (delivery_tx, delivery_rx) = mpsc::channel::<Delivery>();
+        let (result_tx, result_rx) = mpsc::channel();
+        thread::Builder::new()
+            .name("ram-manager-alerts".to_string())
+            .spawn(move || {
+                for delivery in delivery_rx {
+                    for error in deliver(&delivery) {
+                        let _ = result_tx.send((delivery.id, error));
+                    }
+                }
+            })
+            .expect("Không thể khởi tạo thread gửi cảnh báo");
+
+        AlertMonitor {
+            config,
+            history: Vec::new(),
+            next_id: 0,
+            last_fired: HashMap::new(),
+            growth: HashMap::new(),
+            deliveries: delivery_tx,
+            results: result_rx,
+        }
+    }
+
+    pub fn history(&self) -> &[AlertEvent] {
+        &self.history
+    }
+
+    pub fn evaluate(&mut self, processes: &[ProcessInfo], system: &SystemInfo) {
+        let now = Instant::now();
+        for (id, error) in self.results.try_iter() {
+            if let Some(event) = self.history.iter_mut().find(|e| e.id == id) {
+                event.delivery_errors.push(error);
+            }
+        }
+        self.record_growth(processes, now);
+
+        let mut fired: Vec<(usize, Option<&ProcessInfo>, f64)> = Vec::new();
+        for (index, rule) in self.config.rules.iter().enumerate() {
+            let matching = processes
+                .iter()
+                .filter(|p| rule.target.as_ref().is_none_or(|target| target.matches(p.pid, &p.name)));
+            match rule.condition {
+                AlertCondition::SystemUsagePercent { above } => {
+                    let usage = system.used_ram_gb / system.total_ram_gb.max(f64::EPSILON) * 100.0;
+                    if usage > above {
+                        fired.push((index, None, usage));
+                    }
+                }
+                AlertCondition::AvailableGb { below } => {
+                    if system.available_ram_gb < below {
+                        fired.push((index, None, system.available_ram_gb));
+                    }
+                }
+                AlertCondition::ProcessRssMb { above } => {
+                    fired.extend(matching.filter(|p| p.memory_mb > above).map(|p| (index, Some(p), p.memory_mb)));
+                }
+                AlertCondition::GrowthMbPerMin { above } => {
+                    for p in matching {
+                        if let Some(rate) = self.growth_rate(p.pid).filter(|rate| *rate > above) {
+                            fired.push((index, Some(p), rate));
+                        }
+                    }
+                }
+            }
+        }
+
+        for (index, process, value) in fired {
+            let rule = &self.config.rules[index];
+            let cooldown = Duration::from_secs_f32(rule.cooldown_secs.unwrap_or(self.config.cooldown_secs));
+            let key = (index, process.map(|p| p.pid));
+            if self.last_fired.get(&key).is_some_and(|at| now.duration_since(*at) < cooldown) {
+                continue;
+            }
+            self.last_fired.insert(key, now);
+            self.fire(rule.clone(), process, value);
+        }
+
+        // Bỏ trạng thái của các PID đã thoát để PID dùng lại không bị tính nhầm
+        let alive = |pid: &u32| processes.iter().any(|p| p.pid == *pid);
+        self.growth.retain(|pid, _| alive(pid));
+        self.last_fired.retain(|(_, pid), _| pid.as_ref().is_none_or(alive));
+    }
+
+    fn record_growth(&mut self, processes: &[ProcessInfo], now: Instant) {
+        for p in processes {
+            let samples = self.growth.entry(p.pid).or_default();
+            samples.push_back((now, p.memory_mb));
+            while samples.front().is_some_and(|(at, _)| now.duration_since(*at) > GROWTH_WINDOW) {
+                samples.pop_front();
+            }
+        }
+    }
+
+    // MB/phút giữa sample cũ nhất và mới nhất trong cửa sổ
+    fn growth_rate(&self, pid: u32) -> Option<f64> {
+        let samples = self.growth.get(&pid)?;
+        let (first_at, first_mb) = samples.front()?;
+        let (last_at, last_mb) = samples.back()?;
+        let span = last_at.duration_since(*first_at);
+        (span >= MIN_GROWTH_SPAN).then(|| (last_mb - first_mb) / span.as_secs_f64() * 60.0)
+    }
+
+    fn fire(&mut self, rule: AlertRule, process: Option<&ProcessInfo>, value: f64) {
+        let threshold = rule.condition.threshold();
+        let (name, pid) = process.map(|p| (p.name.as_str(), p.pid)).unwrap_or(("", 0));
+        let message = match rule.condition {
+            AlertCondition::SystemUsagePercent { .. } => {
+                t!("alert-msg-system-usage", value = num(value), threshold = num(threshold))
+            }
+            AlertCondition::AvailableGb { .. } => {
+                t!("alert-msg-available", value = num(value), threshold = num(threshold))
+            }
+            AlertCondition::ProcessRssMb { .. } => {
+                t!("alert-msg-process-rss", name = name, pid = pid, value = num(value), threshold = num(threshold))
+            }
+            AlertCondition::GrowthMbPerMin { .. } => {
+                t!("alert-msg-growth", name = name, pid = pid, value = num(value), threshold = num(threshold))
+            }
+        };
+        self.notify(&rule.name, message, process, value, threshold);
+    }
+
+    // Ghi vào lịch sử và gửi thông báo desktop / webhook; early-OOM cũng báo qua đây với rule "early-oom"
+    pub fn notify(&mut self, rule: &str, message: String, process: Option<&ProcessInfo>, value: f64, threshold: f64) {
+        let id = self.next_id;
+        self.next_id += 1;
+        let at = SystemTime::now();
+        let webhook = self.config.webhook_url.as_ref().and_then(|url| {
+            let payload = WebhookPayload {
+                rule,
+                message: &message,
+                timestamp: at.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0),
+                pid: process.map(|p| p.pid),
+                process: process.map(|p| p.name.as_str()),
+                value,
+                threshold,
+            };
+            Some((url.clone(), serde_json::to_string(&payload).ok()?))
+        });
+        let _ = self.deliveries.send(Delivery {
+            id,
+            title: t!("alert-notification-title", rule = rule),
+            body: message.clone(),
+            desktop: self.config.desktop_notifications,
+            webhook,
+        });
+
+        self.history.push(AlertEvent {
+            id,
+            rule: rule.to_string(),
+            message,
+            at,
+            delivery_errors: Vec::new(),
+        });
+        if self.history.len() > MAX_HISTORY {
+            self.history.remove(0);
+        }
+    }
+}
+
+fn deliver(delivery: &Delivery) -> Vec<String> {
+    let mut errors = Vec::new();
+    if delivery.desktop {
+        let shown = notify_rust::Notification::new()
+            .appname("RAM Manager")
+            .summary(&delivery.title)
+            .body(&delivery.body)
+            .show();
+        if let Err(e) = shown {
+            errors.push(t!("err-alert-notification", error = e.to_string()));
+        }
+    }
+    if let Some((url, body)) = &delivery.webhook {
+        if let Err(e) = post_webhook(url, body) {
+            errors.push(t!("err-alert-webhook", url = url.as_str(), error = e));
+        }
+    }
+    errors
+}
+
+// http://host[:port][/path] -> ("host:port", "/path"); host IPv6 phải nằm trong [...]
+// Chỉ nhận host loopback (như helper/API) để cảnh báo không bị gửi ra máy khác
+fn parse_http_url(url: &str) -> Result<(String, String), String> {
+    let rest = url.strip_prefix("http://").ok_or_else(|| t!("err-webhook-scheme"))?;
+    let (authority, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+    let (host, port) = match authority.strip_prefix('[') {
+        Some(bracketed) => {
+            let (host, after) = bracketed.split_once(']').ok_or_else(|| t!("err-webhook-host"))?;
+            match after.strip_prefix(':') {
+                Some(port) => (host, Some(port)),
+                None if after.is_empty() => (host, None),
+                None => return Err(t!("err-webhook-host")),
+            }
+        }
+        None => match authority.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (authority, None),
+        },
+    };
+    if host.is_empty() {
+        return Err(t!("err-webhook-host"));
+    }
+    let port = match port {
+        Some(port) => port.parse::<u16>().map_err(|_| t!("err-webhook-port", port = port))?,
+        None => 80,
+    };
+    let loopback = host.eq_ignore_ascii_case("localhost") || host.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback());
+    if !loopback {
+        return Err(t!("err-helper-not-loopback"));
+    }
+    let authority = if host.contains(':') {
+        format!("[{}]:{}", host, port)
+    } else {
+        format!("{}:{}", host, port)
+    };
+    Ok((authority, path.to_string()))
+}
+
+fn post_webhook(url: &str, body: &str) -> Result<(), String> {
+    let (authority, path) = parse_http_url(url)?;
+    let addr = authority
+        .to_socket_addrs()
+        .map_err(|e| e.to_string())?
+        .find(|addr| addr.ip().is_loopback())
+        .ok_or_else(|| t!("err-webhook-host"))?;
+    let mut stream = TcpStream::connect_timeout(&addr, WEBHOOK_TIMEOUT).map_err(|e| e.to_string())?;
+    stream.set_read_timeout(Some(WEBHOOK_TIMEOUT)).map_err(|e| e.to_string())?;
+    stream.set_write_timeout(Some(WEBHOOK_TIMEOUT)).map_err(|e| e.to_string())?;
+
+    let request = format!(
+        "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        path,
+        authority,
+        body.len(),
+        body
+    );
+    stream.write_all(request.as_bytes()).map_err(|e| e.to_string())?;
+
+    // Chỉ cần dòng trạng thái "HTTP/1.1 200 OK"
+    let mut response = [0u8; 64];
+    let read = stream.read(&mut response).map_err(|e| e.to_string())?;
+    let status_line = String::from_utf8_lossy(&response[..read]);
+    let status = status_line.split_whitespace().nth(1).unwrap_or_default();
+    if status.starts_with('2') {
+        Ok(())
+    } else {
+        Err(t!("err-webhook-status", status = status_line.lines().next().unwrap_or_default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+    use crate::i18n::t;
+    use crate::profile::TargetSelector;
+    use crate::ram_manager::{ProcessInfo, SystemInfo};
+    use super::{parse_http_url, AlertCondition, AlertConfig, AlertMonitor, AlertRule};
+
+    fn rule(name: &str, condition: AlertCondition) -> AlertRule {
+        AlertRule { name: name.to_string(), condition, target: None, cooldown_secs: None }
+    }
+
+    // Không gửi thông báo desktop / webhook thật trong test
+    fn config(rules: Vec<AlertRule>, cooldown_secs: f32) -> AlertConfig {
+        AlertConfig { desktop_notifications: false, webhook_url: None, cooldown_secs, rules }
+    }
+
+    fn system() -> SystemInfo {
+        SystemInfo { total_ram_gb: 16.0, used_ram_gb: 8.0, available_ram_gb: 8.0, process_count: 2 }
+    }
+
+    #[test]
+    fn default_config_is_valid() {
+        assert!(AlertConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_bad_rules() {
+        let invalid = |rules: Vec<AlertRule>| config(rules, 60.0).validate().is_err();
+        assert!(invalid(vec![rule(" ", AlertCondition::AvailableGb { below: 1.0 })]));
+        assert!(invalid(vec![
+            rule("a", AlertCondition::AvailableGb { below: 1.0 }),
+            rule("a", AlertCondition::ProcessRssMb { above: 100.0 }),
+        ]));
+        assert!(invalid(vec![rule("a", AlertCondition::SystemUsagePercent { above: 100.0 })]));
+        assert!(invalid(vec![rule("a", AlertCondition::GrowthMbPerMin { above: 0.0 })]));
+
+        // target chỉ có nghĩa với điều kiện theo tiến trình
+        let mut targeted = rule("a", AlertCondition::AvailableGb { below: 1.0 });
+        targeted.target = Some(TargetSelector::Name("chrome".to_string()));
+        assert!(invalid(vec![targeted.clone()]));
+        targeted.condition = AlertCondition::ProcessRssMb { above: 100.0 };
+        assert!(!invalid(vec![targeted]));
+
+        let mut overlong = rule("a", AlertCondition::AvailableGb { below: 1.0 });
+        overlong.cooldown_secs = Some(f32::INFINITY);
+        assert!(invalid(vec![overlong]));
+        assert!(config(Vec::new(), -1.0).validate().is_err());
+    }
+
+    #[test]
+    fn parse_http_url_splits_authority_and_path() {
+        let parsed = parse_http_url;
+        assert_eq!(parsed("http://127.0.0.1:8080/ram-alert"), Ok(("127.0.0.1:8080".to_string(), "/ram-alert".to_string())));
+        assert_eq!(parsed("http://localhost"), Ok(("localhost:80".to_string(), "/".to_string())));
+        assert_eq!(parsed("http://[::1]/x"), Ok(("[::1]:80".to_string(), "/x".to_string())));
+        assert_eq!(parsed("http://[::1]:9000"), Ok(("[::1]:9000".to_string(), "/".to_string())));
+    }
+
+    #[test]
+    fn parse_http_url_rejects_invalid_and_remote_urls() {
+        assert_eq!(parse_http_url("https://127.0.0.1/x"), Err(t!("err-webhook-scheme")));
+        assert_eq!(parse_http_url("http:///x"), Err(t!("err-webhook-host")));
+        assert_eq!(parse_http_url("http://[::1/x"), Err(t!("err-webhook-host")));
+        assert_eq!(parse_http_url("http://127.0.0.1:http/x"), Err(t!("err-webhook-port", port = "http")));
+        assert_eq!(parse_http_url("http://example.com/hook"), Err(t!("err-helper-not-loopback")));
+        assert_eq!(parse_http_url("http://10.0.0.2:8080/hook"), Err(t!("err-helper-not-loopback")));
+        assert_eq!(parse_http_url("http://[fe80::1]/hook"), Err(t!("err-helper-not-loopback")));
+    }
+
+    #[test]
+    fn cooldown_suppresses_repeats_per_process() {
+        let processes = [ProcessInfo::sample(10, "big", None, 500.0), ProcessInfo::sample(11, "other", None, 500.0)];
+        let mut monitor = AlertMonitor::new(config(vec![rule("rss", AlertCondition::ProcessRssMb { above: 100.0 })], 300.0));
+        monitor.evaluate(&processes, &system());
+        // Mỗi tiến trình báo một lần, lần đánh giá sau còn trong cooldown
+        assert_eq!(monitor.history().len(), 2);
+        monitor.evaluate(&processes, &system());
+        assert_eq!(monitor.history().len(), 2);
+
+        // Cooldown riêng của rule ghi đè giá trị chung
+        let mut no_cooldown = rule("rss", AlertCondition::ProcessRssMb { above: 100.0 });
+        no_cooldown.cooldown_secs = Some(0.0);
+        let mut monitor = AlertMonitor::new(config(vec![no_cooldown], 300.0));
+        monitor.evaluate(&processes[..1], &system());
+        monitor.evaluate(&processes[..1], &system());
+        assert_eq!(monitor.history().len(), 2);
+    }
+
+    #[test]
+    fn growth_rate_uses_the_last_minute() {
+        let mut monitor = AlertMonitor::new(config(Vec::new(), 300.0));
+        let start = Instant::now();
+        let at = |secs: u64| start + Duration::from_secs(secs);
+        let record = |monitor: &mut AlertMonitor, secs: u64, mb: f64| {
+            monitor.record_growth(&[ProcessInfo::sample(7, "leaky", None, mb)], at(secs));
+        };
+
+        record(&mut monitor, 0, 100.0);
+        record(&mut monitor, 10, 110.0);
+        // Chưa đủ MIN_GROWTH_SPAN
+        assert_eq!(monitor.growth_rate(7), None);
+        record(&mut monitor, 30, 160.0);
+        assert_eq!(monitor.growth_rate(7), Some(120.0));
+        // Sample ở giây 0 và 10 đã ra khỏi cửa sổ 60 giây
+        record(&mut monitor, 75, 220.0);
+        assert_eq!(monitor.growth_rate(7), Some(80.0));
+        assert_eq!(monitor.growth_rate(8), None);
+    }
+}
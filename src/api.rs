@@ -0,0 +1,249 @@
+use std::fs;
+use std::io::BufReader;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use crate::config::Config;
+use crate::helper::{self, read_line, read_line_limited, tokens_match, write_line};
+use crate::i18n::t;
+use crate::worker::WorkerCommand;
+
+pub const PROTOCOL_VERSION: u32 = 1;
+const DEFAULT_ADDRESS: &str = "127.0.0.1:47216";
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+// Như helper: mỗi kết nối một luồng, giới hạn số luồng để script lỗi không mở vô hạn kết nối
+const MAX_CLIENTS: usize = 4;
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(10);
+// Worker có thể đang bận một lệnh chậm (batch, trim chờ đo) trước khi tới lượt yêu cầu của API
+const REPLY_TIMEOUT: Duration = Duration::from_secs(60);
+// Yêu cầu chỉ vài chục byte (giới hạn như helper), phản hồi có thể chứa cả danh sách báo cáo
+const MAX_RESPONSE_BYTES: u64 = 16 * 1024 * 1024;
+
+// API điều khiển GUI đang chạy từ script/công cụ khác, tắt mặc định
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ApiConfig {
+    pub enabled: bool,
+    pub address: String,
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        ApiConfig {
+            enabled: false,
+            address: DEFAULT_ADDRESS.to_string(),
+        }
+    }
+}
+
+impl ApiConfig {
+    // Giống helper: chỉ nhận kết nối từ chính máy này
+    pub fn socket_addr(&self) -> Result<SocketAddr, String> {
+        let addr: SocketAddr = self
+            .address
+            .parse()
+            .map_err(|e: std::net::AddrParseError| t!("cfg-api-address", address = self.address.as_str(), error = e.to_string()))?;
+        if !addr.ip().is_loopback() {
+            return Err(t!("cfg-api-address", address = self.address.as_str(), error = t!("err-helper-not-loopback")));
+        }
+        Ok(addr)
+    }
+}
+
+// Token riêng với helper: API chỉ điều khiển GUI của người dùng, không cần quyền admin
+pub fn token_path() -> PathBuf {
+    Config::path().with_file_name("api.token")
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Hello {
+    version: u32,
+    token: String,
+}
+
+// Mỗi dòng JSON một yêu cầu: {"request":"effect_reports"}, {"request":"apply_profile","name":"gaming"}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "request", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ApiRequest {
+    EffectReports,
+    // Như nút Áp dụng/Hoàn tác profile trên GUI, profile tra theo tên trong config.toml
+    ApplyProfile { name: String },
+    RevertProfile,
+}
+
+impl ApiRequest {
+    // `ram_manager --api effect-reports | apply-profile <tên> | revert-profile`
+    pub fn from_args(args: &[String]) -> Result<ApiRequest, String> {
+        match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+            ["effect-reports"] => Ok(ApiRequest::EffectReports),
+            ["apply-profile", name] => Ok(ApiRequest::ApplyProfile { name: name.to_string() }),
+            ["revert-profile"] => Ok(ApiRequest::RevertProfile),
+            _ => Err(t!("err-api-args", args = args.join(" "))),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum Response {
+    Ok { data: serde_json::Value },
+    Error { message: String },
+}
+
+// Lắng nghe trong GUI; mỗi yêu cầu được chuyển cho worker (nơi giữ RamManager) và chờ kết quả
+pub fn serve(config: &ApiConfig, commands: Sender<WorkerCommand>) -> Result<(), String> {
+    let token_file = token_path();
+    helper::ensure_token(&token_file)?;
+    let listener = TcpListener::bind(config.socket_addr()?)
+        .map_err(|e| t!("err-api-bind", address = config.address.as_str(), error = e.to_string()))?;
+    thread::Builder::new()
+        .name("ram-manager-api".to_string())
+        .spawn(move || {
+            // Kết nối chưa gửi gì không được chặn các kết nối khác
+            let active = Arc::new(AtomicUsize::new(0));
+            for stream in listener.incoming().flatten() {
+                if active.fetch_add(1, Ordering::SeqCst) >= MAX_CLIENTS {
+                    active.fetch_sub(1, Ordering::SeqCst);
+                    continue;
+                }
+                let token_file = token_file.clone();
+                let commands = commands.clone();
+                let active = Arc::clone(&active);
+                thread::spawn(move || {
+                    let _ = handle_client(stream, &token_file, &commands);
+                    active.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+        })
+        .map_err(|e| t!("err-api-bind", address = config.address.as_str(), error = e.to_string()))?;
+    Ok(())
+}
+
+fn handle_client(stream: TcpStream, token_file: &Path, commands: &Sender<WorkerCommand>) -> Result<(), String> {
+    if !stream.peer_addr().map_err(|e| e.to_string())?.ip().is_loopback() {
+        return Err(t!("err-helper-not-loopback"));
+    }
+    // Chưa xác thực thì chỉ chờ ngắn
+    stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT)).map_err(|e| e.to_string())?;
+    stream.set_write_timeout(Some(HANDSHAKE_TIMEOUT)).map_err(|e| e.to_string())?;
+    let mut writer = stream.try_clone().map_err(|e| e.to_string())?;
+    let mut reader = BufReader::new(stream);
+
+    let Some(line) = read_line(&mut reader)? else {
+        return Ok(());
+    };
+    let expected = fs::read_to_string(token_file).unwrap_or_default();
+    let expected = expected.trim();
+    let authenticated = match serde_json::from_str::<Hello>(&line) {
+        Ok(hello) if hello.version != PROTOCOL_VERSION => {
+            Err(t!("err-api-version", expected = PROTOCOL_VERSION, actual = hello.version))
+        }
+        Ok(hello) if !expected.is_empty() && tokens_match(&hello.token, expected) => Ok(()),
+        _ => Err(t!("err-api-auth")),
+    };
+    if let Err(message) = authenticated {
+        return write_line(&mut writer, &Response::Error { message });
+    }
+    write_line(&mut writer, &Response::Ok { data: serde_json::Value::Null })?;
+    reader.get_ref().set_read_timeout(Some(CLIENT_TIMEOUT)).map_err(|e| e.to_string())?;
+    writer.set_write_timeout(Some(CLIENT_TIMEOUT)).map_err(|e| e.to_string())?;
+
+    while let Some(line) = read_line(&mut reader)? {
+        let request: ApiRequest = match serde_json::from_str(&line) {
+            Ok(request) => request,
+            Err(e) => {
+                let message = t!("err-api-bad-request", error = e.to_string());
+                return write_line(&mut writer, &Response::Error { message });
+            }
+        };
+        let (reply_tx, reply_rx) = mpsc::channel();
+        let result = commands
+            .send(WorkerCommand::Api { request, reply: reply_tx })
+            .map_err(|_| t!("err-api-worker"))
+            .and_then(|_| reply_rx.recv_timeout(REPLY_TIMEOUT).map_err(|_| t!("err-api-worker"))?);
+        let response = match result {
+            Ok(data) => Response::Ok { data },
+            Err(message) => Response::Error { message },
+        };
+        write_line(&mut writer, &response)?;
+    }
+    Ok(())
+}
+
+// Client dòng lệnh: gửi một yêu cầu tới GUI đang chạy và trả về dữ liệu dạng JSON
+pub fn request(args: &[String]) -> Result<String, String> {
+    let request = ApiRequest::from_args(args)?;
+    let config = Config::load(&Config::path())?.api;
+    let address = config.socket_addr()?;
+    let token = helper::ensure_token(&token_path())?;
+
+    let stream = TcpStream::connect_timeout(&address, CLIENT_TIMEOUT)
+        .map_err(|e| t!("err-api-connect", address = address.to_string(), error = e.to_string()))?;
+    stream.set_read_timeout(Some(REPLY_TIMEOUT)).map_err(|e| e.to_string())?;
+    stream.set_write_timeout(Some(CLIENT_TIMEOUT)).map_err(|e| e.to_string())?;
+    let mut writer = stream.try_clone().map_err(|e| e.to_string())?;
+    let mut reader = BufReader::new(stream);
+
+    write_line(&mut writer, &Hello { version: PROTOCOL_VERSION, token })?;
+    read_response(&mut reader)?;
+    write_line(&mut writer, &request)?;
+    let data = read_response(&mut reader)?;
+    serde_json::to_string_pretty(&data).map_err(|e| e.to_string())
+}
+
+fn read_response(reader: &mut BufReader<TcpStream>) -> Result<serde_json::Value, String> {
+    let line = read_line_limited(reader, MAX_RESPONSE_BYTES)?.ok_or_else(|| t!("err-api-closed"))?;
+    match serde_json::from_str::<Response>(&line).map_err(|_| t!("err-api-protocol"))? {
+        Response::Ok { data } => Ok(data),
+        Response::Error { message } => Err(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ApiConfig, ApiRequest};
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn command_line_arguments_map_to_requests() {
+        assert_eq!(ApiRequest::from_args(&args(&["effect-reports"])), Ok(ApiRequest::EffectReports));
+        assert_eq!(
+            ApiRequest::from_args(&args(&["apply-profile", "gaming"])),
+            Ok(ApiRequest::ApplyProfile { name: "gaming".to_string() })
+        );
+        assert_eq!(ApiRequest::from_args(&args(&["revert-profile"])), Ok(ApiRequest::RevertProfile));
+    }
+
+    #[test]
+    fn unknown_or_incomplete_arguments_are_rejected() {
+        assert!(ApiRequest::from_args(&[]).is_err());
+        assert!(ApiRequest::from_args(&args(&["apply-profile"])).is_err());
+        assert!(ApiRequest::from_args(&args(&["revert-profile", "gaming"])).is_err());
+        assert!(ApiRequest::from_args(&args(&["shutdown"])).is_err());
+    }
+
+    #[test]
+    fn requests_use_the_documented_json_shape() {
+        let json = serde_json::to_string(&ApiRequest::ApplyProfile { name: "gaming".to_string() }).unwrap();
+        assert_eq!(json, r#"{"request":"apply_profile","name":"gaming"}"#);
+        assert!(serde_json::from_str::<ApiRequest>(r#"{"request":"apply_profile","name":"gaming","pid":1}"#).is_err());
+    }
+
+    #[test]
+    fn api_only_listens_on_loopback() {
+        let config = |address: &str| ApiConfig { enabled: true, address: address.to_string() };
+        assert!(config("127.0.0.1:47216").socket_addr().is_ok());
+        assert!(config("[::1]:47216").socket_addr().is_ok());
+        assert!(config("0.0.0.0:47216").socket_addr().is_err());
+        assert!(config("localhost").socket_addr().is_err());
+    }
+}
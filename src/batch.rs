@@ -0,0 +1,173 @@
+use std::collections::{BTreeSet, VecDeque};
+use crate::i18n::t;
+use crate::ram_manager::{ProcessAction, RamManager};
+
+// Click lên một dòng của danh sách: Ctrl/Cmd bật/tắt PID đó, Shift chọn thêm cả đoạn từ anchor tới PID
+// theo thứ tự đang hiển thị (order), click thường chỉ chọn một PID
+pub fn click(selected: &mut BTreeSet<u32>, anchor: &mut Option<u32>, pid: u32, toggle: bool, extend: bool, order: &[u32]) {
+    let anchor_range = anchor.and_then(|anchor| {
+        let from = order.iter().position(|&p| p == anchor)?;
+        let to = order.iter().position(|&p| p == pid)?;
+        Some(if from <= to { from..=to } else { to..=from })
+    });
+
+    if toggle {
+        if !selected.remove(&pid) {
+            selected.insert(pid);
+        }
+        *anchor = Some(pid);
+    } else if let (true, Some(range)) = (extend, anchor_range) {
+        selected.extend(order[range].iter().copied());
+    } else {
+        selected.clear();
+        selected.insert(pid);
+        *anchor = Some(pid);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BatchResult {
+    pub pid: u32,
+    pub name: String,
+    pub result: Result<String, String>,
+}
+
+// Hàng đợi áp dụng một hành động cho nhiều tiến trình, chạy từng PID một để GUI hiển thị tiến độ
+pub struct BatchJob {
+    pub action: ProcessAction,
+    pending: VecDeque<(u32, String)>,
+    pub total: usize,
+    pub results: Vec<BatchResult>,
+}
+
+impl BatchJob {
+    pub fn new(action: ProcessAction, targets: Vec<(u32, String)>) -> Self {
+        BatchJob {
+            action,
+            total: targets.len(),
+            pending: targets.into(),
+            results: Vec::new(),
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    pub fn progress(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.results.len() as f32 / self.total as f32
+        }
+    }
+
+    // Xử lý PID kế tiếp, trả về false khi đã hết việc
+    pub fn step(&mut self, manager: &mut RamManager) -> bool {
+        match self.pending.pop_front() {
+            Some((pid, name)) => {
+                let result = manager.apply_action(pid, &self.action);
+                self.results.push(BatchResult { pid, name, result });
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Ghi nhận kết quả do worker thread gửi về (phía GUI không tự chạy hành động)
+    pub fn record(&mut self, result: BatchResult) {
+        self.pending.retain(|(pid, _)| *pid != result.pid);
+        self.results.push(result);
+    }
+
+    pub fn succeeded(&self) -> usize {
+        self.results.iter().filter(|r| r.result.is_ok()).count()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.succeeded()
+    }
+
+    pub fn summary(&self) -> String {
+        t!(
+            "batch-summary",
+            icon = if self.failed() == 0 { "✅" } else { "⚠️" },
+            action = self.action.label(),
+            succeeded = self.succeeded(),
+            failed = self.failed(),
+            total = self.total
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use super::{click, BatchJob, BatchResult};
+    use crate::ram_manager::ProcessAction;
+
+    const ORDER: [u32; 5] = [50, 40, 30, 20, 10];
+
+    fn selected(pids: &BTreeSet<u32>) -> Vec<u32> {
+        pids.iter().copied().collect()
+    }
+
+    #[test]
+    fn plain_click_selects_only_that_process() {
+        let mut pids = BTreeSet::from([10, 20]);
+        let mut anchor = Some(10);
+        click(&mut pids, &mut anchor, 40, false, false, &ORDER);
+        assert_eq!(selected(&pids), vec![40]);
+        assert_eq!(anchor, Some(40));
+    }
+
+    #[test]
+    fn ctrl_click_toggles_and_moves_the_anchor() {
+        let mut pids = BTreeSet::new();
+        let mut anchor = None;
+        click(&mut pids, &mut anchor, 40, true, false, &ORDER);
+        click(&mut pids, &mut anchor, 20, true, false, &ORDER);
+        assert_eq!(selected(&pids), vec![20, 40]);
+        click(&mut pids, &mut anchor, 40, true, false, &ORDER);
+        assert_eq!(selected(&pids), vec![20]);
+        assert_eq!(anchor, Some(40));
+    }
+
+    #[test]
+    fn shift_click_adds_the_displayed_range_in_either_direction() {
+        let mut pids = BTreeSet::new();
+        let mut anchor = None;
+        click(&mut pids, &mut anchor, 20, false, false, &ORDER);
+        click(&mut pids, &mut anchor, 40, false, true, &ORDER);
+        assert_eq!(selected(&pids), vec![20, 30, 40]);
+        assert_eq!(anchor, Some(20));
+
+        click(&mut pids, &mut anchor, 10, false, true, &ORDER);
+        assert_eq!(selected(&pids), vec![10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn shift_click_without_a_visible_anchor_selects_one_process() {
+        let mut pids = BTreeSet::new();
+        let mut anchor = Some(99);
+        click(&mut pids, &mut anchor, 30, false, true, &ORDER);
+        assert_eq!(selected(&pids), vec![30]);
+        assert_eq!(anchor, Some(30));
+    }
+
+    #[test]
+    fn recorded_results_drive_progress_and_counts() {
+        let targets = vec![(1, "a".to_string()), (2, "b".to_string()), (3, "c".to_string())];
+        let mut job = BatchJob::new(ProcessAction::Trim, targets);
+        assert_eq!(job.progress(), 0.0);
+
+        job.record(BatchResult { pid: 2, name: "b".to_string(), result: Ok(String::new()) });
+        job.record(BatchResult { pid: 1, name: "a".to_string(), result: Err("denied".to_string()) });
+        assert!(!job.is_done());
+        assert_eq!((job.succeeded(), job.failed()), (1, 1));
+
+        job.record(BatchResult { pid: 3, name: "c".to_string(), result: Ok(String::new()) });
+        assert!(job.is_done());
+        assert_eq!(job.progress(), 1.0);
+    }
+}
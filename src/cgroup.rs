@@ -0,0 +1,81 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::i18n::t;
+use crate::memory_cap;
+
+pub const ROOT: &str = "/sys/fs/cgroup";
+
+// Chỉ hỗ trợ cgroup v2 (dòng `0::/đường/dẫn` trong /proc/<pid>/cgroup), cgroup v1 thì trả None
+pub fn parse(proc_cgroup: &str) -> Option<PathBuf> {
+    let relative = proc_cgroup.lines().find_map(|line| line.strip_prefix("0::"))?;
+    Some(Path::new(ROOT).join(relative.trim_start_matches('/')))
+}
+
+// None khi tiến trình đã thoát hoặc hệ thống dùng cgroup v1
+pub fn of_pid(pid: u32) -> Option<PathBuf> {
+    parse(&fs::read_to_string(format!("/proc/{}/cgroup", pid)).ok()?)
+}
+
+pub fn read(dir: &Path, file: &str) -> Result<String, String> {
+    let path = dir.join(file);
+    fs::read_to_string(&path)
+        .map(|text| text.trim().to_string())
+        .map_err(|e| t!("err-read-file", path = path.display().to_string(), error = e.to_string()))
+}
+
+pub fn write(dir: &Path, file: &str, value: &str) -> Result<(), String> {
+    let path = dir.join(file);
+    fs::write(&path, value).map_err(|e| t!("err-write-file", path = path.display().to_string(), error = e.to_string()))
+}
+
+// Giá trị cho memory.max/high/swap.max: "max" = không giới hạn
+pub fn limit_value(mb: Option<u64>) -> Result<String, String> {
+    match mb {
+        Some(mb) => memory_cap::mb_to_bytes::<u64>(mb).map(|bytes| bytes.to_string()),
+        None => Ok("max".to_string()),
+    }
+}
+
+// memory.swap.max / memory.zswap.max; memory.zswap.max có từ kernel 5.19, thiếu file mà không cần giới hạn thì bỏ qua
+pub fn write_swap_limits(dir: &Path, swap_max_mb: Option<u64>, zswap_max_mb: Option<u64>) -> Result<(), String> {
+    for (file, limit) in [("memory.swap.max", swap_max_mb), ("memory.zswap.max", zswap_max_mb)] {
+        if !dir.join(file).exists() {
+            if limit.is_some() {
+                return Err(t!("err-swap-control-missing", file = file, path = dir.display().to_string()));
+            }
+            continue;
+        }
+        write(dir, file, &limit_value(limit)?)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use super::{limit_value, parse};
+
+    #[test]
+    fn parse_uses_the_unified_hierarchy_line() {
+        let proc_cgroup = "12:memory:/user.slice\n0::/user.slice/user-1000.slice/app.scope\n";
+        assert_eq!(
+            parse(proc_cgroup),
+            Some(PathBuf::from("/sys/fs/cgroup/user.slice/user-1000.slice/app.scope"))
+        );
+        assert_eq!(parse("0::/\n"), Some(PathBuf::from("/sys/fs/cgroup")));
+    }
+
+    #[test]
+    fn parse_ignores_cgroup_v1_only_systems() {
+        assert_eq!(parse("4:memory:/user.slice\n1:name=systemd:/user.slice\n"), None);
+        assert_eq!(parse(""), None);
+    }
+
+    #[test]
+    fn limit_value_writes_bytes_or_max() {
+        assert_eq!(limit_value(None), Ok("max".to_string()));
+        assert_eq!(limit_value(Some(1)), Ok("1048576".to_string()));
+        assert_eq!(limit_value(Some(4096)), Ok("4294967296".to_string()));
+        assert!(limit_value(Some(u64::MAX)).is_err());
+    }
+}
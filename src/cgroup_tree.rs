@@ -0,0 +1,434 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use crate::config::Config;
+use crate::i18n::t;
+use crate::memory_cap::{BreachAction, LimitMode};
+use crate::ram_manager::ProcessAction;
+
+// Các file giới hạn mà hành động trên cây cgroup có thể ghi, cũng là những file Restore được phép ghi lại
+pub const RESTORABLE_FILES: [&str; 6] =
+    ["memory.min", "memory.high", "memory.max", "memory.swap.max", "memory.zswap.max", "memory.oom.group"];
+
+#[derive(Clone, Debug)]
+pub struct CgroupLimit {
+    pub file: &'static str,
+    // None là "max" (không giới hạn)
+    pub kb: Option<u64>,
+}
+
+// Một cgroup v2 cùng các cgroup con; giá trị None khi controller memory chưa được bật (VD: cgroup gốc)
+#[derive(Clone, Debug)]
+pub struct CgroupNode {
+    pub path: PathBuf,
+    pub name: String,
+    pub current_kb: Option<u64>,
+    pub swap_kb: Option<u64>,
+    pub stat: Vec<(&'static str, u64)>,
+    pub limits: Vec<CgroupLimit>,
+    // Chỉ tiến trình nằm trực tiếp trong cgroup này, không gồm cgroup con
+    pub pids: Vec<u32>,
+    pub children: Vec<CgroupNode>,
+}
+
+impl CgroupNode {
+    pub fn process_count(&self) -> usize {
+        self.pids.len() + self.children.iter().map(|c| c.process_count()).sum::<usize>()
+    }
+
+    // true nếu tên cgroup hoặc PID nào đó trong nhánh khớp với filter
+    pub fn matches(&self, filter: &str) -> bool {
+        let filter_lower = filter.to_lowercase();
+        self.matches_lower(filter, &filter_lower)
+    }
+
+    fn matches_lower(&self, filter: &str, filter_lower: &str) -> bool {
+        self.name.to_lowercase().contains(filter_lower)
+            || self.pids.iter().any(|pid| pid.to_string().contains(filter))
+            || self.children.iter().any(|c| c.matches_lower(filter, filter_lower))
+    }
+}
+
+pub fn find_node<'a>(node: &'a CgroupNode, path: &Path) -> Option<&'a CgroupNode> {
+    if node.path == path {
+        return Some(node);
+    }
+    node.children.iter().find_map(|child| find_node(child, path))
+}
+
+// Đọc toàn bộ cây từ cgroup gốc, các nhánh con xếp theo memory.current giảm dần
+pub fn scan() -> Result<CgroupNode, String> {
+    platform::scan()
+}
+
+// Pin/Trim/Limit/SwapLimit lên cả cgroup thay vì từng PID; Restore đi qua restore() với giá trị đã lưu
+pub fn apply(path: &Path, action: &ProcessAction) -> Result<(), String> {
+    platform::apply(path, action)
+}
+
+// Ghi lại đúng các giá trị đã lưu trước lần ghi đầu tiên (chỉ file trong RESTORABLE_FILES)
+pub fn restore(path: &Path, values: &[CgroupValue]) -> Result<(), String> {
+    for value in values {
+        let valid_number = !value.value.is_empty() && value.value.bytes().all(|b| b.is_ascii_digit());
+        if !RESTORABLE_FILES.contains(&value.file.as_str()) || !(valid_number || value.value == "max") {
+            return Err(t!("err-cgroup-restore-value", file = value.file.as_str(), value = value.value.as_str()));
+        }
+    }
+    platform::restore(path, values)
+}
+
+// Các file mà hành động sẽ ghi, theo đúng nhánh của platform::apply
+pub fn files_written(action: &ProcessAction) -> Vec<&'static str> {
+    match *action {
+        ProcessAction::Pin { .. } => vec!["memory.min"],
+        ProcessAction::Limit { mode: LimitMode::WorkingSet, .. } => vec!["memory.high"],
+        ProcessAction::Limit { mode: LimitMode::Hard, breach: BreachAction::Deny, .. } => vec!["memory.max"],
+        ProcessAction::Limit { mode: LimitMode::Hard, breach: BreachAction::Terminate, .. } => {
+            vec!["memory.max", "memory.swap.max", "memory.oom.group"]
+        }
+        ProcessAction::SwapLimit { .. } => vec!["memory.swap.max", "memory.zswap.max"],
+        ProcessAction::Trim | ProcessAction::Freeze | ProcessAction::Hibernate | ProcessAction::Restore => Vec::new(),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CgroupValue {
+    pub file: String,
+    pub value: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SavedCgroup {
+    pub path: PathBuf,
+    pub values: Vec<CgroupValue>,
+}
+
+// Giá trị gốc (do systemd/container runtime hay người dùng đặt) của mỗi file trước khi bị ghi lần đầu,
+// lưu ra file như ProfileSession để Restore vẫn đúng sau khi khởi động lại chương trình
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CgroupOriginals {
+    #[serde(default)]
+    pub cgroups: Vec<SavedCgroup>,
+}
+
+impl CgroupOriginals {
+    pub fn path() -> PathBuf {
+        Config::path().with_file_name("cgroup_originals.toml")
+    }
+
+    pub fn load(path: &Path) -> Result<CgroupOriginals, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text)
+                .map_err(|e| t!("err-syntax", path = path.display().to_string(), error = e.to_string())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(CgroupOriginals::default()),
+            Err(e) => Err(t!("err-read-file", path = path.display().to_string(), error = e.to_string())),
+        }
+    }
+
+    // Không còn gì để khôi phục thì xóa file
+    pub fn store(&self, path: &Path) -> Result<(), String> {
+        if self.cgroups.is_empty() {
+            return match fs::remove_file(path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                    Err(t!("err-delete-file", path = path.display().to_string(), error = e.to_string()))
+                }
+                _ => Ok(()),
+            };
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| t!("err-create-dir", path = dir.display().to_string(), error = e.to_string()))?;
+        }
+        let text = toml::to_string_pretty(self).map_err(|e| t!("err-serialize-session", error = e.to_string()))?;
+        fs::write(path, text).map_err(|e| t!("err-write-file", path = path.display().to_string(), error = e.to_string()))
+    }
+
+    pub fn get(&self, cgroup: &Path) -> Option<&SavedCgroup> {
+        self.cgroups.iter().find(|saved| saved.path == cgroup)
+    }
+
+    // Chỉ lưu file chưa có giá trị gốc: lần Limit thứ hai không được ghi đè giá trị của lần đầu.
+    // Trả về true khi có thêm giá trị mới
+    pub fn remember(&mut self, cgroup: &Path, files: &[&str], read: impl Fn(&str) -> Option<String>) -> bool {
+        let index = match self.cgroups.iter().position(|saved| saved.path == cgroup) {
+            Some(index) => index,
+            None => {
+                self.cgroups.push(SavedCgroup { path: cgroup.to_path_buf(), values: Vec::new() });
+                self.cgroups.len() - 1
+            }
+        };
+        let saved = &mut self.cgroups[index];
+        let mut changed = false;
+        for file in files {
+            if saved.values.iter().any(|value| value.file == *file) {
+                continue;
+            }
+            // File không tồn tại (VD: chưa bật swap accounting) thì cũng không bị ghi
+            if let Some(value) = read(file) {
+                saved.values.push(CgroupValue { file: file.to_string(), value });
+                changed = true;
+            }
+        }
+        if saved.values.is_empty() {
+            self.cgroups.remove(index);
+        }
+        changed
+    }
+
+    pub fn forget(&mut self, cgroup: &Path) {
+        self.cgroups.retain(|saved| saved.path != cgroup);
+    }
+}
+
+pub fn read_value(path: &Path, file: &str) -> Option<String> {
+    platform::read_value(path, file)
+}
+
+pub fn current_kb(path: &Path) -> Option<u64> {
+    platform::current_kb(path)
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::cmp::Reverse;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use super::{CgroupLimit, CgroupNode, CgroupValue};
+    use crate::cgroup;
+    use crate::i18n::t;
+    use crate::memory_cap::{BreachAction, LimitMode};
+    use crate::ram_manager::ProcessAction;
+
+    // Các dòng của memory.stat hiện trong bảng phân tích, theo thứ tự (đơn vị byte)
+    const STAT_KEYS: [&str; 8] = ["anon", "file", "kernel", "shmem", "slab", "sock", "pagetables", "zswap"];
+    const LIMIT_FILES: [&str; 6] = ["memory.min", "memory.low", "memory.high", "memory.max", "memory.swap.max", "memory.zswap.max"];
+
+    pub fn scan() -> Result<CgroupNode, String> {
+        let root = Path::new(cgroup::ROOT);
+        // cgroup.controllers chỉ có ở cgroup v2
+        if !root.join("cgroup.controllers").exists() {
+            return Err(t!("err-cgroup-tree-v2", path = cgroup::ROOT));
+        }
+        Ok(read_node(root))
+    }
+
+    fn read_node(dir: &Path) -> CgroupNode {
+        let mut children: Vec<CgroupNode> = fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir()))
+                    .map(|entry| read_node(&entry.path()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        children.sort_by_key(|node| Reverse(node.current_kb));
+
+        let stat = cgroup::read(dir, "memory.stat").unwrap_or_default();
+        CgroupNode {
+            name: match dir.strip_prefix(cgroup::ROOT) {
+                Ok(relative) if relative.as_os_str().is_empty() => "/".to_string(),
+                _ => dir.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default(),
+            },
+            current_kb: current_kb(dir),
+            swap_kb: read_kb(dir, "memory.swap.current"),
+            stat: STAT_KEYS
+                .iter()
+                .filter_map(|key| {
+                    let value = stat.lines().find_map(|line| line.strip_prefix(key)?.strip_prefix(' '))?;
+                    Some((*key, value.parse::<u64>().ok()? / 1024))
+                })
+                .collect(),
+            limits: LIMIT_FILES
+                .iter()
+                .filter_map(|file| {
+                    let value = cgroup::read(dir, file).ok()?;
+                    let kb = if value == "max" { None } else { Some(value.parse::<u64>().ok()? / 1024) };
+                    Some(CgroupLimit { file, kb })
+                })
+                .collect(),
+            pids: cgroup::read(dir, "cgroup.procs")
+                .map(|procs| procs.lines().filter_map(|pid| pid.parse().ok()).collect())
+                .unwrap_or_default(),
+            path: dir.to_path_buf(),
+            children,
+        }
+    }
+
+    fn read_kb(dir: &Path, file: &str) -> Option<u64> {
+        cgroup::read(dir, file).ok()?.parse::<u64>().ok().map(|bytes| bytes / 1024)
+    }
+
+    pub fn current_kb(path: &Path) -> Option<u64> {
+        read_kb(path, "memory.current")
+    }
+
+    // Lệnh có thể tới từ helper: chỉ nhận thư mục cgroup thật nằm dưới cgroup gốc (không phải chính nó)
+    fn checked_dir(path: &Path) -> Result<PathBuf, String> {
+        let invalid = || t!("err-cgroup-path", path = path.display().to_string());
+        let dir = fs::canonicalize(path).map_err(|_| invalid())?;
+        if dir == Path::new(cgroup::ROOT) || !dir.starts_with(cgroup::ROOT) || !dir.join("cgroup.procs").is_file() {
+            return Err(invalid());
+        }
+        Ok(dir)
+    }
+
+    pub fn apply(path: &Path, action: &ProcessAction) -> Result<(), String> {
+        let dir = checked_dir(path)?;
+        match *action {
+            // memory.min: bộ nhớ của cả cgroup không bị reclaim dưới mức này
+            ProcessAction::Pin { working_set_mb } => {
+                cgroup::write(&dir, "memory.min", &cgroup::limit_value(Some(working_set_mb as u64))?)
+            }
+            ProcessAction::Trim => trim(&dir),
+            // Chỉ ghi file của mode được chọn, không đụng tới giới hạn systemd/container runtime đã đặt ở file kia
+            ProcessAction::Limit { max_ws_mb, mode, breach } => {
+                let file = match mode {
+                    LimitMode::WorkingSet => "memory.high",
+                    LimitMode::Hard => "memory.max",
+                };
+                cgroup::write(&dir, file, &cgroup::limit_value(Some(max_ws_mb as u64))?)?;
+                if mode == LimitMode::Hard && breach == BreachAction::Terminate {
+                    if dir.join("memory.swap.max").exists() {
+                        cgroup::write(&dir, "memory.swap.max", "0")?;
+                    }
+                    cgroup::write(&dir, "memory.oom.group", "1")?;
+                }
+                Ok(())
+            }
+            ProcessAction::SwapLimit { swap_max_mb, zswap_max_mb } => {
+                cgroup::write_swap_limits(&dir, swap_max_mb, zswap_max_mb)
+            }
+            // Đóng băng cả slice có thể đóng băng luôn GUI hoặc phiên đăng nhập;
+            // Restore cần giá trị gốc nên đi qua restore()
+            ProcessAction::Freeze | ProcessAction::Hibernate | ProcessAction::Restore => {
+                Err(t!("err-cgroup-action-unsupported", action = action.label()))
+            }
+        }
+    }
+
+    // memory.reclaim (kernel ≥ 5.19) với toàn bộ memory.current; kernel trả EAGAIN khi không thu hồi đủ,
+    // phần đã thu hồi được vẫn giữ nên không coi là lỗi
+    fn trim(dir: &Path) -> Result<(), String> {
+        let current = cgroup::read(dir, "memory.current")?;
+        let path = dir.join("memory.reclaim");
+        match fs::write(&path, current) {
+            Err(e) if e.raw_os_error() != Some(libc::EAGAIN) => {
+                Err(t!("err-write-file", path = path.display().to_string(), error = e.to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn restore(path: &Path, values: &[CgroupValue]) -> Result<(), String> {
+        let dir = checked_dir(path)?;
+        for value in values {
+            if dir.join(&value.file).exists() {
+                cgroup::write(&dir, &value.file, &value.value)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn read_value(path: &Path, file: &str) -> Option<String> {
+        cgroup::read(path, file).ok()
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod platform {
+    use std::path::Path;
+    use super::{CgroupNode, CgroupValue};
+    use crate::i18n::t;
+    use crate::ram_manager::ProcessAction;
+
+    pub fn scan() -> Result<CgroupNode, String> {
+        Err(t!("err-cgroup-tree-unsupported"))
+    }
+
+    pub fn apply(_path: &Path, _action: &ProcessAction) -> Result<(), String> {
+        Err(t!("err-cgroup-tree-unsupported"))
+    }
+
+    pub fn restore(_path: &Path, _values: &[CgroupValue]) -> Result<(), String> {
+        Err(t!("err-cgroup-tree-unsupported"))
+    }
+
+    pub fn read_value(_path: &Path, _file: &str) -> Option<String> {
+        None
+    }
+
+    pub fn current_kb(_path: &Path) -> Option<u64> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::Path;
+    use crate::i18n::t;
+    use crate::memory_cap::{BreachAction, LimitMode};
+    use crate::ram_manager::ProcessAction;
+    use super::{files_written, restore, CgroupOriginals, CgroupValue, RESTORABLE_FILES};
+
+    fn value(file: &str, value: &str) -> CgroupValue {
+        CgroupValue { file: file.to_string(), value: value.to_string() }
+    }
+
+    #[test]
+    fn remember_keeps_the_first_original_value() {
+        let cgroup = Path::new("/sys/fs/cgroup/user.slice/app.scope");
+        let mut originals = CgroupOriginals::default();
+        let first = HashMap::from([("memory.high", "max"), ("memory.max", "8589934592")]);
+        assert!(originals.remember(cgroup, &["memory.high"], |file| first.get(file).map(|v| v.to_string())));
+
+        // Lần Limit thứ hai đọc được giá trị do chính lần đầu ghi, không được thay giá trị gốc
+        let second = HashMap::from([("memory.high", "1073741824"), ("memory.max", "8589934592")]);
+        assert!(originals.remember(cgroup, &["memory.high", "memory.max"], |file| second.get(file).map(|v| v.to_string())));
+        assert!(!originals.remember(cgroup, &["memory.high"], |file| second.get(file).map(|v| v.to_string())));
+
+        let saved = originals.get(cgroup).unwrap();
+        assert_eq!(saved.values, vec![value("memory.high", "max"), value("memory.max", "8589934592")]);
+
+        originals.forget(cgroup);
+        assert!(originals.get(cgroup).is_none());
+    }
+
+    #[test]
+    fn remember_skips_files_that_cannot_be_read() {
+        let cgroup = Path::new("/sys/fs/cgroup/app.scope");
+        let mut originals = CgroupOriginals::default();
+        assert!(!originals.remember(cgroup, &["memory.zswap.max"], |_| None));
+        assert_eq!(originals, CgroupOriginals::default());
+    }
+
+    #[test]
+    fn restore_rejects_files_and_values_it_did_not_save() {
+        let path = Path::new("/sys/fs/cgroup/app.scope");
+        for (file, bad) in [("memory.min", "-1"), ("memory.max", "1e9"), ("memory.high", ""), ("cgroup.procs", "1")] {
+            assert_eq!(
+                restore(path, &[value(file, bad)]),
+                Err(t!("err-cgroup-restore-value", file = file, value = bad))
+            );
+        }
+    }
+
+    #[test]
+    fn every_file_an_action_writes_can_be_restored() {
+        let actions = [
+            ProcessAction::Pin { working_set_mb: 512 },
+            ProcessAction::Limit { max_ws_mb: 512, mode: LimitMode::WorkingSet, breach: BreachAction::Deny },
+            ProcessAction::Limit { max_ws_mb: 512, mode: LimitMode::Hard, breach: BreachAction::Deny },
+            ProcessAction::Limit { max_ws_mb: 512, mode: LimitMode::Hard, breach: BreachAction::Terminate },
+            ProcessAction::SwapLimit { swap_max_mb: Some(0), zswap_max_mb: None },
+        ];
+        for action in actions.iter() {
+            let files = files_written(action);
+            assert!(!files.is_empty());
+            assert!(files.iter().all(|file| RESTORABLE_FILES.contains(file)));
+        }
+        assert_eq!(files_written(&actions[1]), vec!["memory.high"]);
+        assert!(files_written(&ProcessAction::Trim).is_empty());
+    }
+}
@@ -0,0 +1,435 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use crate::alerts::AlertConfig;
+use crate::api::ApiConfig;
+use crate::early_oom::EarlyOomConfig;
+use crate::helper::HelperConfig;
+use crate::memory_cap::{BreachAction, LimitMode};
+use crate::oom::OomConfig;
+use crate::pageout::PageoutConfig;
+use crate::i18n::{t, Language};
+use crate::profile::{self, Profile};
+
+// Cấu hình đọc từ config.toml, thiếu mục nào thì dùng giá trị mặc định của mục đó
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub actions: ActionDefaults,
+    pub sliders: SliderRanges,
+    pub sampling: SamplingConfig,
+    pub thresholds: Thresholds,
+    pub effects: EffectConfig,
+    pub pageout: PageoutConfig,
+    pub oom: OomConfig,
+    pub early_oom: EarlyOomConfig,
+    pub alerts: AlertConfig,
+    pub helper: HelperConfig,
+    pub api: ApiConfig,
+    pub ui: UiConfig,
+    pub profiles: Vec<Profile>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            actions: ActionDefaults::default(),
+            sliders: SliderRanges::default(),
+            sampling: SamplingConfig::default(),
+            thresholds: Thresholds::default(),
+            effects: EffectConfig::default(),
+            pageout: PageoutConfig::default(),
+            oom: OomConfig::default(),
+            early_oom: EarlyOomConfig::default(),
+            alerts: AlertConfig::default(),
+            helper: HelperConfig::default(),
+            api: ApiConfig::default(),
+            ui: UiConfig::default(),
+            profiles: profile::default_profiles(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ActionDefaults {
+    pub pin_working_set_mb: usize,
+    pub limit_max_ws_mb: usize,
+    pub limit_mode: LimitMode,
+    // Chỉ dùng khi limit_mode = "hard"
+    pub limit_breach: BreachAction,
+    // Giới hạn swap/zswap (MB) cho nút SwapLimit, bỏ trống là không giới hạn (chỉ Linux)
+    pub swap_max_mb: Option<u64>,
+    pub zswap_max_mb: Option<u64>,
+}
+
+impl Default for ActionDefaults {
+    fn default() -> Self {
+        ActionDefaults {
+            pin_working_set_mb: 512,
+            limit_max_ws_mb: 256,
+            limit_mode: LimitMode::default(),
+            limit_breach: BreachAction::default(),
+            swap_max_mb: Some(0),
+            zswap_max_mb: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SliderRanges {
+    pub pin_min_mb: usize,
+    pub pin_max_mb: usize,
+    pub limit_min_mb: usize,
+    pub limit_max_mb: usize,
+}
+
+impl Default for SliderRanges {
+    fn default() -> Self {
+        SliderRanges {
+            pin_min_mb: 128,
+            pin_max_mb: 4096,
+            limit_min_mb: 64,
+            limit_max_mb: 2048,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SamplingConfig {
+    pub auto_refresh: bool,
+    pub refresh_interval_secs: f32,
+    pub full_refresh_interval_secs: f32,
+}
+
+impl Default for SamplingConfig {
+    fn default() -> Self {
+        SamplingConfig {
+            auto_refresh: true,
+            refresh_interval_secs: 2.0,
+            full_refresh_interval_secs: 10.0,
+        }
+    }
+}
+
+impl SamplingConfig {
+    pub fn refresh_interval(&self) -> Duration {
+        Duration::from_secs_f32(self.refresh_interval_secs)
+    }
+
+    pub fn full_refresh_interval(&self) -> Duration {
+        Duration::from_secs_f32(self.full_refresh_interval_secs)
+    }
+}
+
+// Ngưỡng % RAM để đổi màu thanh RAM sang cam (warning) và đỏ (critical),
+// và ngưỡng page fault/giây để coi một tiến trình đang bị Limit là thrashing
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Thresholds {
+    pub ram_warning_percent: f64,
+    pub ram_critical_percent: f64,
+    pub thrashing_major_faults_per_sec: f64,
+    pub thrashing_faults_per_sec: f64,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Thresholds {
+            ram_warning_percent: 75.0,
+            ram_critical_percent: 90.0,
+            thrashing_major_faults_per_sec: 50.0,
+            thrashing_faults_per_sec: 5000.0,
+        }
+    }
+}
+
+// Sau mỗi hành động, đo working set của tiến trình trong `window_secs` giây, mỗi `sample_interval_secs` giây một lần
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EffectConfig {
+    pub window_secs: f32,
+    pub sample_interval_secs: f32,
+}
+
+impl Default for EffectConfig {
+    fn default() -> Self {
+        EffectConfig {
+            window_secs: 30.0,
+            sample_interval_secs: 1.0,
+        }
+    }
+}
+
+impl EffectConfig {
+    pub fn window(&self) -> Duration {
+        Duration::from_secs_f32(self.window_secs)
+    }
+
+    pub fn sample_interval(&self) -> Duration {
+        Duration::from_secs_f32(self.sample_interval_secs)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+}
+
+// font = "dejavu_sans" | "egui_default" | { file = "C:/Windows/Fonts/segoeui.ttf" }
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FontChoice {
+    #[default]
+    DejavuSans,
+    EguiDefault,
+    File(PathBuf),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UiConfig {
+    pub theme: Theme,
+    pub show_stats: bool,
+    // Không khai báo thì theo ngôn ngữ của hệ điều hành
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+    pub font: FontChoice,
+    pub scale: f32,
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        UiConfig {
+            theme: Theme::default(),
+            show_stats: false,
+            language: None,
+            font: FontChoice::default(),
+            scale: 1.0,
+        }
+    }
+}
+
+pub const MIN_REFRESH_INTERVAL_SECS: f32 = 0.5;
+pub const MAX_REFRESH_INTERVAL_SECS: f32 = 30.0;
+pub const MAX_FULL_REFRESH_INTERVAL_SECS: f32 = 3600.0;
+pub const MIN_EFFECT_INTERVAL_SECS: f32 = 0.2;
+pub const MAX_EFFECT_WINDOW_SECS: f32 = 600.0;
+pub const MIN_UI_SCALE: f32 = 0.5;
+pub const MAX_UI_SCALE: f32 = 3.0;
+
+impl Config {
+    // Đường dẫn config: biến môi trường RAM_MANAGER_CONFIG, nếu không có thì
+    // %APPDATA%\ram_manager\config.toml (hoặc ~/.config/ram_manager/config.toml)
+    pub fn path() -> PathBuf {
+        if let Some(path) = std::env::var_os("RAM_MANAGER_CONFIG") {
+            return PathBuf::from(path);
+        }
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("ram_manager")
+            .join("config.toml")
+    }
+
+    // File không tồn tại thì dùng cấu hình mặc định
+    pub fn load(path: &Path) -> Result<Config, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(t!("err-read-file", path = path.display().to_string(), error = e.to_string())),
+        };
+
+        let config: Config = toml::from_str(&text)
+            .map_err(|e| t!("err-syntax", path = path.display().to_string(), error = e.to_string()))?;
+        config
+            .validate()
+            .map_err(|e| t!("err-invalid-config", path = path.display().to_string(), error = e))?;
+        Ok(config)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        self.validate()?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| t!("err-create-dir", path = dir.display().to_string(), error = e.to_string()))?;
+        }
+        let text = toml::to_string_pretty(self)
+            .map_err(|e| t!("err-serialize-config", error = e.to_string()))?;
+        fs::write(path, text).map_err(|e| t!("err-write-file", path = path.display().to_string(), error = e.to_string()))
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let s = &self.sliders;
+        if s.pin_min_mb == 0 || s.pin_min_mb >= s.pin_max_mb {
+            return Err(t!("cfg-pin-range", min = s.pin_min_mb, max = s.pin_max_mb));
+        }
+        if s.limit_min_mb == 0 || s.limit_min_mb >= s.limit_max_mb {
+            return Err(t!("cfg-limit-range", min = s.limit_min_mb, max = s.limit_max_mb));
+        }
+
+        let a = &self.actions;
+        if !(s.pin_min_mb..=s.pin_max_mb).contains(&a.pin_working_set_mb) {
+            return Err(t!(
+                "cfg-pin-default",
+                value = a.pin_working_set_mb,
+                min = s.pin_min_mb,
+                max = s.pin_max_mb
+            ));
+        }
+        if !(s.limit_min_mb..=s.limit_max_mb).contains(&a.limit_max_ws_mb) {
+            return Err(t!(
+                "cfg-limit-default",
+                value = a.limit_max_ws_mb,
+                min = s.limit_min_mb,
+                max = s.limit_max_mb
+            ));
+        }
+
+        let r = &self.sampling;
+        if !(MIN_REFRESH_INTERVAL_SECS..=MAX_REFRESH_INTERVAL_SECS).contains(&r.refresh_interval_secs) {
+            return Err(t!(
+                "cfg-refresh-interval",
+                value = r.refresh_interval_secs.to_string(),
+                min = MIN_REFRESH_INTERVAL_SECS.to_string(),
+                max = MAX_REFRESH_INTERVAL_SECS.to_string()
+            ));
+        }
+        // Duration::from_secs_f32 panic với NaN/vô cực nên phải chặn ở đây
+        if !(r.refresh_interval_secs..=MAX_FULL_REFRESH_INTERVAL_SECS).contains(&r.full_refresh_interval_secs) {
+            return Err(t!(
+                "cfg-full-refresh",
+                value = r.full_refresh_interval_secs.to_string(),
+                min = r.refresh_interval_secs.to_string(),
+                max = MAX_FULL_REFRESH_INTERVAL_SECS.to_string()
+            ));
+        }
+
+        let t = &self.thresholds;
+        if !(t.ram_warning_percent > 0.0
+            && t.ram_warning_percent < t.ram_critical_percent
+            && t.ram_critical_percent <= 100.0)
+        {
+            return Err(t!(
+                "cfg-thresholds",
+                warning = t.ram_warning_percent,
+                critical = t.ram_critical_percent
+            ));
+        }
+        if !(t.thrashing_major_faults_per_sec > 0.0 && t.thrashing_faults_per_sec > 0.0) {
+            return Err(t!(
+                "cfg-thrashing",
+                major = t.thrashing_major_faults_per_sec,
+                total = t.thrashing_faults_per_sec
+            ));
+        }
+
+        let e = &self.effects;
+        if !(e.sample_interval_secs >= MIN_EFFECT_INTERVAL_SECS
+            && e.window_secs >= e.sample_interval_secs
+            && e.window_secs <= MAX_EFFECT_WINDOW_SECS)
+        {
+            return Err(t!(
+                "cfg-effects",
+                window = e.window_secs.to_string(),
+                interval = e.sample_interval_secs.to_string(),
+                min = MIN_EFFECT_INTERVAL_SECS.to_string(),
+                max = MAX_EFFECT_WINDOW_SECS.to_string()
+            ));
+        }
+
+        self.pageout.validate()?;
+        self.oom.validate()?;
+        self.early_oom.validate()?;
+        self.alerts.validate()?;
+        self.helper.validate()?;
+        self.api.socket_addr()?;
+
+        if !(MIN_UI_SCALE..=MAX_UI_SCALE).contains(&self.ui.scale) {
+            return Err(t!(
+                "cfg-ui-scale",
+                value = self.ui.scale.to_string(),
+                min = MIN_UI_SCALE.to_string(),
+                max = MAX_UI_SCALE.to_string()
+            ));
+        }
+
+        for (i, profile) in self.profiles.iter().enumerate() {
+            if profile.name.trim().is_empty() {
+                return Err(t!("cfg-profile-name-empty", index = i));
+            }
+            if self.profiles[..i].iter().any(|p| p.name == profile.name) {
+                return Err(t!("cfg-profile-duplicate", profile = profile.name.as_str()));
+            }
+            if profile.rules.is_empty() {
+                return Err(t!("cfg-profile-no-rules", profile = profile.name.as_str()));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn find_profile(&self, name: &str) -> Option<&Profile> {
+        self.profiles.iter().find(|p| p.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use super::{Config, MAX_FULL_REFRESH_INTERVAL_SECS};
+
+    #[test]
+    fn default_config_is_valid() {
+        assert_eq!(Config::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn full_refresh_interval_must_be_finite_and_not_below_refresh() {
+        let mut config = Config::default();
+        for value in [f32::INFINITY, f32::NAN, MAX_FULL_REFRESH_INTERVAL_SECS + 1.0, config.sampling.refresh_interval_secs / 2.0] {
+            config.sampling.full_refresh_interval_secs = value;
+            assert!(config.validate().is_err(), "full_refresh_interval_secs = {}", value);
+        }
+        config.sampling.full_refresh_interval_secs = MAX_FULL_REFRESH_INTERVAL_SECS;
+        assert_eq!(config.validate(), Ok(()));
+    }
+
+    #[test]
+    fn early_oom_and_alert_timings_must_be_finite() {
+        let mut config = Config::default();
+        config.early_oom.cooldown_secs = f32::INFINITY;
+        assert!(config.validate().is_err());
+
+        let mut config = Config::default();
+        config.early_oom.poll_interval_secs = f32::NAN;
+        assert!(config.validate().is_err());
+
+        let mut config = Config::default();
+        config.alerts.cooldown_secs = f32::INFINITY;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn load_rejects_invalid_values_instead_of_panicking_later() {
+        let dir = std::env::temp_dir().join(format!("ram_manager-config-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+
+        // Thiếu file thì dùng cấu hình mặc định
+        assert!(Config::load(&path).is_ok());
+
+        fs::write(&path, "[sampling]\nfull_refresh_interval_secs = inf\n").unwrap();
+        assert!(Config::load(&path).is_err());
+
+        fs::write(&path, "[sampling]\nfull_refresh_interval_secs = 60.0\n").unwrap();
+        assert_eq!(Config::load(&path).unwrap().sampling.full_refresh_interval_secs, 60.0);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
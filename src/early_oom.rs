@@ -0,0 +1,356 @@
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use crate::config::Config;
+use crate::i18n::{num, t};
+use crate::oom;
+use crate::profile::TargetSelector;
+use crate::ram_manager::{ProcessInfo, ProcessStatus};
+use crate::system_memory::SystemMemoryBreakdown;
+
+const MAX_HISTORY: usize = 100;
+const MIN_POLL_INTERVAL_SECS: f32 = 0.1;
+const MAX_POLL_INTERVAL_SECS: f32 = 60.0;
+const MAX_COOLDOWN_SECS: f32 = 3600.0;
+// Tiến trình hệ thống của Windows: kết thúc một trong số này là màn hình xanh, đăng xuất hoặc mất dịch vụ
+const WINDOWS_CRITICAL: &[&str] = &[
+    "System",
+    "Registry",
+    "Memory Compression",
+    "Secure System",
+    "smss.exe",
+    "csrss.exe",
+    "wininit.exe",
+    "winlogon.exe",
+    "services.exe",
+    "lsass.exe",
+    "LsaIso.exe",
+    "svchost.exe",
+    "dwm.exe",
+    "fontdrvhost.exe",
+];
+
+// Kết thúc tiến trình trước khi hệ thống bị treo vì thiếu RAM (giống earlyoom), mặc định tắt
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EarlyOomConfig {
+    pub enabled: bool,
+    // Chỉ ra tay khi cả RAM còn dùng được và swap còn trống cùng dưới ngưỡng (% tổng)
+    pub available_percent: f64,
+    pub swap_free_percent: f64,
+    pub poll_interval_secs: f32,
+    // Sau mỗi lần kết thúc tiến trình, chờ kernel thu hồi bộ nhớ rồi mới xét tiếp
+    pub cooldown_secs: f32,
+    pub protect_pinned: bool,
+    pub protected: Vec<TargetSelector>,
+}
+
+impl Default for EarlyOomConfig {
+    fn default() -> Self {
+        EarlyOomConfig {
+            enabled: false,
+            available_percent: 5.0,
+            swap_free_percent: 10.0,
+            poll_interval_secs: 1.0,
+            cooldown_secs: 5.0,
+            protect_pinned: true,
+            protected: Vec::new(),
+        }
+    }
+}
+
+impl EarlyOomConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        for value in [self.available_percent, self.swap_free_percent] {
+            if !(value > 0.0 && value < 100.0) {
+                return Err(t!("cfg-early-oom-percent", value = value.to_string()));
+            }
+        }
+        // Giá trị vô cực/NaN làm Duration::from_secs_f32 panic trong worker
+        if !((MIN_POLL_INTERVAL_SECS..=MAX_POLL_INTERVAL_SECS).contains(&self.poll_interval_secs)
+            && (0.0..=MAX_COOLDOWN_SECS).contains(&self.cooldown_secs))
+        {
+            return Err(t!(
+                "cfg-early-oom-interval",
+                poll = self.poll_interval_secs.to_string(),
+                cooldown = self.cooldown_secs.to_string(),
+                min = MIN_POLL_INTERVAL_SECS.to_string(),
+                max = MAX_POLL_INTERVAL_SECS.to_string(),
+                max_cooldown = MAX_COOLDOWN_SECS.to_string()
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_secs_f32(self.poll_interval_secs)
+    }
+
+    fn is_protected(&self, process: &ProcessInfo) -> bool {
+        // Kernel cũng không bao giờ chọn tiến trình có oom_score_adj = -1000
+        (self.protect_pinned && process.status == ProcessStatus::Pinned)
+            || process.oom.is_some_and(|score| score.adj == oom::SCORE_ADJ_MIN)
+            || (cfg!(windows) && is_windows_critical(&process.name))
+            || self.protected.iter().any(|target| target.matches(process.pid, &process.name))
+    }
+}
+
+fn is_windows_critical(name: &str) -> bool {
+    WINDOWS_CRITICAL.iter().any(|critical| critical.eq_ignore_ascii_case(name))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MemoryPressure {
+    pub available_percent: f64,
+    pub swap_free_percent: f64,
+}
+
+impl MemoryPressure {
+    // Không có swap thì coi như swap đã cạn
+    pub fn read() -> Result<MemoryPressure, String> {
+        let memory = SystemMemoryBreakdown::read()?;
+        let percent = |part: u64, total: u64| if total == 0 { 0.0 } else { part as f64 / total as f64 * 100.0 };
+        Ok(MemoryPressure {
+            available_percent: percent(memory.available_kb, memory.total_kb),
+            swap_free_percent: percent(memory.swap_free_kb, memory.swap_total_kb),
+        })
+    }
+
+    fn below(&self, available_percent: f64, swap_free_percent: f64) -> bool {
+        self.available_percent < available_percent && self.swap_free_percent < swap_free_percent
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EarlyOomEvent {
+    pub at: SystemTime,
+    pub pid: u32,
+    pub name: String,
+    pub badness: i64,
+    pub pressure: MemoryPressure,
+    // SIGKILL thay vì SIGTERM khi bộ nhớ đã xuống dưới một nửa ngưỡng
+    pub forced: bool,
+    pub result: Result<(), String>,
+    pub log_error: Option<String>,
+}
+
+impl EarlyOomEvent {
+    pub fn message(&self) -> String {
+        let key = match (&self.result, self.forced) {
+            (Ok(()), false) => "early-oom-terminated",
+            (Ok(()), true) => "early-oom-killed",
+            (Err(_), _) => "early-oom-failed",
+        };
+        let message = t!(
+            key,
+            name = self.name.as_str(),
+            pid = self.pid,
+            badness = self.badness,
+            available = num(self.pressure.available_percent),
+            swap = num(self.pressure.swap_free_percent)
+        );
+        match &self.result {
+            Ok(()) => message,
+            Err(e) => format!("{}: {}", message, e),
+        }
+    }
+}
+
+// Thuộc về worker thread, lấy mẫu /proc/meminfo theo nhịp riêng không phụ thuộc auto refresh
+pub struct EarlyOom {
+    config: EarlyOomConfig,
+    next_poll: Instant,
+    cooldown_until: Option<Instant>,
+    // PID đã kết thúc không được (thiếu quyền, tiến trình được bảo vệ...): bỏ qua để lần sau chọn tiến trình khác
+    failed: HashSet<u32>,
+    history: Vec<EarlyOomEvent>,
+}
+
+impl EarlyOom {
+    pub fn new(config: EarlyOomConfig) -> Self {
+        EarlyOom {
+            config,
+            next_poll: Instant::now(),
+            cooldown_until: None,
+            failed: HashSet::new(),
+            history: Vec::new(),
+        }
+    }
+
+    pub fn set_config(&mut self, config: EarlyOomConfig) {
+        self.config = config;
+        self.next_poll = Instant::now();
+    }
+
+    pub fn config(&self) -> &EarlyOomConfig {
+        &self.config
+    }
+
+    pub fn history(&self) -> &[EarlyOomEvent] {
+        &self.history
+    }
+
+    // None khi đang tắt
+    pub fn next_poll(&self) -> Option<Instant> {
+        self.config.enabled.then_some(self.next_poll)
+    }
+
+    // Some khi RAM và swap cùng dưới ngưỡng và đã hết thời gian chờ sau lần kết thúc trước;
+    // bool là có cần SIGKILL hay không
+    pub fn check(&mut self) -> Option<(MemoryPressure, bool)> {
+        let now = Instant::now();
+        self.next_poll = now + self.config.poll_interval();
+        if self.cooldown_until.is_some_and(|until| now < until) {
+            return None;
+        }
+        let pressure = MemoryPressure::read().ok()?;
+        let config = &self.config;
+        if !pressure.below(config.available_percent, config.swap_free_percent) {
+            return None;
+        }
+        let forced = pressure.below(config.available_percent / 2.0, config.swap_free_percent / 2.0);
+        Some((pressure, forced))
+    }
+
+    // Điểm OOM của kernel nếu có (Linux), không thì dùng RAM đang chiếm (MB)
+    pub fn select_victim<'a>(&mut self, processes: &'a [ProcessInfo]) -> Option<(&'a ProcessInfo, i64)> {
+        let own_pid = std::process::id();
+        // PID đã thoát có thể được dùng lại cho tiến trình khác
+        self.failed.retain(|pid| processes.iter().any(|p| p.pid == *pid));
+        processes
+            .iter()
+            .filter(|p| p.pid > 1 && p.pid != own_pid && !self.failed.contains(&p.pid) && !self.config.is_protected(p))
+            .map(|p| (p, p.oom.map_or(p.memory_mb as i64, |oom| oom.score as i64)))
+            .max_by(|(a, a_badness), (b, b_badness)| {
+                a_badness.cmp(b_badness).then(a.memory_mb.total_cmp(&b.memory_mb))
+            })
+    }
+
+    pub fn record(&mut self, mut event: EarlyOomEvent) -> EarlyOomEvent {
+        self.cooldown_until = Some(Instant::now() + Duration::from_secs_f32(self.config.cooldown_secs));
+        if event.result.is_err() {
+            self.failed.insert(event.pid);
+        }
+        event.log_error = append_log(&event).err();
+        self.history.push(event.clone());
+        if self.history.len() > MAX_HISTORY {
+            self.history.remove(0);
+        }
+        event
+    }
+}
+
+pub fn log_path() -> PathBuf {
+    Config::path().with_file_name("early-oom.log")
+}
+
+// Mỗi quyết định một dòng: thời điểm (giây Unix) và nội dung như thông báo
+fn append_log(event: &EarlyOomEvent) -> Result<(), String> {
+    let path = log_path();
+    let timestamp = event.at.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| t!("err-create-dir", path = dir.display().to_string(), error = e.to_string()))?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{} {}", timestamp, event.message()))
+        .map_err(|e| t!("err-write-file", path = path.display().to_string(), error = e.to_string()))
+}
+
+// Gửi SIGTERM (hoặc SIGKILL khi force) trên Linux, TerminateProcess trên Windows
+pub fn terminate(pid: u32, force: bool) -> Result<(), String> {
+    if pid <= 1 || pid == std::process::id() {
+        return Err(t!("err-terminate-pid", pid = pid));
+    }
+    platform::terminate(pid, force)
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::io;
+    use crate::i18n::t;
+
+    pub fn terminate(pid: u32, force: bool) -> Result<(), String> {
+        let signal = if force { libc::SIGKILL } else { libc::SIGTERM };
+        if unsafe { libc::kill(pid as libc::pid_t, signal) } != 0 {
+            return Err(t!("err-signal", pid = pid, error = io::Error::last_os_error().to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{OpenProcess, TerminateProcess, PROCESS_TERMINATE};
+    use crate::i18n::t;
+
+    // Windows không có cách kết thúc "nhẹ nhàng" một tiến trình bất kỳ nên force không đổi gì
+    pub fn terminate(pid: u32, _force: bool) -> Result<(), String> {
+        unsafe {
+            let handle = OpenProcess(PROCESS_TERMINATE, false, pid)
+                .map_err(|e| t!("err-open-process", error = format!("{:?}", e)))?;
+            let result = TerminateProcess(handle, 1).map_err(|e| t!("err-terminate", error = format!("{:?}", e)));
+            let _ = CloseHandle(handle);
+            result
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::profile::TargetSelector;
+    use crate::ram_manager::{ProcessInfo, ProcessStatus};
+    use super::{is_windows_critical, EarlyOom, EarlyOomConfig};
+
+    fn processes() -> Vec<ProcessInfo> {
+        let mut pinned = ProcessInfo::sample(30, "database", None, 4000.0);
+        pinned.status = ProcessStatus::Pinned;
+        vec![
+            ProcessInfo::sample(10, "editor", None, 500.0),
+            ProcessInfo::sample(20, "browser", None, 3000.0),
+            pinned,
+            ProcessInfo::sample(1, "init", None, 9000.0),
+        ]
+    }
+
+    fn victim(early_oom: &mut EarlyOom, processes: &[ProcessInfo]) -> Option<u32> {
+        early_oom.select_victim(processes).map(|(p, _)| p.pid)
+    }
+
+    #[test]
+    fn largest_unprotected_process_is_chosen() {
+        let processes = processes();
+        assert_eq!(victim(&mut EarlyOom::new(EarlyOomConfig::default()), &processes), Some(20));
+
+        let config = EarlyOomConfig {
+            protected: vec![TargetSelector::Name("browser".to_string())],
+            ..EarlyOomConfig::default()
+        };
+        assert_eq!(victim(&mut EarlyOom::new(config), &processes), Some(10));
+    }
+
+    #[test]
+    fn processes_that_could_not_be_terminated_are_skipped_until_they_exit() {
+        let mut early_oom = EarlyOom::new(EarlyOomConfig::default());
+        let processes = processes();
+        early_oom.failed.insert(20);
+        assert_eq!(victim(&mut early_oom, &processes), Some(10));
+
+        // PID 20 đã thoát: lần sau PID đó (nếu được dùng lại) không còn bị bỏ qua
+        let without_browser: Vec<ProcessInfo> = processes.iter().filter(|p| p.pid != 20).cloned().collect();
+        victim(&mut early_oom, &without_browser);
+        assert!(early_oom.failed.is_empty());
+    }
+
+    #[test]
+    fn windows_critical_names_ignore_case() {
+        assert!(is_windows_critical("CSRSS.EXE"));
+        assert!(!is_windows_critical("chrome.exe"));
+    }
+}
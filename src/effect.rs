@@ -0,0 +1,390 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+use serde::{Deserialize, Serialize};
+use crate::config::Config;
+use crate::i18n::t;
+use crate::ram_manager::ProcessAction;
+
+const MAX_FINISHED_REPORTS: usize = 50;
+// Giảm dưới mức này coi như hành động không giải phóng được gì
+const MIN_FREED_MB: f64 = 1.0;
+// Lấy lại từ mức này trở lên coi như bộ nhớ đã bị fault ngược vào gần hết
+const REVERTED_PERCENT: f64 = 80.0;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EffectSample {
+    pub offset_secs: f64,
+    pub working_set_mb: f64,
+    // Số page fault tích luỹ của tiến trình tại thời điểm sample
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faults: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EffectVerdict {
+    Measuring,
+    NoEffect,
+    Effective,
+    Partial,
+    Reverted,
+}
+
+impl EffectVerdict {
+    pub fn label(&self) -> String {
+        match self {
+            EffectVerdict::Measuring => t!("effect-measuring"),
+            EffectVerdict::NoEffect => t!("effect-no-effect"),
+            EffectVerdict::Effective => t!("effect-effective"),
+            EffectVerdict::Partial => t!("effect-partial"),
+            EffectVerdict::Reverted => t!("effect-reverted"),
+        }
+    }
+
+    pub fn color(&self) -> [u8; 3] {
+        match self {
+            EffectVerdict::Measuring => [128, 128, 128],
+            EffectVerdict::NoEffect => [149, 165, 166],
+            EffectVerdict::Effective => [46, 204, 113],
+            EffectVerdict::Partial => [241, 196, 15],
+            EffectVerdict::Reverted => [231, 76, 60],
+        }
+    }
+}
+
+// Diễn biến working set của một tiến trình trong cửa sổ đo sau một hành động
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EffectReport {
+    pub pid: u32,
+    pub name: String,
+    pub action: ProcessAction,
+    pub started_at: SystemTime,
+    pub window_secs: f64,
+    pub before_mb: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_faults: Option<u64>,
+    pub samples: Vec<EffectSample>,
+    pub finished: bool,
+    // Dừng đo sớm vì tiến trình đã thoát hoặc có hành động mới trên cùng PID
+    pub interrupted: bool,
+}
+
+impl EffectReport {
+    fn lowest(&self) -> Option<&EffectSample> {
+        self.samples.iter().min_by(|a, b| a.working_set_mb.total_cmp(&b.working_set_mb))
+    }
+
+    pub fn lowest_mb(&self) -> Option<f64> {
+        self.lowest().map(|s| s.working_set_mb)
+    }
+
+    pub fn latest_mb(&self) -> Option<f64> {
+        self.samples.last().map(|s| s.working_set_mb)
+    }
+
+    // Lượng RAM giải phóng được ở thời điểm working set thấp nhất
+    pub fn freed_mb(&self) -> Option<f64> {
+        self.lowest_mb().map(|lowest| (self.before_mb - lowest).max(0.0))
+    }
+
+    // Phần đã giải phóng nhưng bị fault ngược vào lại tính đến sample cuối
+    pub fn refaulted_mb(&self) -> Option<f64> {
+        Some((self.latest_mb()? - self.lowest_mb()?).max(0.0))
+    }
+
+    pub fn refault_percent(&self) -> Option<f64> {
+        let freed = self.freed_mb().filter(|freed| *freed >= MIN_FREED_MB)?;
+        Some((self.refaulted_mb()? / freed * 100.0).min(100.0))
+    }
+
+    // Tốc độ working set tăng lại kể từ lúc thấp nhất
+    pub fn refault_mb_per_sec(&self) -> Option<f64> {
+        let lowest = self.lowest()?;
+        let latest = self.samples.last()?;
+        let secs = latest.offset_secs - lowest.offset_secs;
+        (secs > 0.0).then(|| self.refaulted_mb().unwrap_or(0.0) / secs)
+    }
+
+    pub fn faults_per_sec(&self) -> Option<f64> {
+        let latest = self.samples.last()?;
+        let delta = latest.faults?.saturating_sub(self.before_faults?);
+        (latest.offset_secs > 0.0).then(|| delta as f64 / latest.offset_secs)
+    }
+
+    pub fn verdict(&self) -> EffectVerdict {
+        if !self.finished {
+            return EffectVerdict::Measuring;
+        }
+        if self.freed_mb().unwrap_or(0.0) < MIN_FREED_MB {
+            return EffectVerdict::NoEffect;
+        }
+        match self.refault_percent() {
+            Some(percent) if percent >= REVERTED_PERCENT => EffectVerdict::Reverted,
+            Some(percent) if percent >= REVERTED_PERCENT / 2.0 => EffectVerdict::Partial,
+            _ => EffectVerdict::Effective,
+        }
+    }
+}
+
+// File export để công cụ khác đọc lại báo cáo, cùng thư mục với config.toml; kèm sẵn các số đã tính
+#[derive(Serialize)]
+struct EffectExport<'a> {
+    reports: Vec<ExportedReport<'a>>,
+}
+
+#[derive(Serialize)]
+struct ExportedReport<'a> {
+    verdict: EffectVerdict,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    freed_mb: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refaulted_mb: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refault_percent: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refault_mb_per_sec: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    faults_per_sec: Option<f64>,
+    #[serde(flatten)]
+    report: &'a EffectReport,
+}
+
+pub fn export_path() -> PathBuf {
+    Config::path().with_file_name("effect_reports.toml")
+}
+
+fn exported(reports: &[EffectReport]) -> EffectExport<'_> {
+    EffectExport {
+        reports: reports
+            .iter()
+            .map(|report| ExportedReport {
+                verdict: report.verdict(),
+                freed_mb: report.freed_mb(),
+                refaulted_mb: report.refaulted_mb(),
+                refault_percent: report.refault_percent(),
+                refault_mb_per_sec: report.refault_mb_per_sec(),
+                faults_per_sec: report.faults_per_sec(),
+                report,
+            })
+            .collect(),
+    }
+}
+
+pub fn export(reports: &[EffectReport], path: &Path) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| t!("err-create-dir", path = dir.display().to_string(), error = e.to_string()))?;
+    }
+    let text = toml::to_string_pretty(&exported(reports))
+        .map_err(|e| t!("err-serialize-effects", error = e.to_string()))?;
+    fs::write(path, text).map_err(|e| t!("err-write-file", path = path.display().to_string(), error = e.to_string()))
+}
+
+// Cùng nội dung với file export, trả qua API (`ram_manager --api effect-reports`)
+pub fn to_json(reports: &[EffectReport]) -> Result<serde_json::Value, String> {
+    serde_json::to_value(exported(reports)).map_err(|e| t!("err-serialize-effects", error = e.to_string()))
+}
+
+struct ActiveEffect {
+    started: Instant,
+    next_sample: Instant,
+    report: EffectReport,
+}
+
+// Theo dõi các hành động đang trong cửa sổ đo, thuộc về RamManager; worker gọi RamManager::sample_effects khi tới hạn
+pub struct EffectTracker {
+    window: Duration,
+    interval: Duration,
+    active: HashMap<u32, ActiveEffect>,
+    finished: Vec<EffectReport>,
+}
+
+impl EffectTracker {
+    pub fn new(window: Duration, interval: Duration) -> Self {
+        EffectTracker {
+            window,
+            interval,
+            active: HashMap::new(),
+            finished: Vec::new(),
+        }
+    }
+
+    pub fn set_window(&mut self, window: Duration, interval: Duration) {
+        self.window = window;
+        self.interval = interval;
+    }
+
+    // Hành động mới trên PID đang đo thì kết thúc báo cáo cũ, vì từ đây số đo thuộc về hành động mới
+    pub fn start(&mut self, pid: u32, name: String, action: ProcessAction, before_mb: f64, before_faults: Option<u64>) {
+        let now = Instant::now();
+        if let Some(previous) = self.active.remove(&pid) {
+            self.finish(previous.report, true);
+        }
+        self.active.insert(
+            pid,
+            ActiveEffect {
+                started: now,
+                next_sample: now + self.interval,
+                report: EffectReport {
+                    pid,
+                    name,
+                    action,
+                    started_at: SystemTime::now(),
+                    window_secs: self.window.as_secs_f64(),
+                    before_mb,
+                    before_faults,
+                    samples: Vec::new(),
+                    finished: false,
+                    interrupted: false,
+                },
+            },
+        );
+    }
+
+    pub fn next_sample(&self) -> Option<Instant> {
+        self.active.values().map(|effect| effect.next_sample).min()
+    }
+
+    pub fn due(&self, now: Instant) -> Vec<u32> {
+        self.active
+            .iter()
+            .filter(|(_, effect)| effect.next_sample <= now)
+            .map(|(pid, _)| *pid)
+            .collect()
+    }
+
+    // `working_set_mb` là None khi không còn đọc được tiến trình (đã thoát)
+    pub fn record(&mut self, pid: u32, now: Instant, working_set_mb: Option<f64>, faults: Option<u64>) {
+        let Some(mut effect) = self.active.remove(&pid) else {
+            return;
+        };
+        let Some(working_set_mb) = working_set_mb else {
+            self.finish(effect.report, true);
+            return;
+        };
+        let offset_secs = now.duration_since(effect.started).as_secs_f64();
+        effect.report.samples.push(EffectSample {
+            offset_secs,
+            working_set_mb,
+            faults,
+        });
+        if now.duration_since(effect.started) >= self.window {
+            self.finish(effect.report, false);
+        } else {
+            effect.next_sample = now + self.interval;
+            self.active.insert(pid, effect);
+        }
+    }
+
+    fn finish(&mut self, mut report: EffectReport, interrupted: bool) {
+        report.finished = true;
+        report.interrupted = interrupted;
+        self.finished.push(report);
+        if self.finished.len() > MAX_FINISHED_REPORTS {
+            self.finished.remove(0);
+        }
+    }
+
+    // Báo cáo đã xong theo thứ tự thời gian, sau đó là các báo cáo đang đo
+    pub fn reports(&self) -> Vec<EffectReport> {
+        let mut active: Vec<&ActiveEffect> = self.active.values().collect();
+        active.sort_by_key(|effect| effect.started);
+        self.finished
+            .iter()
+            .cloned()
+            .chain(active.into_iter().map(|effect| effect.report.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant, SystemTime};
+    use crate::ram_manager::ProcessAction;
+    use super::{EffectReport, EffectSample, EffectTracker, EffectVerdict};
+
+    // samples: (giây sau hành động, working set MB)
+    fn report(before_mb: f64, samples: &[(f64, f64)], finished: bool) -> EffectReport {
+        EffectReport {
+            pid: 42,
+            name: "chrome".to_string(),
+            action: ProcessAction::Trim,
+            started_at: SystemTime::now(),
+            window_secs: 30.0,
+            before_mb,
+            before_faults: None,
+            samples: samples
+                .iter()
+                .map(|&(offset_secs, working_set_mb)| EffectSample { offset_secs, working_set_mb, faults: None })
+                .collect(),
+            finished,
+            interrupted: false,
+        }
+    }
+
+    #[test]
+    fn freed_and_refaulted_memory_are_measured_from_the_lowest_sample() {
+        let report = report(1000.0, &[(1.0, 400.0), (2.0, 200.0), (6.0, 300.0)], true);
+        assert_eq!(report.freed_mb(), Some(800.0));
+        assert_eq!(report.refaulted_mb(), Some(100.0));
+        assert_eq!(report.refault_percent(), Some(12.5));
+        assert_eq!(report.refault_mb_per_sec(), Some(25.0));
+    }
+
+    #[test]
+    fn working_set_growth_is_not_negative_freed_memory() {
+        let report = report(100.0, &[(1.0, 150.0)], true);
+        assert_eq!(report.freed_mb(), Some(0.0));
+        assert_eq!(report.refault_percent(), None);
+        assert_eq!(report.verdict(), EffectVerdict::NoEffect);
+    }
+
+    #[test]
+    fn verdict_follows_how_much_memory_came_back() {
+        assert_eq!(report(1000.0, &[(1.0, 200.0)], false).verdict(), EffectVerdict::Measuring);
+        assert_eq!(report(1000.0, &[], true).verdict(), EffectVerdict::NoEffect);
+        assert_eq!(report(1000.0, &[(1.0, 200.0), (5.0, 300.0)], true).verdict(), EffectVerdict::Effective);
+        assert_eq!(report(1000.0, &[(1.0, 200.0), (5.0, 600.0)], true).verdict(), EffectVerdict::Partial);
+        assert_eq!(report(1000.0, &[(1.0, 200.0), (5.0, 950.0)], true).verdict(), EffectVerdict::Reverted);
+    }
+
+    #[test]
+    fn tracker_finishes_after_the_window_or_when_interrupted() {
+        let window = Duration::from_secs(10);
+        let interval = Duration::from_secs(5);
+        let mut tracker = EffectTracker::new(window, interval);
+        let start = Instant::now();
+
+        tracker.start(1, "a".to_string(), ProcessAction::Trim, 500.0, None);
+        tracker.start(2, "b".to_string(), ProcessAction::Trim, 500.0, None);
+        assert!(tracker.due(start).is_empty());
+
+        tracker.record(1, start + interval, Some(300.0), None);
+        tracker.record(1, start + window + interval, Some(320.0), None);
+        // Tiến trình 2 đã thoát trước sample đầu tiên
+        tracker.record(2, start + interval, None, None);
+
+        let reports = tracker.reports();
+        assert_eq!(reports.len(), 2);
+        assert!(reports.iter().all(|report| report.finished));
+        let first = reports.iter().find(|report| report.pid == 1).unwrap();
+        assert_eq!((first.samples.len(), first.interrupted), (2, false));
+        let second = reports.iter().find(|report| report.pid == 2).unwrap();
+        assert!(second.samples.is_empty() && second.interrupted);
+    }
+
+    #[test]
+    fn new_action_on_the_same_pid_closes_the_previous_report() {
+        let mut tracker = EffectTracker::new(Duration::from_secs(10), Duration::from_secs(5));
+        tracker.start(1, "a".to_string(), ProcessAction::Trim, 500.0, None);
+        tracker.start(1, "a".to_string(), ProcessAction::Trim, 300.0, None);
+
+        let reports = tracker.reports();
+        assert_eq!(reports.len(), 2);
+        assert!(reports[0].finished && reports[0].interrupted);
+        assert!(!reports[1].finished);
+        assert_eq!(reports[1].before_mb, 300.0);
+    }
+}
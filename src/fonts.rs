@@ -0,0 +1,56 @@
+use std::fs;
+use eframe::egui::{self, FontData, FontDefinitions, FontFamily};
+use crate::config::FontChoice;
+use crate::i18n::t;
+
+const DEJAVU_SANS: &str = "DejaVuSans";
+const NOTO_EMOJI: &str = "NotoEmoji-Regular-bundled";
+const CUSTOM_FONT: &str = "custom";
+
+// Luôn nhúng DejaVu Sans (đủ dấu tiếng Việt) và Noto Emoji (icon trạng thái) làm fallback,
+// kể cả khi người dùng chọn font khác, để không bao giờ hiện ô vuông thay cho ký tự.
+// Đọc font từ file lỗi thì vẫn cấu hình với font nhúng và trả lỗi để hiện trên status bar.
+pub fn configure_fonts(ctx: &egui::Context, choice: &FontChoice) -> Result<(), String> {
+    let mut fonts = FontDefinitions::default();
+    fonts.font_data.insert(
+        DEJAVU_SANS.to_owned(),
+        FontData::from_static(include_bytes!("../assets/fonts/DejaVuSans.ttf")),
+    );
+    fonts.font_data.insert(
+        NOTO_EMOJI.to_owned(),
+        FontData::from_static(include_bytes!("../assets/fonts/NotoEmoji-Regular.ttf")),
+    );
+
+    let mut result = Ok(());
+    let primary = match choice {
+        FontChoice::DejavuSans => Some(DEJAVU_SANS),
+        FontChoice::EguiDefault => None,
+        FontChoice::File(path) => match fs::read(path) {
+            Ok(bytes) => {
+                fonts.font_data.insert(CUSTOM_FONT.to_owned(), FontData::from_owned(bytes));
+                Some(CUSTOM_FONT)
+            }
+            Err(e) => {
+                result = Err(t!("err-read-file", path = path.display().to_string(), error = e.to_string()));
+                Some(DEJAVU_SANS)
+            }
+        },
+    };
+
+    let proportional = fonts.families.entry(FontFamily::Proportional).or_default();
+    if let Some(primary) = primary {
+        proportional.insert(0, primary.to_owned());
+    }
+    for fallback in [DEJAVU_SANS, NOTO_EMOJI] {
+        if !proportional.iter().any(|f| f == fallback) {
+            proportional.push(fallback.to_owned());
+        }
+    }
+
+    let monospace = fonts.families.entry(FontFamily::Monospace).or_default();
+    monospace.push(DEJAVU_SANS.to_owned());
+    monospace.push(NOTO_EMOJI.to_owned());
+
+    ctx.set_fonts(fonts);
+    result
+}
@@ -0,0 +1,275 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use crate::config::Config;
+use crate::i18n::t;
+
+// Đóng băng tiến trình: không chạy được nên không fault lại bộ nhớ vừa bị trim.
+// Thaw chỉ có tác dụng khi tiến trình đang bị đóng băng nên gọi lại nhiều lần cũng an toàn.
+pub fn freeze(pid: u32) -> Result<(), String> {
+    // kill(0, SIGSTOP) dừng cả process group; tự đóng băng chính mình thì không ai rã đông được
+    if pid == 0 || pid == std::process::id() {
+        return Err(t!("err-freeze-pid", pid = pid));
+    }
+    platform::freeze(pid)
+}
+
+pub fn thaw(pid: u32) -> Result<(), String> {
+    platform::thaw(pid)
+}
+
+// Các PID đang bị đóng băng, lưu ra file như CgroupOriginals: chương trình bị tắt đột ngột thì
+// lần chạy sau vẫn biết tiến trình nào cần rã đông
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FrozenProcesses {
+    #[serde(default)]
+    pub processes: Vec<FrozenProcess>,
+}
+
+// Tên dùng để nhận ra PID đã bị tái sử dụng cho tiến trình khác, như ProfileChange
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FrozenProcess {
+    pub pid: u32,
+    pub name: String,
+}
+
+impl FrozenProcesses {
+    pub fn path() -> PathBuf {
+        Config::path().with_file_name("frozen.toml")
+    }
+
+    pub fn load(path: &Path) -> Result<FrozenProcesses, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text)
+                .map_err(|e| t!("err-syntax", path = path.display().to_string(), error = e.to_string())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(FrozenProcesses::default()),
+            Err(e) => Err(t!("err-read-file", path = path.display().to_string(), error = e.to_string())),
+        }
+    }
+
+    // Không còn tiến trình nào bị đóng băng thì xóa file
+    pub fn store(&self, path: &Path) -> Result<(), String> {
+        if self.processes.is_empty() {
+            return match fs::remove_file(path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                    Err(t!("err-delete-file", path = path.display().to_string(), error = e.to_string()))
+                }
+                _ => Ok(()),
+            };
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| t!("err-create-dir", path = dir.display().to_string(), error = e.to_string()))?;
+        }
+        let text = toml::to_string_pretty(self).map_err(|e| t!("err-serialize-session", error = e.to_string()))?;
+        fs::write(path, text).map_err(|e| t!("err-write-file", path = path.display().to_string(), error = e.to_string()))
+    }
+
+    pub fn contains(&self, pid: u32) -> bool {
+        self.processes.iter().any(|p| p.pid == pid)
+    }
+
+    pub fn pids(&self) -> Vec<u32> {
+        self.processes.iter().map(|p| p.pid).collect()
+    }
+
+    pub fn insert(&mut self, pid: u32, name: String) {
+        if !self.contains(pid) {
+            self.processes.push(FrozenProcess { pid, name });
+        }
+    }
+
+    // Trả về true khi PID có trong danh sách
+    pub fn remove(&mut self, pid: u32) -> bool {
+        let before = self.processes.len();
+        self.processes.retain(|p| p.pid != pid);
+        self.processes.len() != before
+    }
+
+    // Khi nạp lại: bỏ PID đã thoát hoặc đã thuộc về tiến trình khác. Trả về true khi có thay đổi
+    pub fn retain_running(&mut self, current_name: impl Fn(u32) -> Option<String>) -> bool {
+        let before = self.processes.len();
+        self.processes.retain(|p| current_name(p.pid).as_deref() == Some(p.name.as_str()));
+        self.processes.len() != before
+    }
+}
+
+// Cgroup mà freeze ghi cgroup.freeze thay cho SIGSTOP, nếu có
+#[cfg(target_os = "linux")]
+pub fn own_cgroup(pid: u32) -> Option<std::path::PathBuf> {
+    platform::own_cgroup(pid)
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::fs;
+    use std::io;
+    use std::path::{Path, PathBuf};
+    use crate::cgroup;
+    use crate::i18n::t;
+
+    // Chỉ dùng cgroup.freeze khi cgroup (v2) của tiến trình không có tiến trình nào khác,
+    // để không đóng băng luôn các tiến trình cùng scope/service
+    pub fn own_cgroup(pid: u32) -> Option<PathBuf> {
+        // Cgroup gốc không có cgroup.freeze
+        let dir = cgroup::of_pid(pid).filter(|dir| dir != Path::new(cgroup::ROOT))?;
+        let procs = fs::read_to_string(dir.join("cgroup.procs")).ok()?;
+        let pid = pid.to_string();
+        procs.split_whitespace().all(|p| p == pid).then_some(dir)
+    }
+
+    pub fn freeze(pid: u32) -> Result<(), String> {
+        if let Some(dir) = own_cgroup(pid) {
+            if cgroup::write(&dir, "cgroup.freeze", "1").is_ok() {
+                return Ok(());
+            }
+        }
+        signal(pid, libc::SIGSTOP)
+    }
+
+    pub fn thaw(pid: u32) -> Result<(), String> {
+        if let Some(dir) = own_cgroup(pid) {
+            if cgroup::read(&dir, "cgroup.freeze").is_ok_and(|v| v == "1") {
+                cgroup::write(&dir, "cgroup.freeze", "0")?;
+            }
+        }
+        if is_stopped(pid) {
+            signal(pid, libc::SIGCONT)?;
+        }
+        Ok(())
+    }
+
+    // Trạng thái `T` trong /proc/<pid>/stat (bị dừng bởi SIGSTOP), bỏ qua `t` (đang bị debugger dừng)
+    fn is_stopped(pid: u32) -> bool {
+        fs::read_to_string(format!("/proc/{}/stat", pid))
+            .ok()
+            .and_then(|stat| stat.rsplit_once(')').map(|(_, rest)| rest.trim_start().starts_with('T')))
+            .unwrap_or(false)
+    }
+
+    fn signal(pid: u32, signal: libc::c_int) -> Result<(), String> {
+        if unsafe { libc::kill(pid as libc::pid_t, signal) } != 0 {
+            return Err(t!("err-signal", pid = pid, error = io::Error::last_os_error().to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::mem;
+    use windows::core::{s, w, PCSTR};
+    use windows::Win32::Foundation::{CloseHandle, HANDLE, NTSTATUS};
+    use windows::Win32::System::LibraryLoader::{GetModuleHandleW, GetProcAddress};
+    use windows::Win32::System::Threading::{OpenProcess, PROCESS_SUSPEND_RESUME};
+    use crate::i18n::t;
+
+    type NtProcessFn = unsafe extern "system" fn(HANDLE) -> NTSTATUS;
+
+    // NtSuspendProcess/NtResumeProcess không có trong Win32 API nên lấy trực tiếp từ ntdll.
+    // Suspend tăng bộ đếm suspend của mọi thread, Resume giảm lại nên tiến trình không bị đóng băng thì không có gì thay đổi
+    fn call(pid: u32, name: PCSTR) -> Result<(), String> {
+        unsafe {
+            let ntdll = GetModuleHandleW(w!("ntdll.dll")).map_err(|e| t!("err-suspend", error = format!("{:?}", e)))?;
+            let function = GetProcAddress(ntdll, name).ok_or_else(|| t!("err-suspend", error = "ntdll"))?;
+            let function: NtProcessFn = mem::transmute(function);
+
+            let handle = OpenProcess(PROCESS_SUSPEND_RESUME, false, pid)
+                .map_err(|e| t!("err-open-process", error = format!("{:?}", e)))?;
+            let status = function(handle);
+            let _ = CloseHandle(handle);
+            status.ok().map_err(|e| t!("err-suspend", error = format!("{:?}", e)))
+        }
+    }
+
+    pub fn freeze(pid: u32) -> Result<(), String> {
+        call(pid, s!("NtSuspendProcess"))
+    }
+
+    pub fn thaw(pid: u32) -> Result<(), String> {
+        call(pid, s!("NtResumeProcess"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use super::{FrozenProcess, FrozenProcesses};
+
+    fn frozen(processes: &[(u32, &str)]) -> FrozenProcesses {
+        FrozenProcesses {
+            processes: processes.iter().map(|(pid, name)| FrozenProcess { pid: *pid, name: name.to_string() }).collect(),
+        }
+    }
+
+    #[test]
+    fn frozen_processes_round_trip_and_empty_deletes_the_file() {
+        let path = std::env::temp_dir().join(format!("ram_manager-frozen-{}.toml", std::process::id()));
+        let mut saved = FrozenProcesses::default();
+        saved.insert(10, "chrome".to_string());
+        saved.insert(10, "chrome".to_string());
+        saved.insert(11, "code".to_string());
+        saved.store(&path).unwrap();
+        assert_eq!(FrozenProcesses::load(&path), Ok(frozen(&[(10, "chrome"), (11, "code")])));
+
+        assert!(saved.remove(10));
+        assert!(!saved.remove(10));
+        assert!(saved.remove(11));
+        saved.store(&path).unwrap();
+        assert!(!path.exists());
+        // Chưa từng lưu thì coi như không có tiến trình nào
+        assert_eq!(FrozenProcesses::load(&path), Ok(FrozenProcesses::default()));
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn retain_running_drops_exited_and_reused_pids() {
+        let mut saved = frozen(&[(10, "chrome"), (11, "code"), (12, "slack")]);
+        let changed = saved.retain_running(|pid| match pid {
+            10 => Some("chrome".to_string()),
+            // PID đã được dùng lại cho tiến trình khác
+            11 => Some("bash".to_string()),
+            _ => None,
+        });
+        assert!(changed);
+        assert_eq!(saved.pids(), vec![10]);
+        assert!(!saved.retain_running(|_| Some("chrome".to_string())));
+    }
+
+    #[test]
+    fn refuses_to_freeze_itself() {
+        let own = std::process::id();
+        assert_eq!(super::freeze(own), Err(crate::i18n::t!("err-freeze-pid", pid = own)));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn freeze_stops_and_thaw_resumes_a_process() {
+        // Signal được xử lý bất đồng bộ nên chờ tới khi trạng thái ổn định
+        let wait_state = |pid: u32, stopped: bool| {
+            for _ in 0..100 {
+                let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).unwrap();
+                if stat.rsplit_once(')').unwrap().1.trim_start().starts_with('T') == stopped {
+                    return true;
+                }
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+            false
+        };
+        let mut child = std::process::Command::new("sleep").arg("30").spawn().unwrap();
+        let pid = child.id();
+
+        super::freeze(pid).unwrap();
+        // cgroup.freeze không đổi trạng thái trong /proc nên chỉ kiểm tra khi dùng SIGSTOP
+        if super::own_cgroup(pid).is_none() {
+            assert!(wait_state(pid, true));
+        }
+        super::thaw(pid).unwrap();
+        assert!(wait_state(pid, false));
+        // Rã đông tiến trình không bị đóng băng không có tác dụng gì
+        super::thaw(pid).unwrap();
+
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
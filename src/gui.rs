@@ -1,24 +1,103 @@
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::path::{Path, PathBuf};
 use eframe::egui;
-use crate::ram_manager::{ProcessInfo, RamManager};
+use egui_extras::{Column, TableBuilder};
+use crate::alerts::AlertEvent;
+use crate::early_oom::{self, EarlyOomEvent};
+use crate::batch::{self, BatchJob};
+use crate::cgroup_tree::{self, CgroupNode};
+use crate::config::{self, Config, FontChoice, Theme};
+use crate::effect::{self, EffectReport};
+use crate::fonts;
+use crate::helper::{self, HelperStatus};
+use crate::i18n::{self, num, t, Language};
+use crate::launch::{LaunchExit, LaunchPolicy, LaunchRequest};
+use crate::memory_cap::{BreachAction, LimitMode};
+use crate::memory_map::{self, MemoryMap, MemoryRegion, RegionKind, RegionSort};
+use crate::oom;
+use crate::process_group::{self, GroupActionReport, ProcessGroup};
+use crate::process_tree::{self, ProcessNode};
+use crate::profile::{ProfileCommand, ProfileReport};
+use crate::ram_manager::{Priority, ProcessAction, ProcessInfo, RamStatistics, SystemInfo};
+use crate::page_faults::SystemFaultRates;
+use crate::pageout::{PageoutHint, PageoutReport};
+use crate::permissions::Permissions;
+use crate::swap::SwapStats;
+use crate::system_memory::SystemMemoryBreakdown;
+use crate::worker::{Snapshot, Worker, WorkerCommand, WorkerEvent};
+
+// Số sample page fault giữ lại cho mỗi tiến trình để vẽ lịch sử
+const FAULT_HISTORY_LEN: usize = 60;
+const LAUNCH_HISTORY_LEN: usize = 20;
 
 pub struct RamManagerApp {
-    manager: RamManager,
+    worker: Worker,
+    config: Config,
+    config_path: PathBuf,
     processes: Vec<ProcessInfo>,
+    system_info: SystemInfo,
+    memory_breakdown: Result<SystemMemoryBreakdown, String>,
+    swap_stats: Result<SwapStats, String>,
+    fault_rates: SystemFaultRates,
+    fault_history: HashMap<u32, VecDeque<f64>>,
+    effect_reports: Vec<EffectReport>,
+    pageout_report: Option<PageoutReport>,
+    alert_history: Vec<AlertEvent>,
+    early_oom_history: Vec<EarlyOomEvent>,
+    launch_command: String,
+    launch_policy: LaunchPolicy,
+    launch_history: Vec<LaunchExit>,
+    helper_status: HelperStatus,
+    permissions: Permissions,
+    statistics: RamStatistics,
+    in_flight: usize,
+    active_profile: Option<String>,
+    profile_history: Vec<ProfileReport>,
+    selected_profile: String,
+    tree: Vec<ProcessNode>,
+    groups: Vec<ProcessGroup>,
     filter: String,
-    selected_pid: Option<u32>,
+    selected_pids: BTreeSet<u32>,
+    // Dòng tiến trình đã vẽ trong khung nhìn ở frame này và tập PID đã báo cho worker lần trước
+    visible_pids: BTreeSet<u32>,
+    watched_pids: BTreeSet<u32>,
+    selection_anchor: Option<u32>,
+    batch_job: Option<BatchJob>,
+    selected_group: Option<String>,
+    cgroup_tree: Option<CgroupNode>,
+    selected_cgroup: Option<PathBuf>,
+    last_group_report: Option<GroupActionReport>,
+    memory_map: Option<MemoryMap>,
+    region_sort: RegionSort,
+    region_sort_descending: bool,
+    region_filter: Option<RegionKind>,
     status_message: String,
     auto_refresh: bool,
+    sample_interval_secs: f32,
     pin_working_set_mb: usize,
     limit_max_ws_mb: usize,
+    limit_mode: LimitMode,
+    limit_breach: BreachAction,
+    swap_max_mb: Option<u64>,
+    zswap_max_mb: Option<u64>,
     sort_by: SortBy,
+    view_mode: ViewMode,
+    apply_to_subtree: bool,
     show_stats: bool,
     theme: Theme,
+    language: Option<Language>,
+    font: FontChoice,
+    font_path_input: String,
+    ui_scale: f32,
+    show_display_settings: bool,
 }
 
 #[derive(PartialEq)]
-enum Theme {
-    Dark,
-    Light,
+enum ViewMode {
+    List,
+    Tree,
+    Group,
+    Cgroup,
 }
 
 #[derive(PartialEq)]
@@ -29,24 +108,96 @@ enum SortBy {
     CPU,
 }
 
-impl Default for RamManagerApp {
-    fn default() -> Self {
-        let mut manager = RamManager::new();
-        let processes = manager.list_processes();
+impl RamManagerApp {
+    pub fn new(cc: &eframe::CreationContext<'_>, profile_command: Option<ProfileCommand>) -> Self {
+        let config_path = Config::path();
+        let (config, load_error) = match Config::load(&config_path) {
+            Ok(config) => (config, None),
+            Err(e) => (Config::default(), Some(e)),
+        };
+        i18n::set_language(config.ui.language.unwrap_or_else(Language::detect));
+        let font_result = fonts::configure_fonts(&cc.egui_ctx, &config.ui.font);
+        cc.egui_ctx.set_zoom_factor(config.ui.scale);
+        let status_message = match (load_error, font_result) {
+            (Some(e), _) => t!("status-config-fallback", error = e),
+            (None, Err(e)) => format!("❌ {}", e),
+            (None, Ok(())) => t!("status-ready"),
+        };
+        let worker = Worker::spawn(cc.egui_ctx.clone(), config.clone());
 
-        Self {
-            manager,
-            processes,
+        let mut app = Self {
+            worker,
+            auto_refresh: config.sampling.auto_refresh,
+            sample_interval_secs: config.sampling.refresh_interval_secs,
+            pin_working_set_mb: config.actions.pin_working_set_mb,
+            limit_max_ws_mb: config.actions.limit_max_ws_mb,
+            limit_mode: config.actions.limit_mode,
+            limit_breach: config.actions.limit_breach,
+            swap_max_mb: config.actions.swap_max_mb,
+            zswap_max_mb: config.actions.zswap_max_mb,
+            show_stats: config.ui.show_stats,
+            theme: config.ui.theme,
+            language: config.ui.language,
+            font: config.ui.font.clone(),
+            font_path_input: match &config.ui.font {
+                FontChoice::File(path) => path.display().to_string(),
+                _ => String::new(),
+            },
+            ui_scale: config.ui.scale,
+            show_display_settings: false,
+            selected_profile: config.profiles.first().map(|p| p.name.clone()).unwrap_or_default(),
+            config,
+            config_path,
+            processes: Vec::new(),
+            system_info: SystemInfo::default(),
+            memory_breakdown: Ok(SystemMemoryBreakdown::default()),
+            swap_stats: Ok(SwapStats::default()),
+            fault_rates: SystemFaultRates::default(),
+            fault_history: HashMap::new(),
+            effect_reports: Vec::new(),
+            pageout_report: None,
+            alert_history: Vec::new(),
+            early_oom_history: Vec::new(),
+            launch_command: String::new(),
+            launch_policy: LaunchPolicy::default(),
+            launch_history: Vec::new(),
+            helper_status: HelperStatus::InProcess,
+            permissions: Permissions::default(),
+            statistics: RamStatistics::default(),
+            in_flight: 0,
+            active_profile: None,
+            profile_history: Vec::new(),
+            tree: Vec::new(),
+            groups: Vec::new(),
             filter: String::new(),
-            selected_pid: None,
-            status_message: "🟢 Sẵn sàng - Tool đang chạy".to_string(),
-            auto_refresh: true,
-            pin_working_set_mb: 512,
-            limit_max_ws_mb: 256,
+            selected_pids: BTreeSet::new(),
+            visible_pids: BTreeSet::new(),
+            watched_pids: BTreeSet::new(),
+            selection_anchor: None,
+            batch_job: None,
+            selected_group: None,
+            cgroup_tree: None,
+            selected_cgroup: None,
+            last_group_report: None,
+            memory_map: None,
+            region_sort: RegionSort::Rss,
+            region_sort_descending: true,
+            region_filter: None,
+            status_message,
             sort_by: SortBy::Memory,
-            show_stats: false,
-            theme: Theme::Dark,
+            view_mode: ViewMode::List,
+            apply_to_subtree: false,
+        };
+
+        match profile_command {
+            Some(ProfileCommand::Apply(name)) => {
+                app.selected_profile = name;
+                app.apply_selected_profile();
+            }
+            Some(ProfileCommand::Revert) => app.revert_profile(),
+            None => {}
         }
+        app
     }
 }
 
@@ -58,12 +209,15 @@ impl eframe::App for RamManagerApp {
             Theme::Light => ctx.set_visuals(egui::Visuals::light()),
         }
 
-        // Auto refresh
-        if self.auto_refresh {
-            ctx.request_repaint_after(std::time::Duration::from_secs(2));
-            self.processes = self.manager.list_processes();
+        // Ctrl +/- của egui cũng đổi zoom, giữ slider UI scale khớp với zoom thực tế
+        if !self.show_display_settings {
+            self.ui_scale = ctx.zoom_factor().clamp(config::MIN_UI_SCALE, config::MAX_UI_SCALE);
         }
 
+        // Nhận snapshot và kết quả từ worker thread
+        self.process_worker_events();
+        self.visible_pids.clear();
+
         // Top panel - System info
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
             ui.add_space(5.0);
@@ -71,13 +225,18 @@ impl eframe::App for RamManagerApp {
                 ui.heading(egui::RichText::new("🖥️ Advanced RAM Manager v1.0.0").size(18.0));
                 ui.separator();
 
-                let sys_info = self.manager.get_system_info();
-                let usage_percent = (sys_info.used_ram_gb / sys_info.total_ram_gb) * 100.0;
+                let sys_info = &self.system_info;
+                let usage_percent = if sys_info.total_ram_gb > 0.0 {
+                    (sys_info.used_ram_gb / sys_info.total_ram_gb) * 100.0
+                } else {
+                    0.0
+                };
 
                 // RAM progress bar với màu động
-                let ram_color = if usage_percent > 90.0 {
+                let thresholds = &self.config.thresholds;
+                let ram_color = if usage_percent > thresholds.ram_critical_percent {
                     egui::Color32::from_rgb(231, 76, 60)
-                } else if usage_percent > 75.0 {
+                } else if usage_percent > thresholds.ram_warning_percent {
                     egui::Color32::from_rgb(230, 126, 34)
                 } else {
                     egui::Color32::from_rgb(46, 204, 113)
@@ -92,34 +251,62 @@ impl eframe::App for RamManagerApp {
                 );
 
                 ui.separator();
-                ui.label(format!("📊 Tiến trình: {}", sys_info.process_count));
+                ui.label(t!("top-processes", count = sys_info.process_count));
 
                 ui.separator();
-                ui.checkbox(&mut self.auto_refresh, "🔄 Auto");
+                if ui.checkbox(&mut self.auto_refresh, t!("top-auto")).changed() {
+                    self.worker.send(WorkerCommand::SetAutoRefresh(self.auto_refresh));
+                }
+                let interval = ui.add(
+                    egui::DragValue::new(&mut self.sample_interval_secs)
+                        .range(config::MIN_REFRESH_INTERVAL_SECS..=config::MAX_REFRESH_INTERVAL_SECS)
+                        .speed(0.1)
+                        .suffix(" s"),
+                ).on_hover_text(t!("top-interval-hint"));
+                if interval.changed() {
+                    self.worker.send(WorkerCommand::SetSampleInterval(
+                        std::time::Duration::from_secs_f32(self.sample_interval_secs),
+                    ));
+                }
 
-                if ui.button("🔃").on_hover_text("Refresh ngay").clicked() {
-                    self.processes = self.manager.list_processes();
-                    self.status_message = "✅ Đã làm mới danh sách".to_string();
+                if ui.button("🔃").on_hover_text(t!("top-refresh-hint")).clicked() {
+                    self.worker.send(WorkerCommand::Refresh);
+                    self.status_message = t!("status-refreshed");
                 }
 
                 ui.separator();
                 if ui.button(match self.theme {
                     Theme::Dark => "🌙",
                     Theme::Light => "☀️",
-                }).on_hover_text("Đổi theme").clicked() {
+                }).on_hover_text(t!("top-theme-hint")).clicked() {
                     self.theme = match self.theme {
                         Theme::Dark => Theme::Light,
                         Theme::Light => Theme::Dark,
                     };
                 }
 
-                if ui.button("📈").on_hover_text("Thống kê").clicked() {
+                if ui.button("📈").on_hover_text(t!("top-stats-hint")).clicked() {
                     self.show_stats = !self.show_stats;
                 }
+
+                if ui.button("🔤").on_hover_text(t!("top-display-hint")).clicked() {
+                    self.show_display_settings = !self.show_display_settings;
+                }
+
+                if ui.button("💾").on_hover_text(t!("top-save-hint", path = self.config_path.display().to_string())).clicked() {
+                    self.save_config();
+                }
+
+                ui.separator();
+                self.show_language_picker(ui);
             });
             ui.add_space(5.0);
         });
 
+        if self.show_display_settings {
+            self.show_display_settings(ctx);
+        }
+
         // Bottom panel - Status
         egui::TopBottomPanel::bottom("bottom_panel").show(ctx, |ui| {
             ui.add_space(3.0);
@@ -132,10 +319,30 @@ impl eframe::App for RamManagerApp {
                     egui::Color32::from_rgb(52, 152, 219)
                 };
 
+                if self.in_flight > 0 {
+                    ui.spinner();
+                }
                 ui.colored_label(color, &self.status_message);
 
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    ui.label("⚠️ Chạy với quyền Administrator");
+                    match &self.helper_status {
+                        HelperStatus::InProcess => {
+                            ui.label(t!("bottom-admin"));
+                        }
+                        HelperStatus::Connected => {
+                            ui.colored_label(egui::Color32::from_rgb(46, 204, 113), t!("helper-connected"));
+                        }
+                        HelperStatus::Unreachable(error) => {
+                            if ui.button(t!("helper-start")).clicked() {
+                                self.status_message = match helper::launch_elevated(&self.config.helper) {
+                                    Ok(()) => t!("status-helper-launched"),
+                                    Err(e) => format!("❌ {}", e),
+                                };
+                            }
+                            ui.colored_label(egui::Color32::from_rgb(231, 76, 60), t!("helper-unreachable"))
+                                .on_hover_text(error);
+                        }
+                    }
                 });
             });
             ui.add_space(3.0);
@@ -143,69 +350,146 @@ impl eframe::App for RamManagerApp {
 
         // Left panel - Process list
         egui::SidePanel::left("process_list").min_width(650.0).show(ctx, |ui| {
-            ui.heading("📋 Danh sách tiến trình");
+            ui.heading(t!("list-heading"));
             ui.add_space(5.0);
 
             // Filter and sort controls
             ui.horizontal(|ui| {
                 ui.label("🔍");
                 ui.text_edit_singleline(&mut self.filter)
-                    .on_hover_text("Tìm theo tên hoặc PID");
+                    .on_hover_text(t!("list-filter-hint"));
 
-                if ui.button("❌").on_hover_text("Xóa filter").clicked() {
+                if ui.button("❌").on_hover_text(t!("list-clear-filter")).clicked() {
                     self.filter.clear();
                 }
 
                 ui.separator();
-                ui.label("Sắp xếp:");
+                ui.label(t!("list-sort"));
                 ui.selectable_value(&mut self.sort_by, SortBy::Memory, "💾 RAM");
                 ui.selectable_value(&mut self.sort_by, SortBy::CPU, "⚙️ CPU");
-                ui.selectable_value(&mut self.sort_by, SortBy::Name, "📝 Tên");
-                ui.selectable_value(&mut self.sort_by, SortBy::Status, "🏷️ Trạng thái");
+                ui.selectable_value(&mut self.sort_by, SortBy::Name, t!("sort-name"));
+                ui.selectable_value(&mut self.sort_by, SortBy::Status, t!("sort-status"));
+            });
+
+            ui.horizontal(|ui| {
+                ui.label(t!("list-view"));
+                ui.selectable_value(&mut self.view_mode, ViewMode::List, t!("view-list"));
+                ui.selectable_value(&mut self.view_mode, ViewMode::Tree, t!("view-tree"));
+                ui.selectable_value(&mut self.view_mode, ViewMode::Group, t!("view-group"));
+                if cfg!(target_os = "linux")
+                    && ui.selectable_value(&mut self.view_mode, ViewMode::Cgroup, t!("view-cgroup")).clicked()
+                {
+                    self.scan_cgroups();
+                }
             });
 
             ui.separator();
 
             // Statistics panel
             if self.show_stats {
-                ui.collapsing("📊 Thống kê", |ui| {
-                    let stats = self.manager.get_statistics();
+                ui.collapsing(t!("stats-heading"), |ui| {
+                    let stats = &self.statistics;
                     ui.horizontal(|ui| {
-                        ui.label(format!("📌 Pinned: {}", stats.pinned_count));
+                        ui.label(t!("stats-pinned", count = stats.pinned_count));
+                        ui.separator();
+                        ui.label(t!("stats-trimmed", count = stats.trimmed_count));
                         ui.separator();
-                        ui.label(format!("🗜️ Trimmed: {}", stats.trimmed_count));
+                        ui.label(t!("stats-limited", count = stats.limited_count));
                         ui.separator();
-                        ui.label(format!("⚠️ Limited: {}", stats.limited_count));
+                        ui.label(t!("stats-frozen", count = stats.frozen_count));
+                        ui.separator();
+                        ui.label(t!("stats-launched", count = stats.launched_count));
                     });
                 });
                 ui.separator();
             }
 
+            if self.view_mode == ViewMode::Tree {
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false; 2])
+                    .show(ui, |ui| {
+                        let tree = std::mem::take(&mut self.tree);
+                        for node in tree.iter() {
+                            self.show_tree_node(ui, node);
+                        }
+                        self.tree = tree;
+                    });
+                return;
+            }
+
+            if self.view_mode == ViewMode::Group {
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false; 2])
+                    .show(ui, |ui| {
+                        let groups = std::mem::take(&mut self.groups);
+                        for group in groups.iter() {
+                            self.show_group(ui, group);
+                        }
+                        self.groups = groups;
+                    });
+                return;
+            }
+
+            if self.view_mode == ViewMode::Cgroup {
+                if ui.button(t!("cgroup-rescan")).clicked() {
+                    self.scan_cgroups();
+                }
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false; 2])
+                    .show(ui, |ui| {
+                        let tree = self.cgroup_tree.take();
+                        match &tree {
+                            Some(root) => self.show_cgroup_node(ui, root),
+                            None => {
+                                ui.label(t!("cgroup-not-loaded"));
+                            }
+                        }
+                        self.cgroup_tree = tree;
+                    });
+                return;
+            }
+
+            let mut filtered_processes = self.processes.clone();
+
+            // Apply filter
+            if !self.filter.is_empty() {
+                filtered_processes.retain(|p| {
+                    p.name.to_lowercase().contains(&self.filter.to_lowercase())
+                        || p.pid.to_string().contains(&self.filter)
+                });
+            }
+
+            // Apply sort
+            match self.sort_by {
+                SortBy::Memory => filtered_processes
+                    .sort_by(|a, b| b.memory_mb.partial_cmp(&a.memory_mb).unwrap()),
+                SortBy::CPU => filtered_processes
+                    .sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap()),
+                SortBy::Name => filtered_processes.sort_by(|a, b| a.name.cmp(&b.name)),
+                SortBy::Status => filtered_processes.sort_by_key(|p| p.status.label()),
+            }
+            let order: Vec<u32> = filtered_processes.iter().map(|p| p.pid).collect();
+
+            // Selection controls
+            ui.horizontal(|ui| {
+                if ui.button(t!("select-all")).on_hover_text(t!("select-all-hint")).clicked() {
+                    self.selected_pids.extend(order.iter().copied());
+                    self.selected_group = None;
+                    self.selected_cgroup = None;
+                    self.status_message = t!("status-selected-many", count = self.selected_pids.len());
+                }
+                if ui.button(t!("select-none")).clicked() {
+                    self.selected_pids.clear();
+                    self.selection_anchor = None;
+                }
+                ui.label(t!("selected-count", count = self.selected_pids.len()))
+                    .on_hover_text(t!("selection-hint"));
+            });
+
             // Process table
             egui::ScrollArea::vertical()
                 .auto_shrink([false; 2])
                 .show(ui, |ui| {
-                    let mut filtered_processes = self.processes.clone();
-
-                    // Apply filter
-                    if !self.filter.is_empty() {
-                        filtered_processes.retain(|p| {
-                            p.name.to_lowercase().contains(&self.filter.to_lowercase())
-                                || p.pid.to_string().contains(&self.filter)
-                        });
-                    }
-
-                    // Apply sort
-                    match self.sort_by {
-                        SortBy::Memory => filtered_processes
-                            .sort_by(|a, b| b.memory_mb.partial_cmp(&a.memory_mb).unwrap()),
-                        SortBy::CPU => filtered_processes
-                            .sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap()),
-                        SortBy::Name => filtered_processes.sort_by(|a, b| a.name.cmp(&b.name)),
-                        SortBy::Status => filtered_processes
-                            .sort_by(|a, b| a.status.as_str().cmp(b.status.as_str())),
-                    }
-
                     // Grid
                     egui::Grid::new("process_grid")
                         .striped(true)
@@ -214,15 +498,21 @@ impl eframe::App for RamManagerApp {
                         .show(ui, |ui| {
                             // Header
                             ui.label(egui::RichText::new("PID").strong());
-                            ui.label(egui::RichText::new("Tên tiến trình").strong());
+                            ui.label(egui::RichText::new(t!("col-name")).strong());
                             ui.label(egui::RichText::new("RAM (MB)").strong());
                             ui.label(egui::RichText::new("CPU %").strong());
-                            ui.label(egui::RichText::new("Trạng thái").strong());
+                            ui.label(egui::RichText::new(t!("col-faults")).strong())
+                                .on_hover_text(t!("col-faults-hint"));
+                            if cfg!(target_os = "linux") {
+                                ui.label(egui::RichText::new("OOM").strong())
+                                    .on_hover_text(t!("col-oom-hint"));
+                            }
+                            ui.label(egui::RichText::new(t!("col-status")).strong());
                             ui.end_row();
 
                             // Rows
                             for proc in filtered_processes.iter() {
-                                let is_selected = self.selected_pid == Some(proc.pid);
+                                let is_selected = self.selected_pids.contains(&proc.pid);
 
                                 let response = ui.selectable_label(
                                     is_selected,
@@ -235,24 +525,37 @@ impl eframe::App for RamManagerApp {
                                 );
 
                                 if response.clicked() {
-                                    self.selected_pid = Some(proc.pid);
-                                    self.status_message = format!(
-                                        "🎯 Đã chọn: {} (PID: {})",
-                                        proc.name, proc.pid
-                                    );
+                                    let modifiers = ui.input(|i| i.modifiers);
+                                    self.click_process(proc, modifiers, &order);
+                                }
+                                if ui.is_rect_visible(response.rect) {
+                                    self.visible_pids.insert(proc.pid);
                                 }
 
                                 ui.label(&proc.name);
                                 ui.label(format!("{:.1}", proc.memory_mb));
                                 ui.label(format!("{:.1}", proc.cpu_usage));
+                                ui.label(proc.fault_rate().map(|r| format!("{:.0}", r)).unwrap_or_else(|| "-".to_string()));
+                                if cfg!(target_os = "linux") {
+                                    match proc.oom {
+                                        Some(oom) => ui.label(oom.score.to_string())
+                                            .on_hover_text(t!("oom-score-adj", value = oom.adj.to_string())),
+                                        None => ui.label("-"),
+                                    };
+                                }
 
                                 let color = proc.status.color();
+                                let thrashing = proc.is_thrashing(&self.config.thresholds);
                                 ui.horizontal(|ui| {
                                     ui.label(proc.status.icon());
                                     ui.colored_label(
                                         egui::Color32::from_rgb(color[0], color[1], color[2]),
-                                        proc.status.as_str(),
+                                        proc.status.label(),
                                     );
+                                    if thrashing {
+                                        ui.colored_label(egui::Color32::from_rgb(231, 76, 60), "🔥")
+                                            .on_hover_text(t!("thrashing-hint"));
+                                    }
                                 });
 
                                 ui.end_row();
@@ -262,26 +565,32 @@ impl eframe::App for RamManagerApp {
                     // Total count
                     ui.add_space(10.0);
                     ui.separator();
-                    ui.label(format!(
-                        "📊 Hiển thị {} / {} tiến trình",
-                        filtered_processes.len(),
-                        self.processes.len()
+                    ui.label(t!(
+                        "list-showing",
+                        shown = filtered_processes.len(),
+                        total = self.processes.len()
                     ));
                 });
         });
 
         // Central panel - Control panel
         egui::CentralPanel::default().show(ctx, |ui| {
-            ui.heading("⚙️ Bảng điều khiển");
+            ui.heading(t!("panel-heading"));
             ui.add_space(10.0);
 
-            if let Some(pid) = self.selected_pid {
-                if let Some(proc) = self.processes.iter().find(|p| p.pid == pid) {
+            if let Some(path) = self.selected_cgroup.clone() {
+                self.show_cgroup_panel(ui, &path);
+            } else if let Some(key) = self.selected_group.clone() {
+                self.show_group_panel(ui, &key);
+            } else if self.selected_pids.len() > 1 || self.batch_job.is_some() {
+                self.show_batch_panel(ui);
+            } else if let Some(pid) = self.selected_pids.iter().next().copied() {
+                if let Some(proc) = self.processes.iter().find(|p| p.pid == pid).cloned() {
                     // Process info
                     ui.group(|ui| {
                         ui.set_min_height(80.0);
                         ui.horizontal(|ui| {
-                            ui.label(egui::RichText::new("Tiến trình đã chọn:").strong().size(14.0));
+                            ui.label(egui::RichText::new(t!("selected-process")).strong().size(14.0));
                             ui.label(egui::RichText::new(format!("{} (PID: {})", proc.name, proc.pid)).size(14.0));
                         });
                         ui.separator();
@@ -289,18 +598,54 @@ impl eframe::App for RamManagerApp {
                             ui.label(format!("💾 RAM: {:.1} MB", proc.memory_mb));
                             ui.separator();
                             ui.label(format!("⚙️ CPU: {:.1}%", proc.cpu_usage));
+                            if let Some(swapped_mb) = proc.swapped_mb {
+                                ui.separator();
+                                ui.label(format!("💽 Swap: {:.1} MB", swapped_mb));
+                            }
+                            if let Some(oom) = proc.oom {
+                                ui.separator();
+                                ui.label(t!("process-oom", score = oom.score.to_string(), adj = oom.adj.to_string()));
+                            }
+                        });
+                        ui.horizontal(|ui| {
+                            let rate = |value: Option<f64>| value.map(|r| format!("{:.0}", r)).unwrap_or_else(|| "-".to_string());
+                            ui.label(t!(
+                                "process-faults",
+                                total = rate(proc.page_faults_per_sec),
+                                major = rate(proc.major_faults_per_sec)
+                            ));
+                            if let Some(history) = self.fault_history.get(&pid) {
+                                fault_sparkline(ui, history);
+                            }
                         });
+                        if proc.is_thrashing(&self.config.thresholds) {
+                            ui.colored_label(egui::Color32::from_rgb(231, 76, 60), t!("thrashing-warning"));
+                        }
                         ui.horizontal(|ui| {
-                            ui.label("🏷️ Trạng thái:");
+                            ui.label(t!("status-label"));
                             let color = proc.status.color();
                             ui.label(proc.status.icon());
                             ui.colored_label(
                                 egui::Color32::from_rgb(color[0], color[1], color[2]),
-                                proc.status.as_str(),
+                                proc.status.label(),
                             );
                         });
                     });
 
+                    // Subtree
+                    if let Some(node) = process_tree::find_node(&self.tree, pid) {
+                        if !node.children.is_empty() {
+                            let label = t!(
+                                "subtree-apply",
+                                count = node.process_count(),
+                                mb = num(node.total_memory_mb),
+                                cpu = num(node.total_cpu_usage as f64)
+                            );
+                            ui.add_space(5.0);
+                            ui.checkbox(&mut self.apply_to_subtree, label);
+                        }
+                    }
+
                     ui.add_space(15.0);
 
                     // Controls
@@ -309,23 +654,25 @@ impl eframe::App for RamManagerApp {
                         ui.group(|ui| {
                             ui.colored_label(
                                 egui::Color32::from_rgb(46, 204, 113),
-                                egui::RichText::new("🔒 1. Ưu tiên giữ trong RAM vật lý").strong().size(14.0),
+                                egui::RichText::new(t!("pin-title")).strong().size(14.0),
                             );
-                            ui.label("Tăng working set và priority để giữ tiến trình trong bộ nhớ thật");
+                            ui.label(t!("pin-desc"));
 
                             ui.add_space(8.0);
                             ui.horizontal(|ui| {
                                 ui.label("Working Set:");
-                                ui.add(egui::Slider::new(&mut self.pin_working_set_mb, 128..=4096)
+                                ui.add(egui::Slider::new(&mut self.pin_working_set_mb, self.config.sliders.pin_min_mb..=self.config.sliders.pin_max_mb)
                                     .suffix(" MB"));
                             });
 
+                            if cfg!(target_os = "linux") {
+                                self.show_oom_option(ui, true);
+                            }
+
                             ui.add_space(8.0);
-                            if ui.button("🔒 Ghim vào RAM").clicked() {
-                                match self.manager.pin_to_ram(pid, self.pin_working_set_mb) {
-                                    Ok(msg) => self.status_message = msg,
-                                    Err(e) => self.status_message = format!("❌ {}", e),
-                                }
+                            let action = ProcessAction::Pin { working_set_mb: self.pin_working_set_mb };
+                            if action_button(ui, t!("pin-button"), &self.permissions.check(&action, pid)) {
+                                self.run_action(pid, action);
                             }
                         });
 
@@ -335,16 +682,16 @@ impl eframe::App for RamManagerApp {
                         ui.group(|ui| {
                             ui.colored_label(
                                 egui::Color32::from_rgb(52, 152, 219),
-                                egui::RichText::new("🗜️ 2. Giảm working set / Đẩy ra nền").strong().size(14.0),
+                                egui::RichText::new(t!("trim-title")).strong().size(14.0),
                             );
-                            ui.label("Thu nhỏ bộ nhớ đang dùng và hạ priority");
+                            ui.label(t!("trim-desc"));
 
                             ui.add_space(8.0);
-                            if ui.button("🗜️ Trim Working Set").clicked() {
-                                match self.manager.trim_working_set(pid) {
-                                    Ok(msg) => self.status_message = msg,
-                                    Err(e) => self.status_message = format!("❌ {}", e),
-                                }
+                            if cfg!(target_os = "linux") {
+                                self.show_pageout_options(ui, pid);
+                            }
+                            if action_button(ui, t!("action-trim"), &self.permissions.check(&ProcessAction::Trim, pid)) {
+                                self.run_action(pid, ProcessAction::Trim);
                             }
                         });
 
@@ -354,73 +701,1795 @@ impl eframe::App for RamManagerApp {
                         ui.group(|ui| {
                             ui.colored_label(
                                 egui::Color32::from_rgb(230, 126, 34),
-                                egui::RichText::new("⚠️ 3. Giới hạn tài nguyên").strong().size(14.0),
+                                egui::RichText::new(t!("limit-title")).strong().size(14.0),
                             );
-                            ui.label("Đặt giới hạn working set tối đa");
+                            ui.label(t!("limit-desc"));
 
                             ui.add_space(8.0);
                             ui.horizontal(|ui| {
-                                ui.label("Giới hạn:");
-                                ui.add(egui::Slider::new(&mut self.limit_max_ws_mb, 64..=2048)
+                                ui.label(t!("limit-label"));
+                                ui.add(egui::Slider::new(&mut self.limit_max_ws_mb, self.config.sliders.limit_min_mb..=self.config.sliders.limit_max_mb)
                                     .suffix(" MB"));
                             });
 
+                            self.show_limit_mode(ui);
+                            if cfg!(target_os = "linux") {
+                                self.show_oom_option(ui, false);
+                            }
+
                             ui.add_space(8.0);
-                            if ui.button("⚠️ Áp dụng giới hạn").clicked() {
-                                match self.manager.limit_resources(pid, self.limit_max_ws_mb) {
-                                    Ok(msg) => self.status_message = msg,
-                                    Err(e) => self.status_message = format!("❌ {}", e),
+                            let action = self.limit_action();
+                            if action_button(ui, t!("limit-button"), &self.permissions.check(&action, pid)) {
+                                self.run_action(pid, action);
+                            }
+
+                            if cfg!(target_os = "linux") {
+                                ui.separator();
+                                ui.label(t!("swap-limit-desc"));
+                                self.show_swap_fields(ui);
+                                let action = self.swap_action();
+                                if action_button(ui, t!("swap-limit-button"), &self.permissions.check(&action, pid)) {
+                                    self.run_action(pid, action);
                                 }
                             }
                         });
 
                         ui.add_space(10.0);
 
+                        // Freeze / Hibernate
+                        ui.group(|ui| {
+                            ui.colored_label(
+                                egui::Color32::from_rgb(93, 173, 226),
+                                egui::RichText::new(t!("freeze-title")).strong().size(14.0),
+                            );
+                            ui.label(t!("freeze-desc"));
+
+                            ui.add_space(8.0);
+                            ui.horizontal(|ui| {
+                                if action_button(ui, t!("freeze-button"), &self.permissions.check(&ProcessAction::Freeze, pid)) {
+                                    self.run_action(pid, ProcessAction::Freeze);
+                                }
+                                if action_button(ui, t!("hibernate-button"), &self.permissions.check(&ProcessAction::Hibernate, pid)) {
+                                    self.run_action(pid, ProcessAction::Hibernate);
+                                }
+                            });
+                        });
+
+                        ui.add_space(10.0);
+
                         // Restore
                         ui.group(|ui| {
                             ui.colored_label(
                                 egui::Color32::GRAY,
-                                egui::RichText::new("♻️ Khôi phục về bình thường").strong().size(14.0),
+                                egui::RichText::new(t!("restore-title")).strong().size(14.0),
                             );
-                            ui.label("Reset tất cả cài đặt về mặc định");
+                            ui.label(t!("restore-desc"));
 
                             ui.add_space(8.0);
-                            if ui.button("♻️ Khôi phục").clicked() {
-                                match self.manager.restore_process(pid) {
-                                    Ok(msg) => self.status_message = msg,
-                                    Err(e) => self.status_message = format!("❌ {}", e),
-                                }
+                            if action_button(ui, t!("restore-button"), &self.permissions.check(&ProcessAction::Restore, pid)) {
+                                self.run_action(pid, ProcessAction::Restore);
                             }
                         });
+
+                        ui.add_space(10.0);
+
+                        // Memory map
+                        self.show_memory_inspector(ui, pid);
                     });
                 } else {
                     ui.vertical_centered(|ui| {
                         ui.add_space(50.0);
-                        ui.label(egui::RichText::new("❌ Tiến trình không tồn tại").size(16.0));
-                        if ui.button("🔄 Làm mới").clicked() {
-                            self.selected_pid = None;
-                            self.processes = self.manager.list_processes();
+                        ui.label(egui::RichText::new(t!("process-gone")).size(16.0));
+                        if ui.button(t!("refresh-button")).clicked() {
+                            self.selected_pids.clear();
+                            self.worker.send(WorkerCommand::Refresh);
                         }
                     });
                 }
             } else {
                 ui.vertical_centered(|ui| {
                     ui.add_space(100.0);
-                    ui.heading("👈 Chọn một tiến trình từ danh sách");
-                    ui.label("Click vào PID để bắt đầu");
+                    ui.heading(t!("pick-process"));
+                    ui.label(t!("pick-process-hint"));
                 });
             }
 
             ui.add_space(20.0);
             ui.separator();
-            
+
+            self.show_launch_section(ui);
+            self.show_memory_breakdown(ui);
+            self.show_swap_section(ui);
+            self.show_effect_section(ui);
+            self.show_alert_section(ui);
+            self.show_early_oom_section(ui);
+            self.show_permission_section(ui);
+            self.show_profile_section(ui);
+
             // Help
-            ui.collapsing("❓ Hướng dẫn", |ui| {
-                ui.label("🔒 Pin: Giữ tiến trình trong RAM vật lý");
-                ui.label("🗜️ Trim: Giải phóng RAM không dùng");
-                ui.label("⚠️ Limit: Giới hạn RAM tối đa");
-                ui.label("♻️ Restore: Đưa về trạng thái ban đầu");
+            ui.collapsing(t!("help-heading"), |ui| {
+                ui.label(t!("help-pin"));
+                ui.label(t!("help-trim"));
+                ui.label(t!("help-limit"));
+                ui.label(t!("help-freeze"));
+                ui.label(t!("help-restore"));
+                ui.label(t!("help-launch"));
+                ui.label(t!("help-cgroup"));
+                ui.label(t!("help-multi-select"));
+                ui.label(t!("help-inspector"));
+            });
+        });
+
+        self.update_watched();
+    }
+}
+
+impl RamManagerApp {
+    // Ghi các giá trị đang dùng trên GUI ngược lại config.toml
+    fn save_config(&mut self) {
+        self.config.actions.pin_working_set_mb = self.pin_working_set_mb;
+        self.config.actions.limit_max_ws_mb = self.limit_max_ws_mb;
+        self.config.actions.limit_mode = self.limit_mode;
+        self.config.actions.limit_breach = self.limit_breach;
+        self.config.actions.swap_max_mb = self.swap_max_mb;
+        self.config.actions.zswap_max_mb = self.zswap_max_mb;
+        self.config.sampling.auto_refresh = self.auto_refresh;
+        self.config.sampling.refresh_interval_secs = self.sample_interval_secs;
+        self.config.sampling.full_refresh_interval_secs = self
+            .config
+            .sampling
+            .full_refresh_interval_secs
+            .max(self.sample_interval_secs);
+        self.config.ui.theme = self.theme;
+        self.config.ui.show_stats = self.show_stats;
+        self.config.ui.language = self.language;
+        self.config.ui.font = self.font.clone();
+        self.config.ui.scale = self.ui_scale;
+
+        self.status_message = match self.config.save(&self.config_path) {
+            Ok(()) => t!("status-config-saved", path = self.config_path.display().to_string()),
+            Err(e) => format!("❌ {}", e),
+        };
+    }
+
+    fn show_display_settings(&mut self, ctx: &egui::Context) {
+        let mut open = true;
+        let mut new_font = None;
+
+        egui::Window::new(t!("display-heading"))
+            .open(&mut open)
+            .resizable(false)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.label(egui::RichText::new(t!("display-font")).strong());
+                let is_file = matches!(self.font, FontChoice::File(_));
+                if ui.radio(self.font == FontChoice::DejavuSans, t!("font-dejavu")).clicked() {
+                    new_font = Some(FontChoice::DejavuSans);
+                }
+                if ui.radio(self.font == FontChoice::EguiDefault, t!("font-egui-default")).clicked() {
+                    new_font = Some(FontChoice::EguiDefault);
+                }
+                ui.horizontal(|ui| {
+                    let picked = ui.radio(is_file, t!("font-file")).clicked();
+                    ui.text_edit_singleline(&mut self.font_path_input)
+                        .on_hover_text(t!("font-file-hint"));
+                    let has_path = !self.font_path_input.trim().is_empty();
+                    let applied = ui.add_enabled(has_path, egui::Button::new(t!("font-apply"))).clicked();
+                    if has_path && (picked || applied) {
+                        new_font = Some(FontChoice::File(self.font_path_input.trim().into()));
+                    }
+                });
+
+                ui.add_space(8.0);
+                ui.label(egui::RichText::new(t!("display-scale")).strong());
+                let scale = ui.add(
+                    egui::Slider::new(&mut self.ui_scale, config::MIN_UI_SCALE..=config::MAX_UI_SCALE)
+                        .step_by(0.05)
+                        .custom_formatter(|v, _| format!("{:.0}%", v * 100.0)),
+                );
+                // Áp dụng khi thả chuột, tránh giao diện co giãn liên tục lúc đang kéo
+                if scale.drag_stopped() || (scale.changed() && !scale.dragged()) {
+                    ctx.set_zoom_factor(self.ui_scale);
+                }
+            });
+
+        if let Some(font) = new_font {
+            self.status_message = match fonts::configure_fonts(ctx, &font) {
+                Ok(()) => {
+                    self.font = font;
+                    t!("status-font-applied")
+                }
+                Err(e) => format!("❌ {}", e),
+            };
+        }
+        self.show_display_settings = open;
+    }
+
+    // None = tự động theo ngôn ngữ của hệ điều hành
+    fn show_language_picker(&mut self, ui: &mut egui::Ui) {
+        let auto_label = t!("language-auto", language = Language::detect().native_name());
+        let selected_text = match self.language {
+            Some(language) => language.native_name().to_string(),
+            None => auto_label.clone(),
+        };
+
+        let previous = self.language;
+        egui::ComboBox::from_id_source("language_select")
+            .selected_text(format!("🌐 {}", selected_text))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut self.language, None, auto_label);
+                for language in Language::ALL {
+                    ui.selectable_value(&mut self.language, Some(language), language.native_name());
+                }
+            })
+            .response
+            .on_hover_text(t!("top-language-hint"));
+
+        if self.language != previous {
+            i18n::set_language(self.language.unwrap_or_else(Language::detect));
+        }
+    }
+
+    fn process_worker_events(&mut self) {
+        for event in self.worker.poll() {
+            match event {
+                WorkerEvent::Snapshot(snapshot) => self.apply_snapshot(*snapshot),
+                WorkerEvent::ActionDone(result) => {
+                    self.in_flight = self.in_flight.saturating_sub(1);
+                    match result {
+                        Ok(msg) => self.status_message = msg,
+                        Err(e) => self.status_message = format!("❌ {}", e),
+                    }
+                }
+                WorkerEvent::GroupDone(report) => {
+                    self.in_flight = self.in_flight.saturating_sub(1);
+                    self.status_message = report.summary();
+                    self.last_group_report = Some(report);
+                }
+                WorkerEvent::ProfileDone(result) => {
+                    self.in_flight = self.in_flight.saturating_sub(1);
+                    match result {
+                        Ok(report) => self.status_message = report.summary(),
+                        Err(e) => self.status_message = format!("❌ {}", e),
+                    }
+                }
+                WorkerEvent::MemoryMap(result) => {
+                    self.in_flight = self.in_flight.saturating_sub(1);
+                    match result {
+                        Ok(mut map) => {
+                            map.sort(self.region_sort, self.region_sort_descending);
+                            self.status_message = t!("status-memory-map-read", count = map.regions.len(), pid = map.pid);
+                            self.memory_map = Some(map);
+                        }
+                        Err(e) => self.status_message = format!("❌ {}", e),
+                    }
+                }
+                WorkerEvent::Cgroups(result) => {
+                    self.in_flight = self.in_flight.saturating_sub(1);
+                    match result {
+                        Ok(root) => {
+                            self.status_message = t!("status-cgroups-read", count = root.process_count());
+                            self.cgroup_tree = Some(root);
+                        }
+                        Err(e) => self.status_message = format!("❌ {}", e),
+                    }
+                }
+                WorkerEvent::Effects(reports) => self.effect_reports = reports,
+                WorkerEvent::Pageout(report) => self.pageout_report = Some(report),
+                WorkerEvent::LaunchExited(exit) => {
+                    self.status_message = exit.message();
+                    self.launch_history.push(exit);
+                    if self.launch_history.len() > LAUNCH_HISTORY_LEN {
+                        self.launch_history.remove(0);
+                    }
+                }
+                WorkerEvent::BatchProgress(result) => {
+                    if let Some(job) = &mut self.batch_job {
+                        job.record(result);
+                        if job.is_done() {
+                            self.in_flight = self.in_flight.saturating_sub(1);
+                            self.status_message = job.summary();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn apply_snapshot(&mut self, snapshot: Snapshot) {
+        self.tree = process_tree::build_tree(&snapshot.processes);
+        self.groups = process_group::group_by_name(&snapshot.processes);
+        self.processes = snapshot.processes;
+        self.system_info = snapshot.system_info;
+        self.memory_breakdown = snapshot.memory_breakdown;
+        self.swap_stats = snapshot.swap;
+        self.fault_rates = snapshot.fault_rates;
+        for proc in self.processes.iter() {
+            if let Some(rate) = proc.fault_rate() {
+                let history = self.fault_history.entry(proc.pid).or_default();
+                history.push_back(rate);
+                if history.len() > FAULT_HISTORY_LEN {
+                    history.pop_front();
+                }
+            }
+        }
+        let alive: BTreeSet<u32> = self.processes.iter().map(|p| p.pid).collect();
+        self.fault_history.retain(|pid, _| alive.contains(pid));
+        self.statistics = snapshot.statistics;
+        self.active_profile = snapshot.active_profile;
+        self.profile_history = snapshot.profile_history;
+        // Cảnh báo mới nhất hiện lên status bar
+        let last_seen = self.alert_history.last().map(|a| a.id);
+        if let Some(alert) = snapshot.alert_history.last().filter(|a| Some(a.id) != last_seen) {
+            self.status_message = format!("🔔 {}", alert.message);
+        }
+        self.alert_history = snapshot.alert_history;
+        self.early_oom_history = snapshot.early_oom_history;
+        self.helper_status = snapshot.helper;
+        self.permissions = snapshot.permissions;
+    }
+
+    fn apply_selected_profile(&mut self) {
+        match self.config.find_profile(&self.selected_profile) {
+            Some(profile) => {
+                self.status_message = t!("status-applying-profile", profile = profile.name.as_str());
+                self.in_flight += 1;
+                self.worker.send(WorkerCommand::ApplyProfile(profile.clone()));
+            }
+            None => {
+                self.status_message = t!("status-profile-not-found", profile = self.selected_profile.as_str());
+            }
+        }
+    }
+
+    fn revert_profile(&mut self) {
+        self.status_message = t!("status-reverting-profile");
+        self.in_flight += 1;
+        self.worker.send(WorkerCommand::RevertProfile);
+    }
+
+    // Khởi chạy chương trình trong cgroup (Linux) / job object (Windows) mới với giới hạn đặt sẵn
+    fn show_launch_section(&mut self, ui: &mut egui::Ui) {
+        ui.collapsing(t!("launch-heading"), |ui| {
+            ui.label(t!("launch-desc"));
+            ui.horizontal(|ui| {
+                ui.label(t!("launch-command"));
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.launch_command)
+                        .hint_text(t!("launch-command-hint"))
+                        .desired_width(f32::INFINITY),
+                );
+            });
+
+            let policy = &mut self.launch_policy;
+            limit_field(ui, t!("launch-memory-max"), &mut policy.memory_max_mb, 1024);
+            if cfg!(target_os = "linux") {
+                limit_field(ui, t!("launch-memory-high"), &mut policy.memory_high_mb, 768);
+                limit_field(ui, t!("launch-swap-max"), &mut policy.swap_max_mb, 0);
+            }
+            ui.horizontal(|ui| {
+                ui.label(t!("launch-priority"));
+                egui::ComboBox::from_id_source("launch_priority")
+                    .selected_text(policy.priority.map_or_else(|| t!("launch-priority-default"), |p| p.as_str().to_string()))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut policy.priority, None, t!("launch-priority-default"));
+                        for priority in Priority::ALL {
+                            ui.selectable_value(&mut policy.priority, Some(priority), priority.as_str());
+                        }
+                    });
+                if let Some(Err(reason)) = policy.priority.map(|p| self.permissions.check_priority(p)) {
+                    ui.colored_label(egui::Color32::from_rgb(230, 126, 34), "⚠️").on_hover_text(reason);
+                }
+            });
+
+            ui.add_space(5.0);
+            let command = self.launch_command.trim();
+            if ui.add_enabled(!command.is_empty(), egui::Button::new(t!("launch-button"))).clicked() {
+                self.status_message = t!("status-launching", command = command);
+                self.in_flight += 1;
+                self.worker.send(WorkerCommand::Launch(LaunchRequest {
+                    command: command.to_string(),
+                    policy: self.launch_policy.clone(),
+                }));
+            }
+
+            if !self.launch_history.is_empty() {
+                ui.add_space(5.0);
+                ui.label(egui::RichText::new(t!("launch-history")).strong());
+            }
+            for exit in self.launch_history.iter().rev() {
+                let ago = exit.at.elapsed().map(|d| d.as_secs()).unwrap_or(0);
+                let success = matches!(&exit.status, Ok(status) if status.success());
+                let color = if success {
+                    egui::Color32::from_rgb(46, 204, 113)
+                } else {
+                    egui::Color32::from_rgb(231, 76, 60)
+                };
+                ui.colored_label(color, t!("launch-history-entry", message = exit.message(), seconds = ago));
+            }
+        });
+    }
+
+    // Phân tích RAM toàn hệ thống, mỗi dòng kèm thanh tỉ lệ so với tổng RAM
+    fn show_memory_breakdown(&mut self, ui: &mut egui::Ui) {
+        ui.collapsing(t!("breakdown-heading"), |ui| {
+            let breakdown = match &self.memory_breakdown {
+                Ok(breakdown) => breakdown,
+                Err(e) => {
+                    ui.colored_label(egui::Color32::from_rgb(231, 76, 60), format!("❌ {}", e));
+                    return;
+                }
+            };
+            let total_kb = breakdown.total_kb.max(1) as f32;
+            let rows = [
+                (t!("breakdown-anon"), breakdown.anon_kb),
+                (t!("breakdown-page-cache"), Some(breakdown.page_cache_kb)),
+                (t!("breakdown-buffers"), breakdown.buffers_kb),
+                (t!("breakdown-shmem"), breakdown.shmem_kb),
+                (t!("breakdown-slab-reclaimable"), breakdown.slab_reclaimable_kb),
+                (t!("breakdown-slab-unreclaimable"), breakdown.slab_unreclaimable_kb),
+                (t!("breakdown-dirty"), breakdown.dirty_kb),
+                (t!("breakdown-writeback"), breakdown.writeback_kb),
+                (t!("breakdown-free"), Some(breakdown.free_kb)),
+                (t!("breakdown-available"), Some(breakdown.available_kb)),
+            ];
+
+            egui::Grid::new("memory_breakdown_grid")
+                .striped(true)
+                .spacing([10.0, 4.0])
+                .show(ui, |ui| {
+                    for (label, kb) in rows {
+                        let Some(kb) = kb else { continue };
+                        let fraction = kb as f32 / total_kb;
+                        ui.label(label);
+                        ui.label(memory_map::format_kb(kb));
+                        ui.add(
+                            egui::ProgressBar::new(fraction)
+                                .desired_width(160.0)
+                                .text(format!("{:.1}%", fraction * 100.0)),
+                        );
+                        ui.end_row();
+                    }
+
+                    ui.label("Swap");
+                    if breakdown.swap_total_kb > 0 {
+                        let used = breakdown.swap_used_kb();
+                        ui.label(format!(
+                            "{} / {}",
+                            memory_map::format_kb(used),
+                            memory_map::format_kb(breakdown.swap_total_kb)
+                        ));
+                        ui.add(
+                            egui::ProgressBar::new(used as f32 / breakdown.swap_total_kb as f32)
+                                .desired_width(160.0)
+                                .show_percentage(),
+                        );
+                    } else {
+                        ui.label(t!("breakdown-no-swap"));
+                    }
+                    ui.end_row();
+
+                    // Commit vượt commit limit thì hiện màu đỏ
+                    let ratio = breakdown.commit_ratio();
+                    ui.label(t!("breakdown-commit")).on_hover_text(t!("breakdown-commit-hint"));
+                    ui.label(format!(
+                        "{} / {}",
+                        memory_map::format_kb(breakdown.committed_kb),
+                        memory_map::format_kb(breakdown.commit_limit_kb)
+                    ));
+                    let color = if ratio > 1.0 {
+                        egui::Color32::from_rgb(231, 76, 60)
+                    } else {
+                        egui::Color32::from_rgb(52, 152, 219)
+                    };
+                    ui.add(
+                        egui::ProgressBar::new(ratio.min(1.0) as f32)
+                            .desired_width(160.0)
+                            .fill(color)
+                            .text(format!("{:.1}%", ratio * 100.0)),
+                    );
+                    ui.end_row();
+
+                    let rates = &self.fault_rates;
+                    let rate = |value: Option<f64>| value.map(|r| format!("{:.0}/s", r)).unwrap_or_else(|| "-".to_string());
+                    ui.label(t!("breakdown-faults")).on_hover_text(t!("breakdown-faults-hint"));
+                    ui.label(t!(
+                        "breakdown-faults-value",
+                        total = rate(rates.faults_per_sec),
+                        major = rate(rates.major_per_sec)
+                    ));
+                    ui.end_row();
+                    if rates.pgscan_per_sec.is_some() || rates.pgsteal_per_sec.is_some() {
+                        ui.label(t!("breakdown-reclaim")).on_hover_text(t!("breakdown-reclaim-hint"));
+                        ui.label(t!(
+                            "breakdown-reclaim-value",
+                            scan = rate(rates.pgscan_per_sec),
+                            steal = rate(rates.pgsteal_per_sec)
+                        ));
+                        ui.end_row();
+                    }
+
+                    if let Some(huge) = &breakdown.hugepages {
+                        ui.label("HugePages");
+                        ui.label(t!(
+                            "breakdown-hugepages",
+                            free = huge.free,
+                            total = huge.total,
+                            size = memory_map::format_kb(huge.page_size_kb)
+                        ));
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+
+    // Swap/page file, vùng nén và tốc độ swap-in/out để biết trim có đẩy dữ liệu ra đĩa hay không
+    fn show_swap_section(&mut self, ui: &mut egui::Ui) {
+        ui.collapsing(t!("swap-heading"), |ui| {
+            let stats = match &self.swap_stats {
+                Ok(stats) => stats,
+                Err(e) => {
+                    ui.colored_label(egui::Color32::from_rgb(231, 76, 60), format!("❌ {}", e));
+                    return;
+                }
+            };
+
+            let rate = |value: Option<f64>| {
+                value
+                    .map(|kb| format!("{}/s", memory_map::format_kb(kb as u64)))
+                    .unwrap_or_else(|| "-".to_string())
+            };
+            ui.horizontal(|ui| {
+                ui.label(t!("swap-in-rate", rate = rate(stats.swap_in_kb_per_sec)));
+                ui.separator();
+                ui.label(t!("swap-out-rate", rate = rate(stats.swap_out_kb_per_sec)))
+                    .on_hover_text(t!("swap-rate-hint"));
+            });
+
+            ui.add_space(5.0);
+            if stats.devices.is_empty() {
+                ui.label(t!("breakdown-no-swap"));
+            } else {
+                egui::Grid::new("swap_devices_grid")
+                    .striped(true)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label(egui::RichText::new(t!("swap-device")).strong());
+                        ui.label(egui::RichText::new(t!("col-kind")).strong());
+                        ui.label(egui::RichText::new(t!("swap-used")).strong());
+                        ui.label(egui::RichText::new(t!("swap-priority")).strong());
+                        ui.end_row();
+
+                        for device in stats.devices.iter() {
+                            let fraction = if device.size_kb > 0 {
+                                device.used_kb as f32 / device.size_kb as f32
+                            } else {
+                                0.0
+                            };
+                            ui.label(&device.name);
+                            ui.label(&device.kind);
+                            ui.add(
+                                egui::ProgressBar::new(fraction).desired_width(200.0).text(format!(
+                                    "{} / {}",
+                                    memory_map::format_kb(device.used_kb),
+                                    memory_map::format_kb(device.size_kb)
+                                )),
+                            );
+                            ui.label(device.priority.map(|p| p.to_string()).unwrap_or_else(|| "-".to_string()));
+                            ui.end_row();
+                        }
+                    });
+            }
+
+            if !stats.compressed.is_empty() {
+                ui.add_space(5.0);
+                ui.label(egui::RichText::new(t!("swap-compressed")).strong());
+                egui::Grid::new("swap_compressed_grid")
+                    .striped(true)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label(egui::RichText::new(t!("swap-device")).strong());
+                        ui.label(egui::RichText::new(t!("swap-original")).strong());
+                        ui.label(egui::RichText::new(t!("swap-compressed-size")).strong());
+                        ui.label(egui::RichText::new(t!("swap-ratio")).strong());
+                        ui.label(egui::RichText::new(t!("swap-ram-used")).strong());
+                        ui.end_row();
+
+                        for entry in stats.compressed.iter() {
+                            ui.label(&entry.name);
+                            ui.label(kb_cell(entry.original_kb));
+                            ui.label(memory_map::format_kb(entry.compressed_kb));
+                            ui.label(entry.ratio().map(|r| format!("{:.2}×", r)).unwrap_or_else(|| "-".to_string()));
+                            ui.label(kb_cell(entry.ram_used_kb));
+                            ui.end_row();
+                        }
+                    });
+            }
+
+            if !stats.top_processes.is_empty() {
+                ui.add_space(5.0);
+                ui.label(egui::RichText::new(t!("swap-top-processes")).strong());
+                for entry in stats.top_processes.iter() {
+                    ui.label(format!("{} (PID: {}) — {:.1} MB", entry.name, entry.pid, entry.swapped_mb));
+                }
+            }
+        });
+    }
+
+    // RAM giải phóng được so với phần bị fault ngược vào trong cửa sổ đo sau mỗi hành động
+    fn show_effect_section(&mut self, ui: &mut egui::Ui) {
+        ui.collapsing(t!("effect-heading"), |ui| {
+            ui.horizontal(|ui| {
+                ui.label(t!(
+                    "effect-window",
+                    window = self.config.effects.window_secs.to_string(),
+                    interval = self.config.effects.sample_interval_secs.to_string()
+                ));
+                ui.separator();
+                let export = ui.add_enabled(!self.effect_reports.is_empty(), egui::Button::new(t!("effect-export")));
+                if export.on_hover_text(t!("effect-export-hint")).clicked() {
+                    let path = effect::export_path();
+                    self.status_message = match effect::export(&self.effect_reports, &path) {
+                        Ok(()) => t!("status-effects-exported", count = self.effect_reports.len(), path = path.display().to_string()),
+                        Err(e) => format!("❌ {}", e),
+                    };
+                }
             });
+
+            if self.effect_reports.is_empty() {
+                ui.label(t!("effect-empty"));
+                return;
+            }
+
+            let mb = |value: Option<f64>| value.map(|v| format!("{:.1} MB", v)).unwrap_or_else(|| "-".to_string());
+            egui::Grid::new("effect_reports_grid")
+                .striped(true)
+                .spacing([10.0, 4.0])
+                .show(ui, |ui| {
+                    for header in [
+                        t!("col-name"),
+                        t!("effect-action"),
+                        t!("effect-before"),
+                        t!("effect-lowest"),
+                        t!("effect-freed"),
+                        t!("effect-refaulted"),
+                        t!("effect-refault-rate"),
+                        t!("col-faults"),
+                        t!("col-result"),
+                    ] {
+                        ui.label(egui::RichText::new(header).strong());
+                    }
+                    ui.end_row();
+
+                    for report in self.effect_reports.iter().rev().take(20) {
+                        let verdict = report.verdict();
+                        let [r, g, b] = verdict.color();
+                        ui.label(format!("{} ({})", report.name, report.pid));
+                        ui.label(report.action.label());
+                        ui.label(format!("{:.1} MB", report.before_mb));
+                        ui.label(mb(report.lowest_mb()));
+                        ui.label(mb(report.freed_mb()));
+                        ui.label(match (report.refaulted_mb(), report.refault_percent()) {
+                            (Some(refaulted), Some(percent)) => format!("{:.1} MB ({:.0}%)", refaulted, percent),
+                            (refaulted, _) => mb(refaulted),
+                        });
+                        ui.label(report.refault_mb_per_sec().map(|r| format!("{:.2} MB/s", r)).unwrap_or_else(|| "-".to_string()));
+                        ui.label(report.faults_per_sec().map(|r| format!("{:.0}/s", r)).unwrap_or_else(|| "-".to_string()));
+                        let mut result = ui.colored_label(egui::Color32::from_rgb(r, g, b), verdict.label());
+                        if report.interrupted {
+                            result = result.on_hover_text(t!("effect-interrupted"));
+                        }
+                        let elapsed = report.samples.last().map(|s| s.offset_secs).unwrap_or(0.0);
+                        result.on_hover_text(t!(
+                            "effect-samples",
+                            count = report.samples.len(),
+                            seconds = format!("{:.0}", elapsed)
+                        ));
+                        ui.end_row();
+                    }
+                });
         });
     }
-}
\ No newline at end of file
+
+    fn show_alert_section(&mut self, ui: &mut egui::Ui) {
+        let heading = if self.alert_history.is_empty() {
+            t!("alert-heading")
+        } else {
+            format!("{} ({})", t!("alert-heading"), self.alert_history.len())
+        };
+        ui.collapsing(heading, |ui| {
+            let alerts = &self.config.alerts;
+            ui.label(egui::RichText::new(t!("alert-rules")).strong());
+            if alerts.rules.is_empty() {
+                ui.label(t!("alert-no-rules"));
+            }
+            for rule in alerts.rules.iter() {
+                let target = rule
+                    .target
+                    .as_ref()
+                    .map(|target| format!(" — {:?}", target))
+                    .unwrap_or_default();
+                ui.label(format!("• {}: {}{}", rule.name, rule.condition.describe(), target));
+            }
+            ui.horizontal(|ui| {
+                ui.label(if alerts.desktop_notifications {
+                    t!("alert-desktop-on")
+                } else {
+                    t!("alert-desktop-off")
+                });
+                ui.separator();
+                match &alerts.webhook_url {
+                    Some(url) => ui.label(t!("alert-webhook", url = url.as_str())),
+                    None => ui.label(t!("alert-webhook-off")),
+                };
+                ui.separator();
+                ui.label(t!("alert-cooldown", seconds = alerts.cooldown_secs.to_string()));
+            });
+
+            ui.add_space(5.0);
+            ui.label(egui::RichText::new(t!("alert-history")).strong());
+            if self.alert_history.is_empty() {
+                ui.label(t!("alert-history-empty"));
+            }
+            for alert in self.alert_history.iter().rev().take(20) {
+                let ago = alert.at.elapsed().map(|d| d.as_secs()).unwrap_or(0);
+                ui.label(t!("alert-history-entry", rule = alert.rule.as_str(), message = alert.message.as_str(), seconds = ago));
+                for error in alert.delivery_errors.iter() {
+                    ui.colored_label(egui::Color32::from_rgb(231, 76, 60), format!("   ❌ {}", error));
+                }
+            }
+        });
+    }
+
+    fn show_early_oom_section(&mut self, ui: &mut egui::Ui) {
+        let heading = if self.early_oom_history.is_empty() {
+            t!("early-oom-heading")
+        } else {
+            format!("{} ({})", t!("early-oom-heading"), self.early_oom_history.len())
+        };
+        ui.collapsing(heading, |ui| {
+            let config = &mut self.config.early_oom;
+            if ui.checkbox(&mut config.enabled, t!("early-oom-enabled")).on_hover_text(t!("early-oom-enabled-hint")).changed() {
+                self.worker.send(WorkerCommand::SetEarlyOom(config.clone()));
+            }
+            ui.label(t!(
+                "early-oom-thresholds",
+                available = num(config.available_percent),
+                swap = num(config.swap_free_percent)
+            ));
+            ui.label(t!(
+                "early-oom-timing",
+                poll = config.poll_interval_secs.to_string(),
+                cooldown = config.cooldown_secs.to_string()
+            ));
+            let mut protected: Vec<String> = config.protected.iter().map(|target| format!("{:?}", target)).collect();
+            if config.protect_pinned {
+                protected.insert(0, t!("early-oom-protect-pinned"));
+            }
+            ui.label(t!("early-oom-protected", list = protected.join(", ")));
+            ui.label(t!("early-oom-log", path = early_oom::log_path().display().to_string()));
+
+            ui.add_space(5.0);
+            ui.label(egui::RichText::new(t!("early-oom-history")).strong());
+            if self.early_oom_history.is_empty() {
+                ui.label(t!("early-oom-history-empty"));
+            }
+            for event in self.early_oom_history.iter().rev().take(20) {
+                let ago = event.at.elapsed().map(|d| d.as_secs()).unwrap_or(0);
+                let color = if event.result.is_ok() {
+                    egui::Color32::from_rgb(230, 126, 34)
+                } else {
+                    egui::Color32::from_rgb(231, 76, 60)
+                };
+                ui.colored_label(color, t!("early-oom-history-entry", message = event.message(), seconds = ago));
+                if let Some(error) = &event.log_error {
+                    ui.colored_label(egui::Color32::from_rgb(231, 76, 60), format!("   ❌ {}", error));
+                }
+            }
+        });
+    }
+
+    fn limit_action(&self) -> ProcessAction {
+        ProcessAction::Limit {
+            max_ws_mb: self.limit_max_ws_mb,
+            mode: self.limit_mode,
+            breach: self.limit_breach,
+        }
+    }
+
+    fn swap_action(&self) -> ProcessAction {
+        ProcessAction::SwapLimit {
+            swap_max_mb: self.swap_max_mb,
+            zswap_max_mb: self.zswap_max_mb,
+        }
+    }
+
+    // Bỏ chọn là không giới hạn, 0 MB là không bao giờ swap
+    fn show_swap_fields(&mut self, ui: &mut egui::Ui) {
+        limit_field(ui, t!("swap-limit-swap"), &mut self.swap_max_mb, 0);
+        limit_field(ui, t!("swap-limit-zswap"), &mut self.zswap_max_mb, 0);
+    }
+
+    // Working set (mềm) hoặc giới hạn cứng, và cách xử lý khi tiến trình chạm giới hạn cứng
+    fn show_limit_mode(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label(t!("limit-mode"));
+            for mode in LimitMode::ALL {
+                ui.radio_value(&mut self.limit_mode, mode, mode.label()).on_hover_text(mode.hint());
+            }
+        });
+        if self.limit_mode == LimitMode::Hard {
+            ui.horizontal(|ui| {
+                ui.label(t!("limit-breach"));
+                for breach in BreachAction::ALL {
+                    ui.radio_value(&mut self.limit_breach, breach, breach.label()).on_hover_text(breach.hint());
+                }
+            });
+        }
+    }
+
+    // Pin hạ / Limit tăng oom_score_adj, giá trị cũ được trả lại khi Restore
+    fn show_oom_option(&mut self, ui: &mut egui::Ui, pinned: bool) {
+        let oom = &mut self.config.oom;
+        let (enabled, value, label) = if pinned {
+            (&mut oom.protect_pinned, &mut oom.pinned_score_adj, t!("oom-protect-pinned"))
+        } else {
+            (&mut oom.expose_limited, &mut oom.limited_score_adj, t!("oom-expose-limited"))
+        };
+        let permitted = if pinned { self.permissions.check_oom_lower() } else { Ok(()) };
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            changed |= ui.checkbox(enabled, label).on_hover_text(t!("oom-option-hint")).changed();
+            let enabled = *enabled;
+            changed |= ui
+                .add_enabled(enabled, egui::DragValue::new(value).range(oom::SCORE_ADJ_MIN..=oom::SCORE_ADJ_MAX))
+                .changed();
+            if let (true, Err(reason)) = (enabled, &permitted) {
+                ui.colored_label(egui::Color32::from_rgb(230, 126, 34), "⚠️").on_hover_text(reason);
+            }
+        });
+        if changed {
+            self.worker.send(WorkerCommand::SetOom(self.config.oom.clone()));
+        }
+    }
+
+    // Trim trên Linux: chọn gợi ý process_madvise, loại vùng nhớ và xem RSS trước/sau của lần gần nhất
+    fn show_pageout_options(&mut self, ui: &mut egui::Ui, pid: u32) {
+        let mut changed = false;
+        let pageout = &mut self.config.pageout;
+        ui.horizontal(|ui| {
+            ui.label(t!("pageout-hint"));
+            for hint in PageoutHint::ALL {
+                changed |= ui.radio_value(&mut pageout.hint, hint, hint.label()).changed();
+            }
+        });
+        ui.horizontal_wrapped(|ui| {
+            ui.label(t!("pageout-regions"));
+            for kind in RegionKind::ALL {
+                let mut selected = pageout.regions.contains(&kind);
+                // Luôn giữ ít nhất một loại vùng nhớ
+                let last = selected && pageout.regions.len() == 1;
+                if ui.add_enabled(!last, egui::Checkbox::new(&mut selected, kind.label())).changed() {
+                    if selected {
+                        pageout.regions.push(kind);
+                    } else {
+                        pageout.regions.retain(|k| *k != kind);
+                    }
+                    changed = true;
+                }
+            }
+        });
+        if changed {
+            self.worker.send(WorkerCommand::SetPageout(self.config.pageout.clone()));
+        }
+
+        let Some(report) = self.pageout_report.as_ref().filter(|r| r.pid == pid) else {
+            return;
+        };
+        ui.add_space(5.0);
+        ui.label(egui::RichText::new(t!("pageout-report", hint = report.hint.label())).strong());
+        egui::Grid::new("pageout_report").striped(true).show(ui, |ui| {
+            ui.label(t!("pageout-col-kind"));
+            ui.label(t!("pageout-col-rss"));
+            ui.label(t!("pageout-col-swap"));
+            ui.end_row();
+            for row in report.rows() {
+                let color = row.kind.color();
+                ui.colored_label(egui::Color32::from_rgb(color[0], color[1], color[2]), row.kind.label());
+                ui.label(format!("{} → {}", memory_map::format_kb(row.rss_before_kb), memory_map::format_kb(row.rss_after_kb)));
+                match (row.swap_before_kb, row.swap_after_kb) {
+                    (Some(before), Some(after)) => {
+                        ui.label(format!("{} → {}", memory_map::format_kb(before), memory_map::format_kb(after)))
+                    }
+                    _ => ui.label("-"),
+                };
+                ui.end_row();
+            }
+        });
+        ui.colored_label(egui::Color32::from_rgb(46, 204, 113), t!("pageout-freed", freed = memory_map::format_kb(report.freed_kb())));
+    }
+
+    // Chỉ có trên Linux khi tự thực hiện thao tác (không qua helper)
+    fn show_permission_section(&mut self, ui: &mut egui::Ui) {
+        if !self.permissions.supported {
+            return;
+        }
+        let permissions = &self.permissions;
+        let mark = |ok: bool| if ok { ("✅", egui::Color32::from_rgb(46, 204, 113)) } else { ("❌", egui::Color32::from_rgb(231, 76, 60)) };
+        ui.collapsing(t!("perm-heading"), |ui| {
+            if let Some(uid) = permissions.effective_uid {
+                ui.label(t!("perm-uid", uid = uid));
+            }
+            ui.label(egui::RichText::new(t!("perm-capabilities")).strong());
+            for (cap, present) in permissions.capabilities.iter() {
+                let (icon, color) = mark(*present);
+                ui.colored_label(color, format!("{} {}", icon, cap.name())).on_hover_text(cap.purpose());
+            }
+
+            ui.add_space(5.0);
+            ui.label(egui::RichText::new(t!("perm-cgroup")).strong());
+            match &permissions.cgroup {
+                Err(e) => {
+                    ui.colored_label(egui::Color32::from_rgb(231, 76, 60), format!("❌ {}", e));
+                }
+                Ok(None) => {
+                    ui.label(t!("perm-cgroup-v1"));
+                }
+                Ok(Some(cgroup)) => {
+                    ui.label(cgroup.path.display().to_string());
+                    match &cgroup.delegated_root {
+                        Some(root) => ui.colored_label(egui::Color32::from_rgb(46, 204, 113), t!("perm-delegated", path = root.display().to_string())),
+                        None => ui.colored_label(egui::Color32::from_rgb(231, 76, 60), t!("perm-not-delegated")),
+                    };
+                    egui::Grid::new("cgroup_files").striped(true).show(ui, |ui| {
+                        for file in cgroup.files.iter() {
+                            let (icon, color) = mark(file.writable);
+                            ui.colored_label(color, format!("{} {}", icon, file.name));
+                            ui.label(file.label());
+                            ui.end_row();
+                        }
+                    });
+                }
+            }
+
+            ui.add_space(5.0);
+            ui.label(egui::RichText::new(t!("perm-actions")).strong());
+            // Quyền cần cho từng hành động phụ thuộc vào tiến trình đích (chủ sở hữu, cgroup, nice)
+            let pid = match self.selected_pids.iter().collect::<Vec<_>>().as_slice() {
+                [pid] => **pid,
+                _ => {
+                    ui.label(t!("perm-select-process"));
+                    return;
+                }
+            };
+            ui.label(t!("perm-target", pid = pid));
+            if let Some(cgroup) = permissions.targets.get(&pid).and_then(|target| target.limit_cgroup.as_ref()) {
+                ui.label(t!("perm-limit-cgroup", path = cgroup.path.display().to_string()));
+            }
+            let actions = [
+                ProcessAction::Pin { working_set_mb: self.pin_working_set_mb },
+                ProcessAction::Trim,
+                self.limit_action(),
+                self.swap_action(),
+                ProcessAction::Freeze,
+                ProcessAction::Hibernate,
+                ProcessAction::Restore,
+            ];
+            for action in actions.iter() {
+                match permissions.check(action, pid) {
+                    Ok(()) => ui.colored_label(egui::Color32::from_rgb(46, 204, 113), format!("✅ {}", action.label())),
+                    Err(reason) => ui.colored_label(egui::Color32::from_rgb(231, 76, 60), format!("❌ {}", reason)),
+                };
+            }
+        });
+    }
+
+    fn show_profile_section(&mut self, ui: &mut egui::Ui) {
+        ui.collapsing(t!("profile-heading"), |ui| {
+            ui.horizontal(|ui| {
+                ui.label(t!("profile-active"));
+                match &self.active_profile {
+                    Some(name) => ui.colored_label(egui::Color32::from_rgb(46, 204, 113), name),
+                    None => ui.label(t!("profile-none")),
+                };
+            });
+
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_source("profile_select")
+                    .selected_text(&self.selected_profile)
+                    .show_ui(ui, |ui| {
+                        for profile in self.config.profiles.iter() {
+                            ui.selectable_value(&mut self.selected_profile, profile.name.clone(), &profile.name)
+                                .on_hover_text(&profile.description);
+                        }
+                    });
+
+                if ui.button(t!("profile-apply")).clicked() {
+                    self.apply_selected_profile();
+                }
+                if ui
+                    .add_enabled(self.active_profile.is_some(), egui::Button::new(t!("profile-revert")))
+                    .on_hover_text(t!("profile-revert-hint"))
+                    .clicked()
+                {
+                    self.revert_profile();
+                }
+            });
+
+            if let Some(profile) = self.config.find_profile(&self.selected_profile) {
+                if !profile.description.is_empty() {
+                    ui.label(&profile.description);
+                }
+                for rule in profile.rules.iter() {
+                    ui.label(format!(
+                        "• {:?} → {}{}",
+                        rule.target,
+                        rule.action.label(),
+                        rule.priority.map(|p| format!(", priority {}", p.as_str())).unwrap_or_default()
+                    ));
+                }
+            }
+
+            if !self.profile_history.is_empty() {
+                ui.add_space(5.0);
+                ui.label(egui::RichText::new(t!("profile-history")).strong());
+                for report in self.profile_history.iter().rev().take(10) {
+                    let ago = report.at.elapsed().map(|d| d.as_secs()).unwrap_or(0);
+                    ui.label(t!("profile-history-entry", summary = report.summary(), seconds = ago));
+                }
+            }
+        });
+    }
+
+    // Ctrl+click bật/tắt, Shift+click chọn dải theo thứ tự đang hiển thị (`order`), click thường chọn một
+    fn click_process(&mut self, proc: &ProcessInfo, modifiers: egui::Modifiers, order: &[u32]) {
+        self.selected_group = None;
+        self.selected_cgroup = None;
+        self.batch_job = self.batch_job.take().filter(|job| !job.is_done());
+
+        batch::click(
+            &mut self.selected_pids,
+            &mut self.selection_anchor,
+            proc.pid,
+            modifiers.command,
+            modifiers.shift,
+            order,
+        );
+
+        self.status_message = if self.selected_pids.len() == 1 {
+            t!("status-selected-one", name = proc.name.as_str(), pid = proc.pid)
+        } else {
+            t!("status-selected-many", count = self.selected_pids.len())
+        };
+    }
+
+    fn show_batch_panel(&mut self, ui: &mut egui::Ui) {
+        let selected: Vec<ProcessInfo> = self
+            .processes
+            .iter()
+            .filter(|p| self.selected_pids.contains(&p.pid))
+            .cloned()
+            .collect();
+        let running = self.batch_job.as_ref().is_some_and(|job| !job.is_done());
+
+        ui.group(|ui| {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(t!("batch-selected")).strong().size(14.0));
+                ui.label(egui::RichText::new(t!("process-count", count = selected.len())).size(14.0));
+            });
+            ui.separator();
+            ui.label(t!("total-ram", mb = num(selected.iter().map(|p| p.memory_mb).sum::<f64>())));
+        });
+
+        ui.add_space(15.0);
+
+        let pids: Vec<u32> = selected.iter().map(|p| p.pid).collect();
+        let mut action = None;
+        ui.add_enabled_ui(!running, |ui| {
+            ui.group(|ui| {
+                ui.label(egui::RichText::new(t!("batch-actions")).strong().size(14.0));
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    ui.label("Working Set:");
+                    ui.add(egui::Slider::new(&mut self.pin_working_set_mb, self.config.sliders.pin_min_mb..=self.config.sliders.pin_max_mb).suffix(" MB"));
+                    let pin = ProcessAction::Pin { working_set_mb: self.pin_working_set_mb };
+                    if action_button(ui, t!("batch-pin-all"), &self.permissions.check_all(&pin, &pids)) {
+                        action = Some(pin);
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label(t!("limit-label"));
+                    ui.add(egui::Slider::new(&mut self.limit_max_ws_mb, self.config.sliders.limit_min_mb..=self.config.sliders.limit_max_mb).suffix(" MB"));
+                    let limit = self.limit_action();
+                    if action_button(ui, t!("batch-limit-all"), &self.permissions.check_all(&limit, &pids)) {
+                        action = Some(limit);
+                    }
+                });
+                if cfg!(target_os = "linux") {
+                    self.show_swap_fields(ui);
+                    let swap = self.swap_action();
+                    if action_button(ui, t!("batch-swap-limit-all"), &self.permissions.check_all(&swap, &pids)) {
+                        action = Some(swap);
+                    }
+                }
+                ui.horizontal(|ui| {
+                    if action_button(ui, t!("batch-trim-all"), &self.permissions.check_all(&ProcessAction::Trim, &pids)) {
+                        action = Some(ProcessAction::Trim);
+                    }
+                    if action_button(ui, t!("batch-freeze-all"), &self.permissions.check_all(&ProcessAction::Freeze, &pids)) {
+                        action = Some(ProcessAction::Freeze);
+                    }
+                    if action_button(ui, t!("batch-hibernate-all"), &self.permissions.check_all(&ProcessAction::Hibernate, &pids)) {
+                        action = Some(ProcessAction::Hibernate);
+                    }
+                    if action_button(ui, t!("batch-restore-all"), &self.permissions.check_all(&ProcessAction::Restore, &pids)) {
+                        action = Some(ProcessAction::Restore);
+                    }
+                });
+            });
+        });
+
+        if let Some(action) = action {
+            let targets: Vec<(u32, String)> = selected.iter().map(|p| (p.pid, p.name.clone())).collect();
+            self.status_message = t!("status-running-action", action = action.label(), count = selected.len());
+            self.in_flight += 1;
+            self.batch_job = Some(BatchJob::new(action.clone(), targets.clone()));
+            self.worker.send(WorkerCommand::Batch(BatchJob::new(action, targets)));
+        }
+
+        if let Some(job) = &self.batch_job {
+            ui.add_space(15.0);
+            ui.group(|ui| {
+                ui.label(egui::RichText::new(t!("batch-progress-heading", action = job.action.label())).strong());
+                ui.add(
+                    egui::ProgressBar::new(job.progress())
+                        .text(format!("{} / {}", job.results.len(), job.total))
+                        .animate(!job.is_done()),
+                );
+                if job.is_done() {
+                    ui.label(job.summary());
+                }
+
+                egui::ScrollArea::vertical().id_source("batch_results").show(ui, |ui| {
+                    egui::Grid::new("batch_results_grid").striped(true).show(ui, |ui| {
+                        ui.label(egui::RichText::new("PID").strong());
+                        ui.label(egui::RichText::new(t!("col-name")).strong());
+                        ui.label(egui::RichText::new(t!("col-result")).strong());
+                        ui.end_row();
+                        for entry in job.results.iter() {
+                            ui.label(entry.pid.to_string());
+                            ui.label(&entry.name);
+                            match &entry.result {
+                                Ok(msg) => ui.colored_label(egui::Color32::from_rgb(46, 204, 113), msg),
+                                Err(e) => ui.colored_label(egui::Color32::from_rgb(231, 76, 60), format!("❌ {}", e)),
+                            };
+                            ui.end_row();
+                        }
+                    });
+                });
+            });
+        }
+    }
+
+    // Chỉ đọc memory map khi bấm nút vì duyệt smaps/VirtualQueryEx của tiến trình lớn khá chậm
+    fn show_memory_inspector(&mut self, ui: &mut egui::Ui, pid: u32) {
+        let mut clicked_sort = None;
+
+        ui.group(|ui| {
+            ui.colored_label(
+                egui::Color32::from_rgb(155, 89, 182),
+                egui::RichText::new(t!("inspector-title")).strong().size(14.0),
+            );
+            ui.label(t!("inspector-desc"));
+
+            ui.add_space(8.0);
+            let loaded = self.memory_map.as_ref().is_some_and(|m| m.pid == pid);
+            let label = if loaded { t!("inspector-reload") } else { t!("inspector-load") };
+            if ui.button(label).clicked() {
+                self.status_message = t!("status-reading-memory-map", pid = pid);
+                self.in_flight += 1;
+                self.worker.send(WorkerCommand::InspectMemory(pid));
+            }
+
+            let Some(map) = self.memory_map.as_ref().filter(|m| m.pid == pid) else {
+                return;
+            };
+
+            // Tổng theo loại ánh xạ, bấm vào một loại để lọc bảng chi tiết
+            ui.add_space(8.0);
+            let summary = map.summary();
+            egui::Grid::new("region_summary_grid")
+                .striped(true)
+                .spacing([10.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label(egui::RichText::new(t!("col-kind")).strong());
+                    ui.label(egui::RichText::new(t!("col-regions")).strong());
+                    ui.label(egui::RichText::new(t!("col-size")).strong());
+                    ui.label(egui::RichText::new("RSS").strong());
+                    ui.label(egui::RichText::new("PSS").strong());
+                    ui.label(egui::RichText::new("Swap").strong());
+                    ui.end_row();
+
+                    for entry in summary.iter() {
+                        let color = entry.kind.color();
+                        let selected = self.region_filter == Some(entry.kind);
+                        let text = egui::RichText::new(entry.kind.label())
+                            .color(egui::Color32::from_rgb(color[0], color[1], color[2]));
+                        if ui.selectable_label(selected, text).on_hover_text(t!("inspector-filter-hint")).clicked() {
+                            self.region_filter = if selected { None } else { Some(entry.kind) };
+                        }
+                        ui.label(entry.regions.to_string());
+                        ui.label(memory_map::format_kb(entry.size_kb));
+                        ui.label(memory_map::format_kb(entry.rss_kb));
+                        ui.label(kb_cell(entry.pss_kb));
+                        ui.label(kb_cell(entry.swap_kb));
+                        ui.end_row();
+                    }
+
+                    if ui.selectable_label(self.region_filter.is_none(), egui::RichText::new(t!("inspector-all")).strong()).clicked() {
+                        self.region_filter = None;
+                    }
+                    ui.label(map.regions.len().to_string());
+                    ui.label(memory_map::format_kb(summary.iter().map(|s| s.size_kb).sum()));
+                    ui.label(memory_map::format_kb(summary.iter().map(|s| s.rss_kb).sum()));
+                    ui.label(kb_cell(summary.iter().map(|s| s.pss_kb).sum()));
+                    ui.label(kb_cell(summary.iter().map(|s| s.swap_kb).sum()));
+                    ui.end_row();
+                });
+
+            ui.add_space(8.0);
+            let rows: Vec<&MemoryRegion> = map
+                .regions
+                .iter()
+                .filter(|r| self.region_filter.is_none_or(|kind| r.kind == kind))
+                .collect();
+            let columns = [
+                (Some(RegionSort::Address), t!("col-address")),
+                (Some(RegionSort::Kind), t!("col-kind")),
+                (None, t!("col-permissions")),
+                (Some(RegionSort::Size), t!("col-size")),
+                (Some(RegionSort::Rss), "RSS".to_string()),
+                (Some(RegionSort::Pss), "PSS".to_string()),
+                (Some(RegionSort::Swap), "Swap".to_string()),
+                (Some(RegionSort::Path), t!("col-path")),
+            ];
+
+            ui.push_id("region_table", |ui| {
+                TableBuilder::new(ui)
+                    .striped(true)
+                    .resizable(true)
+                    .max_scroll_height(300.0)
+                    .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                    .columns(Column::auto(), columns.len() - 1)
+                    .column(Column::remainder().clip(true))
+                    .header(20.0, |mut header| {
+                        for (sort, label) in columns.iter() {
+                            header.col(|ui| match sort {
+                                Some(sort) => {
+                                    let active = self.region_sort == *sort;
+                                    let arrow = match (active, self.region_sort_descending) {
+                                        (true, true) => " ⏷",
+                                        (true, false) => " ⏶",
+                                        (false, _) => "",
+                                    };
+                                    let text = egui::RichText::new(format!("{}{}", label, arrow)).strong();
+                                    if ui.selectable_label(active, text).clicked() {
+                                        clicked_sort = Some(*sort);
+                                    }
+                                }
+                                None => {
+                                    ui.label(egui::RichText::new(label).strong());
+                                }
+                            });
+                        }
+                    })
+                    .body(|body| {
+                        body.rows(18.0, rows.len(), |mut row| {
+                            let region = rows[row.index()];
+                            let color = region.kind.color();
+                            row.col(|ui| {
+                                ui.monospace(format!("{:012x}", region.start));
+                            });
+                            row.col(|ui| {
+                                ui.colored_label(
+                                    egui::Color32::from_rgb(color[0], color[1], color[2]),
+                                    region.kind.label(),
+                                );
+                            });
+                            row.col(|ui| {
+                                ui.monospace(&region.permissions);
+                            });
+                            row.col(|ui| {
+                                ui.label(memory_map::format_kb(region.size_kb));
+                            });
+                            row.col(|ui| {
+                                ui.label(memory_map::format_kb(region.rss_kb));
+                            });
+                            row.col(|ui| {
+                                ui.label(kb_cell(region.pss_kb));
+                            });
+                            row.col(|ui| {
+                                ui.label(kb_cell(region.swap_kb));
+                            });
+                            row.col(|ui| {
+                                let path = region.path.as_deref().unwrap_or("");
+                                ui.label(path).on_hover_text(path);
+                            });
+                        });
+                    });
+            });
+        });
+
+        // Bấm lại cột đang sắp xếp thì đảo chiều, cột mới dùng chiều mặc định của cột đó
+        if let Some(sort) = clicked_sort {
+            if sort == self.region_sort {
+                self.region_sort_descending = !self.region_sort_descending;
+            } else {
+                self.region_sort = sort;
+                self.region_sort_descending = sort.default_descending();
+            }
+            if let Some(map) = &mut self.memory_map {
+                map.sort(self.region_sort, self.region_sort_descending);
+            }
+        }
+    }
+
+    // Worker chỉ đọc swap/page fault/OOM và dò quyền mỗi lần sample cho các PID đang hiện, đang chọn
+    // hoặc thuộc nhóm đang chọn
+    fn update_watched(&mut self) {
+        let mut watched: BTreeSet<u32> = self.visible_pids.union(&self.selected_pids).copied().collect();
+        if let Some(group) = self.selected_group.as_deref().and_then(|key| process_group::find_group(&self.groups, key)) {
+            watched.extend(group.pids());
+        }
+        if watched != self.watched_pids {
+            self.worker.send(WorkerCommand::SetWatched(watched.iter().copied().collect()));
+            self.watched_pids = watched;
+        }
+    }
+
+    fn run_action(&mut self, pid: u32, action: ProcessAction) {
+        let subtree = if self.apply_to_subtree {
+            process_tree::find_node(&self.tree, pid)
+                .filter(|node| !node.children.is_empty())
+                .map(|node| node.pids())
+        } else {
+            None
+        };
+        let pids = subtree.unwrap_or_else(|| vec![pid]);
+
+        self.status_message = t!("status-running-action", action = action.label(), count = pids.len());
+        self.in_flight += 1;
+        self.worker.send(WorkerCommand::Apply { pids, action });
+    }
+
+    fn show_tree_node(&mut self, ui: &mut egui::Ui, node: &ProcessNode) {
+        if !self.filter.is_empty() && !node.matches(&self.filter) {
+            return;
+        }
+
+        if node.children.is_empty() {
+            ui.horizontal(|ui| {
+                ui.add_space(ui.spacing().indent);
+                self.show_tree_row(ui, node);
+            });
+        } else {
+            let id = ui.make_persistent_id(("process_tree", node.info.pid));
+            egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, false)
+                .show_header(ui, |ui| self.show_tree_row(ui, node))
+                .body(|ui| {
+                    for child in node.children.iter() {
+                        self.show_tree_node(ui, child);
+                    }
+                });
+        }
+    }
+
+    fn show_tree_row(&mut self, ui: &mut egui::Ui, node: &ProcessNode) {
+        let proc = &node.info;
+        let is_selected = self.selected_pids.contains(&proc.pid);
+
+        let response = ui.selectable_label(
+            is_selected,
+            format!("{} {} (PID: {})", proc.status.icon(), proc.name, proc.pid),
+        );
+        if response.clicked() {
+            let modifiers = ui.input(|i| i.modifiers);
+            self.click_process(proc, modifiers, &[]);
+        }
+        if ui.is_rect_visible(response.rect) {
+            self.visible_pids.insert(proc.pid);
+        }
+
+        ui.add_space(10.0);
+        if node.children.is_empty() {
+            ui.label(format!("💾 {:.1} MB  ⚙️ {:.1}%", proc.memory_mb, proc.cpu_usage));
+        } else {
+            ui.label(t!(
+                "tree-subtree-summary",
+                mb = num(node.total_memory_mb),
+                cpu = num(node.total_cpu_usage as f64),
+                count = node.process_count(),
+                own = num(proc.memory_mb)
+            ));
+        }
+    }
+
+    fn show_group(&mut self, ui: &mut egui::Ui, group: &ProcessGroup) {
+        if !self.filter.is_empty() && !group.matches(&self.filter) {
+            return;
+        }
+
+        let id = ui.make_persistent_id(("process_group", &group.key));
+        egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, false)
+            .show_header(ui, |ui| {
+                let is_selected = self.selected_group.as_deref() == Some(group.key.as_str());
+                let response = ui.selectable_label(
+                    is_selected,
+                    format!("📦 {} ×{}", group.name, group.instance_count()),
+                );
+                if response.clicked() {
+                    self.selected_group = Some(group.key.clone());
+                    self.selected_cgroup = None;
+                    self.selected_pids.clear();
+                    self.status_message = t!(
+                        "status-selected-group",
+                        group = group.name.as_str(),
+                        count = group.instance_count()
+                    );
+                }
+
+                ui.add_space(10.0);
+                ui.label(format!(
+                    "💾 {:.1} MB  ⚙️ {:.1}%",
+                    group.total_memory_mb, group.total_cpu_usage
+                ));
+            })
+            .body(|ui| {
+                for proc in group.processes.iter() {
+                    ui.horizontal(|ui| {
+                        let is_selected = self.selected_pids.contains(&proc.pid);
+                        let response =
+                            ui.selectable_label(is_selected, format!("{} PID {}", proc.status.icon(), proc.pid));
+                        if response.clicked() {
+                            let modifiers = ui.input(|i| i.modifiers);
+                            self.click_process(proc, modifiers, &[]);
+                        }
+                        if ui.is_rect_visible(response.rect) {
+                            self.visible_pids.insert(proc.pid);
+                        }
+                        ui.label(format!("💾 {:.1} MB  ⚙️ {:.1}%", proc.memory_mb, proc.cpu_usage));
+                    });
+                }
+            });
+    }
+
+    fn show_group_panel(&mut self, ui: &mut egui::Ui, key: &str) {
+        let group = match process_group::find_group(&self.groups, key) {
+            Some(group) => group.clone(),
+            None => {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(50.0);
+                    ui.label(egui::RichText::new(t!("group-gone")).size(16.0));
+                    if ui.button(t!("refresh-button")).clicked() {
+                        self.selected_group = None;
+                        self.worker.send(WorkerCommand::Refresh);
+                    }
+                });
+                return;
+            }
+        };
+
+        ui.group(|ui| {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(t!("selected-app")).strong().size(14.0));
+                ui.label(egui::RichText::new(format!("{} ({})", group.name, t!("process-count", count = group.instance_count()))).size(14.0));
+            });
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.label(t!("total-ram", mb = num(group.total_memory_mb)));
+                ui.separator();
+                ui.label(t!("total-cpu", cpu = num(group.total_cpu_usage as f64)));
+            });
+            ui.collapsing(t!("group-instances"), |ui| {
+                egui::Grid::new("group_instances").striped(true).show(ui, |ui| {
+                    ui.label(egui::RichText::new("PID").strong());
+                    ui.label(egui::RichText::new("RAM (MB)").strong());
+                    ui.label(egui::RichText::new("CPU %").strong());
+                    ui.end_row();
+                    for proc in group.processes.iter() {
+                        ui.label(proc.pid.to_string());
+                        ui.label(format!("{:.1}", proc.memory_mb));
+                        ui.label(format!("{:.1}", proc.cpu_usage));
+                        ui.end_row();
+                    }
+                });
+            });
+        });
+
+        ui.add_space(15.0);
+
+        let pids = group.pids();
+        let mut action = None;
+        ui.group(|ui| {
+            ui.label(egui::RichText::new(t!("group-actions")).strong().size(14.0));
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                ui.label("Working Set:");
+                ui.add(egui::Slider::new(&mut self.pin_working_set_mb, self.config.sliders.pin_min_mb..=self.config.sliders.pin_max_mb).suffix(" MB"));
+                let pin = ProcessAction::Pin { working_set_mb: self.pin_working_set_mb };
+                if action_button(ui, t!("group-pin"), &self.permissions.check_all(&pin, &pids)) {
+                    action = Some(pin);
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label(t!("limit-label"));
+                ui.add(egui::Slider::new(&mut self.limit_max_ws_mb, self.config.sliders.limit_min_mb..=self.config.sliders.limit_max_mb).suffix(" MB"));
+                let limit = self.limit_action();
+                if action_button(ui, t!("group-limit"), &self.permissions.check_all(&limit, &pids)) {
+                    action = Some(limit);
+                }
+            });
+            if cfg!(target_os = "linux") {
+                self.show_swap_fields(ui);
+                let swap = self.swap_action();
+                if action_button(ui, t!("group-swap-limit"), &self.permissions.check_all(&swap, &pids)) {
+                    action = Some(swap);
+                }
+            }
+            ui.horizontal(|ui| {
+                if action_button(ui, t!("group-trim"), &self.permissions.check_all(&ProcessAction::Trim, &pids)) {
+                    action = Some(ProcessAction::Trim);
+                }
+                if action_button(ui, t!("group-freeze"), &self.permissions.check_all(&ProcessAction::Freeze, &pids)) {
+                    action = Some(ProcessAction::Freeze);
+                }
+                if action_button(ui, t!("group-hibernate"), &self.permissions.check_all(&ProcessAction::Hibernate, &pids)) {
+                    action = Some(ProcessAction::Hibernate);
+                }
+                if action_button(ui, t!("group-restore"), &self.permissions.check_all(&ProcessAction::Restore, &pids)) {
+                    action = Some(ProcessAction::Restore);
+                }
+            });
+        });
+
+        if let Some(action) = action {
+            self.status_message = t!("status-running-group", action = action.label(), group = group.name.as_str());
+            self.in_flight += 1;
+            self.worker.send(WorkerCommand::ApplyGroup { group, action });
+        }
+
+        if let Some(report) = &self.last_group_report {
+            ui.add_space(15.0);
+            ui.group(|ui| {
+                ui.label(egui::RichText::new(t!(
+                    "group-result-heading",
+                    action = report.action.label(),
+                    group = report.group_name.as_str()
+                )).strong());
+                ui.label(t!(
+                    "group-before-after",
+                    before = num(report.total_before_mb()),
+                    after = num(report.total_after_mb()),
+                    freed = num(report.freed_mb())
+                ));
+                egui::Grid::new("group_report").striped(true).show(ui, |ui| {
+                    ui.label(egui::RichText::new("PID").strong());
+                    ui.label(egui::RichText::new(t!("col-before")).strong());
+                    ui.label(egui::RichText::new(t!("col-after")).strong());
+                    ui.label(egui::RichText::new(t!("col-result")).strong());
+                    ui.end_row();
+                    for entry in report.entries.iter() {
+                        ui.label(entry.pid.to_string());
+                        ui.label(entry.before_mb.map_or("-".to_string(), |v| format!("{:.1}", v)));
+                        ui.label(entry.after_mb.map_or("-".to_string(), |v| format!("{:.1}", v)));
+                        match &entry.error {
+                            Some(e) => ui.colored_label(egui::Color32::from_rgb(231, 76, 60), format!("❌ {}", e)),
+                            None => ui.colored_label(egui::Color32::from_rgb(46, 204, 113), "✅"),
+                        };
+                        ui.end_row();
+                    }
+                });
+            });
+        }
+    }
+
+    fn scan_cgroups(&mut self) {
+        self.in_flight += 1;
+        self.worker.send(WorkerCommand::ScanCgroups);
+    }
+
+    fn show_cgroup_node(&mut self, ui: &mut egui::Ui, node: &CgroupNode) {
+        if !self.filter.is_empty() && !node.matches(&self.filter) {
+            return;
+        }
+
+        let header = |app: &mut Self, ui: &mut egui::Ui| {
+            let is_selected = app.selected_cgroup.as_deref() == Some(node.path.as_path());
+            if ui.selectable_label(is_selected, format!("🗂️ {}", node.name)).clicked() {
+                app.selected_cgroup = Some(node.path.clone());
+                app.selected_group = None;
+                app.selected_pids.clear();
+                app.status_message = t!("status-selected-cgroup", cgroup = node.path.display().to_string());
+            }
+            ui.add_space(10.0);
+            ui.label(t!("cgroup-summary", memory = kb_cell(node.current_kb), count = node.process_count()));
+        };
+
+        if node.children.is_empty() {
+            ui.horizontal(|ui| {
+                ui.add_space(ui.spacing().indent);
+                header(self, ui);
+            });
+        } else {
+            let id = ui.make_persistent_id(("cgroup_tree", &node.path));
+            // Mở sẵn cgroup gốc (tên "/") để thấy ngay các slice
+            egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, node.name == "/")
+                .show_header(ui, |ui| header(self, ui))
+                .body(|ui| {
+                    for child in node.children.iter() {
+                        self.show_cgroup_node(ui, child);
+                    }
+                });
+        }
+    }
+
+    fn show_cgroup_panel(&mut self, ui: &mut egui::Ui, path: &Path) {
+        let Some(node) = self.cgroup_tree.as_ref().and_then(|root| cgroup_tree::find_node(root, path)).cloned() else {
+            ui.vertical_centered(|ui| {
+                ui.add_space(50.0);
+                ui.label(egui::RichText::new(t!("cgroup-gone")).size(16.0));
+                if ui.button(t!("cgroup-rescan")).clicked() {
+                    self.selected_cgroup = None;
+                    self.scan_cgroups();
+                }
+            });
+            return;
+        };
+
+        ui.group(|ui| {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(t!("selected-cgroup")).strong().size(14.0));
+                ui.label(egui::RichText::new(node.path.display().to_string()).size(14.0));
+            });
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.label(t!("cgroup-current", memory = kb_cell(node.current_kb)));
+                ui.separator();
+                ui.label(t!("cgroup-swap", memory = kb_cell(node.swap_kb)));
+                ui.separator();
+                ui.label(t!("process-count", count = node.process_count()));
+            });
+
+            ui.collapsing(t!("cgroup-stat"), |ui| {
+                egui::Grid::new("cgroup_stat").striped(true).show(ui, |ui| {
+                    for (key, kb) in node.stat.iter() {
+                        ui.label(*key);
+                        ui.label(memory_map::format_kb(*kb));
+                        ui.end_row();
+                    }
+                });
+            });
+            ui.collapsing(t!("cgroup-limits"), |ui| {
+                egui::Grid::new("cgroup_limits").striped(true).show(ui, |ui| {
+                    for limit in node.limits.iter() {
+                        ui.label(limit.file);
+                        ui.label(limit.kb.map_or_else(|| "max".to_string(), memory_map::format_kb));
+                        ui.end_row();
+                    }
+                });
+            });
+            ui.collapsing(t!("cgroup-members", count = node.pids.len()), |ui| {
+                egui::Grid::new("cgroup_members").striped(true).show(ui, |ui| {
+                    ui.label(egui::RichText::new("PID").strong());
+                    ui.label(egui::RichText::new(t!("col-name")).strong());
+                    ui.label(egui::RichText::new("RAM (MB)").strong());
+                    ui.end_row();
+                    for pid in node.pids.iter() {
+                        let proc = self.processes.iter().find(|p| p.pid == *pid);
+                        ui.label(pid.to_string());
+                        ui.label(proc.map_or("-", |p| p.name.as_str()));
+                        ui.label(proc.map_or("-".to_string(), |p| format!("{:.1}", p.memory_mb)));
+                        ui.end_row();
+                    }
+                });
+            });
+        });
+
+        ui.add_space(10.0);
+
+        // Quyền được kiểm tra khi ghi file của chính cgroup đó, không dò trước như với từng PID
+        let mut action = None;
+        ui.group(|ui| {
+            ui.label(egui::RichText::new(t!("cgroup-actions")).strong().size(14.0));
+            ui.label(t!("cgroup-actions-desc"));
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                ui.label("memory.min:");
+                ui.add(egui::Slider::new(&mut self.pin_working_set_mb, self.config.sliders.pin_min_mb..=self.config.sliders.pin_max_mb).suffix(" MB"));
+                if ui.button(t!("cgroup-pin")).clicked() {
+                    action = Some(ProcessAction::Pin { working_set_mb: self.pin_working_set_mb });
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label(t!("limit-label"));
+                ui.add(egui::Slider::new(&mut self.limit_max_ws_mb, self.config.sliders.limit_min_mb..=self.config.sliders.limit_max_mb).suffix(" MB"));
+                if ui.button(t!("cgroup-limit")).clicked() {
+                    action = Some(self.limit_action());
+                }
+            });
+            self.show_limit_mode(ui);
+            self.show_swap_fields(ui);
+            if ui.button(t!("cgroup-swap-limit")).clicked() {
+                action = Some(self.swap_action());
+            }
+            ui.horizontal(|ui| {
+                if ui.button(t!("cgroup-trim")).clicked() {
+                    action = Some(ProcessAction::Trim);
+                }
+                if ui.button(t!("cgroup-restore")).on_hover_text(t!("cgroup-restore-hint")).clicked() {
+                    action = Some(ProcessAction::Restore);
+                }
+            });
+        });
+
+        if let Some(action) = action {
+            self.status_message = t!("status-running-cgroup", action = action.label(), cgroup = node.path.display().to_string());
+            self.in_flight += 1;
+            self.worker.send(WorkerCommand::ApplyCgroup { path: node.path, action });
+            // Worker xử lý lệnh theo thứ tự nên lần đọc lại này thấy giá trị mới
+            self.scan_cgroups();
+        }
+    }
+}
+
+// PSS/Swap không có trên Windows thì hiện "-"
+fn kb_cell(kb: Option<u64>) -> String {
+    kb.map(memory_map::format_kb).unwrap_or_else(|| "-".to_string())
+}
+
+// Đường lịch sử page fault/giây, chuẩn hoá theo giá trị lớn nhất trong cửa sổ
+fn fault_sparkline(ui: &mut egui::Ui, history: &VecDeque<f64>) {
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(160.0, 24.0), egui::Sense::hover());
+    let max = history.iter().copied().fold(1.0, f64::max);
+    let step = rect.width() / (FAULT_HISTORY_LEN - 1) as f32;
+    let points: Vec<egui::Pos2> = history
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let x = rect.left() + i as f32 * step;
+            let y = rect.bottom() - (*value / max) as f32 * rect.height();
+            egui::pos2(x, y)
+        })
+        .collect();
+    ui.painter().rect_stroke(rect, 2.0, ui.visuals().widgets.noninteractive.bg_stroke);
+    ui.painter().add(egui::Shape::line(
+        points,
+        egui::Stroke::new(1.5, egui::Color32::from_rgb(230, 126, 34)),
+    ));
+    response.on_hover_text(t!("fault-history-hint", max = format!("{:.0}", max), samples = history.len()));
+}
+
+// Nút hành động bị khoá khi thiếu quyền, rê chuột lên để xem thiếu gì
+fn action_button(ui: &mut egui::Ui, label: impl Into<egui::WidgetText>, allowed: &Result<(), String>) -> bool {
+    let response = ui.add_enabled(allowed.is_ok(), egui::Button::new(label));
+    match allowed {
+        Ok(()) => response.clicked(),
+        Err(reason) => {
+            response.on_disabled_hover_text(reason);
+            false
+        }
+    }
+}
+
+// Giới hạn tuỳ chọn (MB): bỏ chọn = không giới hạn
+fn limit_field(ui: &mut egui::Ui, label: String, value: &mut Option<u64>, default_mb: u64) {
+    ui.horizontal(|ui| {
+        let mut enabled = value.is_some();
+        if ui.checkbox(&mut enabled, label).changed() {
+            *value = enabled.then_some(default_mb);
+        }
+        if let Some(mb) = value {
+            ui.add(egui::DragValue::new(mb).range(0..=1024 * 1024).suffix(" MB"));
+        }
+    });
+}
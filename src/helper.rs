@@ -0,0 +1,771 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use crate::config::Config;
+use crate::i18n::t;
+use crate::oom;
+use crate::privileged::{self, PrivilegedOp};
+use crate::ram_manager::{Priority, ProcessAction};
+
+pub const PROTOCOL_VERSION: u32 = 1;
+const DEFAULT_ADDRESS: &str = "127.0.0.1:47215";
+// Một dòng lệnh hợp lệ chỉ vài chục byte, dòng dài hơn coi như vi phạm giao thức
+const MAX_LINE_BYTES: u64 = 4096;
+const SERVER_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+// GUI chỉ giữ một kết nối; vài kết nối dư cho lúc kết nối lại khi kết nối cũ chưa hết hạn
+const MAX_CLIENTS: usize = 4;
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(10);
+// Chặn giá trị vô lý (và tràn số khi đổi ra byte) trước khi tới Windows API
+const MAX_WORKING_SET_MB: usize = 1024 * 1024;
+// memory.min/working set tối thiểu giữ RAM khỏi mọi tiến trình khác nên Pin qua helper bị giới hạn chặt hơn
+const DEFAULT_MAX_PIN_MB: usize = 8192;
+
+// oom_score_adj gốc của các tiến trình helper đã đổi, theo PID và thời điểm khởi động
+// để tiến trình mới dùng lại PID không kế thừa giá trị của tiến trình cũ
+type OomOriginals = Arc<Mutex<HashMap<(u32, Option<u64>), i32>>>;
+
+// Khi bật, GUI chạy không cần quyền admin và gửi các thao tác cần quyền tới helper qua socket cục bộ
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HelperConfig {
+    pub enabled: bool,
+    pub address: String,
+    // Truyền cho helper qua --max-pin-mb khi khởi động bằng nút 🚀
+    pub max_pin_mb: usize,
+}
+
+impl Default for HelperConfig {
+    fn default() -> Self {
+        HelperConfig {
+            enabled: false,
+            address: DEFAULT_ADDRESS.to_string(),
+            max_pin_mb: DEFAULT_MAX_PIN_MB,
+        }
+    }
+}
+
+impl HelperConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        self.socket_addr()?;
+        if !(1..=MAX_WORKING_SET_MB).contains(&self.max_pin_mb) {
+            return Err(t!("cfg-helper-max-pin", value = self.max_pin_mb, max = MAX_WORKING_SET_MB));
+        }
+        Ok(())
+    }
+
+    // Chỉ cho phép địa chỉ loopback để helper không nhận lệnh từ máy khác
+    pub fn socket_addr(&self) -> Result<SocketAddr, String> {
+        let addr: SocketAddr = self
+            .address
+            .parse()
+            .map_err(|e: std::net::AddrParseError| t!("cfg-helper-address", address = self.address.as_str(), error = e.to_string()))?;
+        if !addr.ip().is_loopback() {
+            return Err(t!("cfg-helper-address", address = self.address.as_str(), error = t!("err-helper-not-loopback")));
+        }
+        Ok(addr)
+    }
+}
+
+// File token nằm trong thư mục config của người dùng: người dùng tạo ra, helper (admin/root) đọc được
+pub fn token_path() -> PathBuf {
+    Config::path().with_file_name("helper.token")
+}
+
+// Đọc token hiện có hoặc tạo mới (chỉ chủ sở hữu đọc được trên Unix)
+pub fn ensure_token(path: &Path) -> Result<String, String> {
+    if let Ok(token) = fs::read_to_string(path) {
+        if !token.trim().is_empty() {
+            return Ok(token.trim().to_string());
+        }
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| t!("err-create-dir", path = dir.display().to_string(), error = e.to_string()))?;
+    }
+    let token = generate_token()?;
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options
+        .open(path)
+        .and_then(|mut file| file.write_all(token.as_bytes()))
+        .map_err(|e| t!("err-write-file", path = path.display().to_string(), error = e.to_string()))?;
+    Ok(token)
+}
+
+// 256 bit lấy trực tiếp từ bộ sinh số ngẫu nhiên của hệ điều hành (getrandom / BCryptGenRandom)
+fn generate_token() -> Result<String, String> {
+    let mut bytes = [0u8; 32];
+    getrandom::getrandom(&mut bytes).map_err(|e| t!("err-helper-token", error = e.to_string()))?;
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+// So sánh hết mọi byte kể cả khi độ dài khác nhau, thời gian phản hồi chỉ phụ thuộc token mong đợi
+pub fn tokens_match(given: &str, expected: &str) -> bool {
+    let given = given.as_bytes();
+    let diff = expected
+        .bytes()
+        .enumerate()
+        .fold(given.len() ^ expected.len(), |acc, (i, byte)| acc | usize::from(given.get(i).copied().unwrap_or(0) ^ byte));
+    diff == 0
+}
+
+// Dòng đầu tiên của mỗi kết nối
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Hello {
+    version: u32,
+    token: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum Response {
+    Ok,
+    Error { message: String },
+}
+
+// None khi bên kia đã đóng kết nối
+pub fn read_line(reader: &mut BufReader<TcpStream>) -> Result<Option<String>, String> {
+    read_line_limited(reader, MAX_LINE_BYTES)
+}
+
+pub fn read_line_limited(reader: &mut BufReader<TcpStream>, max_bytes: u64) -> Result<Option<String>, String> {
+    let mut line = String::new();
+    let read = reader
+        .by_ref()
+        .take(max_bytes)
+        .read_line(&mut line)
+        .map_err(|e| e.to_string())?;
+    if read == 0 {
+        return Ok(None);
+    }
+    if !line.ends_with('\n') {
+        return Err(t!("err-helper-protocol"));
+    }
+    Ok(Some(line))
+}
+
+pub fn write_line<T: Serialize>(stream: &mut TcpStream, value: &T) -> Result<(), String> {
+    let mut line = serde_json::to_string(value).map_err(|e| e.to_string())?;
+    line.push('\n');
+    stream.write_all(line.as_bytes()).map_err(|e| e.to_string())
+}
+
+// `ram_manager --helper [--token-file <path>] [--listen <addr>] [--max-pin-mb <mb>]`, chạy với quyền admin/root
+pub fn serve(args: &[String]) -> Result<(), String> {
+    let mut token_file = token_path();
+    let mut config = HelperConfig::default();
+    let usage_error = || t!("err-helper-args", args = args.join(" "));
+    let mut rest = args.iter();
+    while let Some(arg) = rest.next() {
+        let value = rest.next().ok_or_else(usage_error)?;
+        match arg.as_str() {
+            "--token-file" => token_file = PathBuf::from(value),
+            "--listen" => config.address = value.clone(),
+            "--max-pin-mb" => config.max_pin_mb = value.parse().map_err(|_| usage_error())?,
+            _ => return Err(usage_error()),
+        }
+    }
+    config.validate()?;
+
+    let listener = TcpListener::bind(config.socket_addr()?)
+        .map_err(|e| t!("err-helper-bind", address = config.address.as_str(), error = e.to_string()))?;
+    let active = Arc::new(AtomicUsize::new(0));
+    let oom_originals = OomOriginals::default();
+    for stream in listener.incoming().flatten() {
+        // Quá số kết nối đồng thời thì đóng ngay, không sinh thêm luồng
+        if active.fetch_add(1, Ordering::SeqCst) >= MAX_CLIENTS {
+            active.fetch_sub(1, Ordering::SeqCst);
+            continue;
+        }
+        let token_file = token_file.clone();
+        let active = Arc::clone(&active);
+        let oom_originals = Arc::clone(&oom_originals);
+        let max_pin_mb = config.max_pin_mb;
+        thread::spawn(move || {
+            let _ = handle_client(stream, &token_file, max_pin_mb, &oom_originals);
+            active.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+    Ok(())
+}
+
+fn handle_client(stream: TcpStream, token_file: &Path, max_pin_mb: usize, oom_originals: &OomOriginals) -> Result<(), String> {
+    let peer = stream.peer_addr().map_err(|e| e.to_string())?;
+    if !peer.ip().is_loopback() {
+        return Err(t!("err-helper-not-loopback"));
+    }
+    let local = stream.local_addr().map_err(|e| e.to_string())?;
+    // Chưa xác thực thì chỉ chờ ngắn; client không đọc phản hồi cũng không giữ được luồng mãi
+    stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT)).map_err(|e| e.to_string())?;
+    stream.set_write_timeout(Some(HANDSHAKE_TIMEOUT)).map_err(|e| e.to_string())?;
+    let mut writer = stream.try_clone().map_err(|e| e.to_string())?;
+    let mut reader = BufReader::new(stream);
+
+    let Some(line) = read_line(&mut reader)? else {
+        return Ok(());
+    };
+    // Token đọc lại ở mỗi kết nối để người dùng đổi token mà không phải khởi động lại helper
+    let expected = fs::read_to_string(token_file).unwrap_or_default();
+    let expected = expected.trim();
+    let authenticated = match serde_json::from_str::<Hello>(&line) {
+        Ok(hello) if hello.version != PROTOCOL_VERSION => {
+            Err(t!("err-helper-version", expected = PROTOCOL_VERSION, actual = hello.version))
+        }
+        Ok(hello) if !expected.is_empty() && tokens_match(&hello.token, expected) => Ok(()),
+        _ => Err(t!("err-helper-auth")),
+    };
+    // Người dùng sở hữu socket phía client; mọi lệnh sau đó chỉ được đụng tới tiến trình của người này
+    let owner = match authenticated.and_then(|_| platform::peer_owner(peer, local)) {
+        Ok(owner) => owner,
+        Err(message) => return write_line(&mut writer, &Response::Error { message }),
+    };
+    write_line(&mut writer, &Response::Ok)?;
+    reader.get_ref().set_read_timeout(Some(SERVER_IDLE_TIMEOUT)).map_err(|e| e.to_string())?;
+
+    while let Some(line) = read_line(&mut reader)? {
+        // Lệnh không đúng kiểu thì đóng kết nối, không cố đoán ý client
+        let op: PrivilegedOp = match serde_json::from_str(&line) {
+            Ok(op) => op,
+            Err(e) => {
+                let message = t!("err-helper-bad-request", error = e.to_string());
+                return write_line(&mut writer, &Response::Error { message });
+            }
+        };
+        let response = match check_op(&op, &owner, max_pin_mb).and_then(|_| execute(&op, oom_originals)) {
+            Ok(()) => Response::Ok,
+            Err(message) => Response::Error { message },
+        };
+        write_line(&mut writer, &response)?;
+    }
+    Ok(())
+}
+
+// PID 0/1 (init, System Idle) và chính helper không bao giờ là đích hợp lệ.
+// Kích thước được kiểm tra trước, không cần đọc /proc với lệnh sai dạng
+fn check_op(op: &PrivilegedOp, owner: &platform::Owner, max_pin_mb: usize) -> Result<(), String> {
+    let size_mb = match *op {
+        PrivilegedOp::Pin { working_set_mb, .. }
+        | PrivilegedOp::Cgroup { action: ProcessAction::Pin { working_set_mb }, .. } => Some(working_set_mb),
+        PrivilegedOp::Limit { max_ws_mb, .. }
+        | PrivilegedOp::Cgroup { action: ProcessAction::Limit { max_ws_mb, .. }, .. } => Some(max_ws_mb),
+        _ => None,
+    };
+    if let Some(mb) = size_mb.filter(|mb| *mb == 0 || *mb > MAX_WORKING_SET_MB) {
+        return Err(t!("err-helper-size", mb = mb, max = MAX_WORKING_SET_MB));
+    }
+    if let PrivilegedOp::Pin { working_set_mb, .. }
+    | PrivilegedOp::Cgroup { action: ProcessAction::Pin { working_set_mb }, .. } = *op
+    {
+        if working_set_mb > max_pin_mb {
+            return Err(t!("err-helper-pin-size", mb = working_set_mb, max = max_pin_mb));
+        }
+    }
+    if let PrivilegedOp::SetOsPriority { value, .. } = *op {
+        if !Priority::covers_os_value(value) {
+            return Err(t!("err-helper-priority", value = value));
+        }
+    }
+    // Trim trên Linux là Pageout; EmptyWorkingSet chỉ có trên Windows
+    if cfg!(target_os = "linux") && matches!(op, PrivilegedOp::Trim { .. }) {
+        return Err(t!("err-trim-unsupported"));
+    }
+    if let Some(pid) = op.pid() {
+        if pid <= 1 || pid == std::process::id() {
+            return Err(t!("err-helper-pid", pid = pid));
+        }
+        platform::check_target(pid, owner)?;
+    }
+    if let PrivilegedOp::Cgroup { path, .. } | PrivilegedOp::CgroupRestore { path, .. } = op {
+        platform::check_cgroup(path, owner)?;
+    }
+    Ok(())
+}
+
+// oom_score_adj âm (tránh OOM killer) cần CAP_SYS_RESOURCE: client chỉ được đưa về đúng giá trị gốc
+// trước lần đổi đầu tiên qua helper, không được tự hạ xuống
+fn execute(op: &PrivilegedOp, oom_originals: &OomOriginals) -> Result<(), String> {
+    let PrivilegedOp::SetOomScoreAdj { pid, value } = *op else {
+        return privileged::execute(op);
+    };
+    let key = (pid, platform::start_time(pid));
+    let mut originals = oom_originals.lock().unwrap();
+    let original = match originals.get(&key) {
+        Some(&original) => original,
+        None => oom::score_adj(pid)?,
+    };
+    if value < 0 && value != original {
+        return Err(t!("err-helper-oom-negative", pid = pid, value = value));
+    }
+    privileged::execute(op)?;
+    if value == original {
+        originals.remove(&key);
+    } else {
+        originals.entry(key).or_insert(original);
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum HelperStatus {
+    // GUI tự thực hiện các thao tác (helper không được bật)
+    InProcess,
+    Connected,
+    Unreachable(String),
+}
+
+// Kết nối tới helper, giữ lại giữa các lệnh và tự kết nối lại khi helper khởi động lại
+pub struct HelperClient {
+    address: SocketAddr,
+    token_file: PathBuf,
+    connection: Option<(TcpStream, BufReader<TcpStream>)>,
+    last_error: Option<String>,
+}
+
+impl HelperClient {
+    pub fn new(config: &HelperConfig) -> Result<HelperClient, String> {
+        Ok(HelperClient {
+            address: config.socket_addr()?,
+            token_file: token_path(),
+            connection: None,
+            last_error: None,
+        })
+    }
+
+    fn connect(&mut self) -> Result<(TcpStream, BufReader<TcpStream>), String> {
+        let token = ensure_token(&self.token_file)?;
+        let stream = TcpStream::connect_timeout(&self.address, CLIENT_TIMEOUT)
+            .map_err(|e| t!("err-helper-connect", address = self.address.to_string(), error = e.to_string()))?;
+        stream.set_read_timeout(Some(CLIENT_TIMEOUT)).map_err(|e| e.to_string())?;
+        stream.set_write_timeout(Some(CLIENT_TIMEOUT)).map_err(|e| e.to_string())?;
+        let mut writer = stream.try_clone().map_err(|e| e.to_string())?;
+        let mut reader = BufReader::new(stream);
+
+        write_line(&mut writer, &Hello { version: PROTOCOL_VERSION, token })?;
+        read_response(&mut reader)??;
+        Ok((writer, reader))
+    }
+
+    // Kết nối cũ có thể đã bị đóng (helper khởi động lại, hết thời gian chờ) nên thử lại một lần với kết nối mới
+    pub fn execute(&mut self, op: &PrivilegedOp) -> Result<(), String> {
+        for attempt in 0..2 {
+            let (mut writer, mut reader) = match self.connection.take() {
+                Some(connection) => connection,
+                None => self.connect().inspect_err(|e| self.last_error = Some(e.clone()))?,
+            };
+            let response = write_line(&mut writer, op).and_then(|_| read_response(&mut reader));
+            match response {
+                Ok(result) => {
+                    self.connection = Some((writer, reader));
+                    self.last_error = None;
+                    return result;
+                }
+                Err(e) if attempt > 0 => {
+                    self.last_error = Some(e.clone());
+                    return Err(t!("err-helper-connect", address = self.address.to_string(), error = e));
+                }
+                Err(_) => continue,
+            }
+        }
+        unreachable!()
+    }
+
+    // Chưa có kết nối thì thử kết nối ngay, để GUI biết helper đã sẵn sàng trước khi người dùng bấm nút
+    pub fn status(&mut self) -> HelperStatus {
+        if self.connection.is_none() {
+            match self.connect() {
+                Ok(connection) => {
+                    self.connection = Some(connection);
+                    self.last_error = None;
+                }
+                Err(e) => self.last_error = Some(e),
+            }
+        }
+        match &self.last_error {
+            Some(e) => HelperStatus::Unreachable(e.clone()),
+            None => HelperStatus::Connected,
+        }
+    }
+}
+
+// Lỗi ngoài là lỗi kết nối (nên thử lại), lỗi trong là helper từ chối/thao tác thất bại
+fn read_response(reader: &mut BufReader<TcpStream>) -> Result<Result<(), String>, String> {
+    let line = read_line(reader)?.ok_or_else(|| t!("err-helper-closed"))?;
+    match serde_json::from_str::<Response>(&line).map_err(|_| t!("err-helper-protocol"))? {
+        Response::Ok => Ok(Ok(())),
+        Response::Error { message } => Ok(Err(message)),
+    }
+}
+
+// Chạy chính file thực thi này ở chế độ helper với quyền admin (UAC) / root (pkexec)
+pub fn launch_elevated(config: &HelperConfig) -> Result<(), String> {
+    config.socket_addr()?;
+    let token_file = token_path();
+    ensure_token(&token_file)?;
+    let exe = std::env::current_exe().map_err(|e| t!("err-helper-launch", error = e.to_string()))?;
+    let args = [
+        "--helper".to_string(),
+        "--token-file".to_string(),
+        token_file.display().to_string(),
+        "--listen".to_string(),
+        config.address.clone(),
+        "--max-pin-mb".to_string(),
+        config.max_pin_mb.to_string(),
+    ];
+    platform::launch(&exe, &args)
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::fs;
+    use std::net::SocketAddr;
+    use std::os::unix::fs::MetadataExt;
+    use std::path::Path;
+    use std::process::Command;
+    use crate::i18n::t;
+
+    // Cờ PF_KTHREAD trong trường flags của /proc/<pid>/stat
+    const PF_KTHREAD: u64 = 0x0020_0000;
+
+    // uid của người dùng phía client; root (0) được thao tác mọi tiến trình không phải của kernel
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Owner(pub u32);
+
+    pub fn peer_owner(peer: SocketAddr, local: SocketAddr) -> Result<Owner, String> {
+        ["/proc/net/tcp", "/proc/net/tcp6"]
+            .iter()
+            .filter_map(|path| fs::read_to_string(path).ok())
+            .find_map(|table| socket_uid(&table, peer.port(), local.port()))
+            .map(Owner)
+            .ok_or_else(|| t!("err-helper-peer"))
+    }
+
+    // Socket của client là dòng ESTABLISHED (01) có cổng local là cổng client và cổng remote là cổng helper.
+    // Cột: sl local_address rem_address st tx_queue:rx_queue tr:tm->when retrnsmt uid ...
+    pub fn socket_uid(table: &str, local_port: u16, remote_port: u16) -> Option<u32> {
+        let port = |field: &str| field.rsplit_once(':').and_then(|(_, port)| u16::from_str_radix(port, 16).ok());
+        table.lines().skip(1).find_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if port(fields.get(1)?)? != local_port || port(fields.get(2)?)? != remote_port || *fields.get(3)? != "01" {
+                return None;
+            }
+            fields.get(7)?.parse().ok()
+        })
+    }
+
+    pub fn check_target(pid: u32, owner: &Owner) -> Result<(), String> {
+        let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).map_err(|_| t!("err-helper-not-owner", pid = pid))?;
+        if is_kernel_thread(&stat) {
+            return Err(t!("err-helper-kernel-thread", pid = pid));
+        }
+        if owner.0 == 0 {
+            return Ok(());
+        }
+        let status = fs::read_to_string(format!("/proc/{}/status", pid)).unwrap_or_default();
+        if !owned_by(&status, owner.0) {
+            return Err(t!("err-helper-not-owner", pid = pid));
+        }
+        Ok(())
+    }
+
+    // Thời điểm khởi động (trường starttime, tính bằng tick kể từ lúc boot), None khi tiến trình đã thoát
+    pub fn start_time(pid: u32) -> Option<u64> {
+        stat_start_time(&fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?)
+    }
+
+    pub fn stat_start_time(stat: &str) -> Option<u64> {
+        stat.rsplit_once(')')?.1.split_whitespace().nth(19)?.parse().ok()
+    }
+
+    // Sau tên tiến trình trong ngoặc: state ppid pgrp session tty_nr tpgid flags ...
+    pub fn is_kernel_thread(stat: &str) -> bool {
+        stat.rsplit_once(')')
+            .and_then(|(_, rest)| rest.split_whitespace().nth(6)?.parse::<u64>().ok())
+            .is_none_or(|flags| flags & PF_KTHREAD != 0)
+    }
+
+    // Uid thực, hiệu dụng và saved đều phải là của client, để không nhận chương trình setuid đang chạy
+    pub fn owned_by(status: &str, uid: u32) -> bool {
+        let uids: Vec<u32> = status
+            .lines()
+            .find_map(|line| line.strip_prefix("Uid:"))
+            .map(|value| value.split_whitespace().take(3).filter_map(|v| v.parse().ok()).collect())
+            .unwrap_or_default();
+        uids.len() == 3 && uids.iter().all(|id| *id == uid)
+    }
+
+    // Cgroup phải được uỷ quyền cho client (thư mục thuộc uid đó) và mọi tiến trình trong nhánh đều của client
+    pub fn check_cgroup(path: &Path, owner: &Owner) -> Result<(), String> {
+        let denied = || t!("err-helper-cgroup-owner", path = path.display().to_string());
+        let dir = fs::canonicalize(path).map_err(|_| denied())?;
+        if owner.0 != 0 && fs::metadata(&dir).map_err(|_| denied())?.uid() != owner.0 {
+            return Err(denied());
+        }
+        subtree_pids(&dir).into_iter().try_for_each(|pid| check_target(pid, owner))
+    }
+
+    fn subtree_pids(dir: &Path) -> Vec<u32> {
+        let mut pids: Vec<u32> = fs::read_to_string(dir.join("cgroup.procs"))
+            .map(|procs| procs.lines().filter_map(|pid| pid.parse().ok()).collect())
+            .unwrap_or_default();
+        if let Ok(entries) = fs::read_dir(dir) {
+            for entry in entries.filter_map(Result::ok).filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir())) {
+                pids.extend(subtree_pids(&entry.path()));
+            }
+        }
+        pids
+    }
+
+    pub fn launch(exe: &Path, args: &[String]) -> Result<(), String> {
+        Command::new("pkexec")
+            .arg(exe)
+            .args(args)
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| t!("err-helper-launch", error = e.to_string()))
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::net::SocketAddr;
+    use std::path::Path;
+    use std::slice;
+    use windows::core::{w, HSTRING, PCWSTR, PWSTR};
+    use windows::Win32::Foundation::{CloseHandle, LocalFree, HANDLE, HLOCAL, HWND};
+    use windows::Win32::NetworkManagement::IpHelper::{
+        GetExtendedTcpTable, MIB_TCP6TABLE_OWNER_PID, MIB_TCPTABLE_OWNER_PID, TCP_TABLE_OWNER_PID_CONNECTIONS,
+    };
+    use windows::Win32::Networking::WinSock::{ADDRESS_FAMILY, AF_INET, AF_INET6};
+    use windows::Win32::Security::Authorization::ConvertSidToStringSidW;
+    use windows::Win32::Security::{GetTokenInformation, TokenUser, TOKEN_QUERY, TOKEN_USER};
+    use windows::Win32::System::Threading::{OpenProcess, OpenProcessToken, PROCESS_QUERY_LIMITED_INFORMATION};
+    use windows::Win32::UI::Shell::ShellExecuteW;
+    use windows::Win32::UI::WindowsAndMessaging::SW_HIDE;
+    use crate::i18n::t;
+
+    // SID (dạng chuỗi) của tài khoản chạy tiến trình phía client
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Owner(String);
+
+    pub fn peer_owner(peer: SocketAddr, local: SocketAddr) -> Result<Owner, String> {
+        tcp_owner_pid(peer, local)
+            .and_then(process_sid)
+            .map(Owner)
+            .ok_or_else(|| t!("err-helper-peer"))
+    }
+
+    // System (PID 4) và các tiến trình của SYSTEM/dịch vụ có SID khác nên bị loại ở đây
+    pub fn check_target(pid: u32, owner: &Owner) -> Result<(), String> {
+        if pid <= 4 {
+            return Err(t!("err-helper-kernel-thread", pid = pid));
+        }
+        match process_sid(pid) {
+            Some(sid) if sid == owner.0 => Ok(()),
+            _ => Err(t!("err-helper-not-owner", pid = pid)),
+        }
+    }
+
+    pub fn check_cgroup(_path: &Path, _owner: &Owner) -> Result<(), String> {
+        Err(t!("err-cgroup-tree-unsupported"))
+    }
+
+    // oom_score_adj không có trên Windows nên không cần phân biệt PID bị dùng lại
+    pub fn start_time(_pid: u32) -> Option<u64> {
+        None
+    }
+
+    // Bảng TCP kèm PID của từng kết nối; buffer u32 để bảng được căn lề đúng
+    fn tcp_table(family: ADDRESS_FAMILY) -> Option<Vec<u32>> {
+        let mut size = 0u32;
+        unsafe { GetExtendedTcpTable(None, &mut size, false, family.0 as u32, TCP_TABLE_OWNER_PID_CONNECTIONS, 0) };
+        let mut buffer = vec![0u32; (size as usize).div_ceil(4)];
+        let result = unsafe {
+            GetExtendedTcpTable(Some(buffer.as_mut_ptr().cast()), &mut size, false, family.0 as u32, TCP_TABLE_OWNER_PID_CONNECTIONS, 0)
+        };
+        (result == 0).then_some(buffer)
+    }
+
+    // Socket của client là dòng có cổng local là cổng client và cổng remote là cổng helper
+    // (cổng nằm ở 16 bit thấp, thứ tự byte mạng)
+    fn tcp_owner_pid(peer: SocketAddr, local: SocketAddr) -> Option<u32> {
+        let matches = |local_port: u32, remote_port: u32| {
+            u16::from_be(local_port as u16) == peer.port() && u16::from_be(remote_port as u16) == local.port()
+        };
+        if peer.is_ipv4() {
+            let buffer = tcp_table(AF_INET)?;
+            let table = unsafe { &*(buffer.as_ptr() as *const MIB_TCPTABLE_OWNER_PID) };
+            let rows = unsafe { slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize) };
+            rows.iter().find(|row| matches(row.dwLocalPort, row.dwRemotePort)).map(|row| row.dwOwningPid)
+        } else {
+            let buffer = tcp_table(AF_INET6)?;
+            let table = unsafe { &*(buffer.as_ptr() as *const MIB_TCP6TABLE_OWNER_PID) };
+            let rows = unsafe { slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize) };
+            rows.iter().find(|row| matches(row.dwLocalPort, row.dwRemotePort)).map(|row| row.dwOwningPid)
+        }
+    }
+
+    fn process_sid(pid: u32) -> Option<String> {
+        unsafe {
+            let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+            let mut token = HANDLE::default();
+            let opened = OpenProcessToken(process, TOKEN_QUERY, &mut token);
+            let _ = CloseHandle(process);
+            opened.ok()?;
+            let mut size = 0u32;
+            let _ = GetTokenInformation(token, TokenUser, None, 0, &mut size);
+            let mut buffer = vec![0u64; (size as usize).div_ceil(8)];
+            let queried = GetTokenInformation(token, TokenUser, Some(buffer.as_mut_ptr().cast()), size, &mut size);
+            let _ = CloseHandle(token);
+            queried.ok()?;
+            let user = &*(buffer.as_ptr() as *const TOKEN_USER);
+            let mut text = PWSTR::null();
+            ConvertSidToStringSidW(user.User.Sid, &mut text).ok()?;
+            let sid = text.to_string().ok();
+            let _ = LocalFree(HLOCAL(text.0.cast()));
+            sid
+        }
+    }
+
+    pub fn launch(exe: &Path, args: &[String]) -> Result<(), String> {
+        let params: Vec<String> = args.iter().map(|arg| format!("\"{}\"", arg)).collect();
+        let exe = HSTRING::from(exe.as_os_str());
+        let params = HSTRING::from(params.join(" "));
+        // Giá trị trả về <= 32 là mã lỗi (VD: người dùng bấm No ở hộp thoại UAC)
+        let result = unsafe { ShellExecuteW(HWND(0), w!("runas"), &exe, &params, PCWSTR::null(), SW_HIDE) };
+        if result.0 <= 32 {
+            return Err(t!("err-helper-launch", error = format!("ShellExecute {}", result.0)));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tokens_match;
+
+    #[test]
+    fn tokens_must_match_exactly() {
+        assert!(tokens_match("abc123", "abc123"));
+        assert!(!tokens_match("abc124", "abc123"));
+        assert!(!tokens_match("abc", "abc123"));
+        assert!(!tokens_match("abc1234", "abc123"));
+        assert!(!tokens_match("", "abc123"));
+    }
+
+    #[cfg(target_os = "linux")]
+    mod linux {
+        use crate::i18n::t;
+        use crate::memory_cap::{BreachAction, LimitMode};
+        use crate::privileged::PrivilegedOp;
+        use crate::oom;
+        use super::super::platform::{is_kernel_thread, owned_by, socket_uid, stat_start_time, Owner};
+        use super::super::{check_op, execute, OomOriginals, MAX_WORKING_SET_MB};
+
+        const TCP_TABLE: &str = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:C350 0100007F:B870 0A 00000000:00000000 00:00000000 00000000     0        0 1 1 0 10 0
+   1: 0100007F:D431 0100007F:B870 01 00000000:00000000 00:00000000 00000000  1000        0 2 1 0 10 0
+   2: 0100007F:B870 0100007F:D431 01 00000000:00000000 00:00000000 00000000     0        0 3 1 0 10 0
+";
+
+        #[test]
+        fn socket_uid_finds_the_established_client_side() {
+            // Client 54321 (0xD431) nối tới helper 47216 (0xB870)
+            assert_eq!(socket_uid(TCP_TABLE, 0xD431, 0xB870), Some(1000));
+            // Chiều ngược lại là socket của chính helper
+            assert_eq!(socket_uid(TCP_TABLE, 0xB870, 0xD431), Some(0));
+            // Dòng 0 không ở trạng thái ESTABLISHED
+            assert_eq!(socket_uid(TCP_TABLE, 0xC350, 0xB870), None);
+        }
+
+        #[test]
+        fn owned_by_requires_real_effective_and_saved_uid() {
+            assert!(owned_by("Name:\tbash\nUid:\t1000\t1000\t1000\t1000\n", 1000));
+            // Chương trình setuid root do người dùng 1000 chạy
+            assert!(!owned_by("Uid:\t1000\t0\t0\t0\n", 1000));
+            assert!(!owned_by("Name:\tbash\n", 1000));
+        }
+
+        #[test]
+        fn kernel_threads_are_detected_from_the_flags_field() {
+            assert!(is_kernel_thread("2 (kthreadd) S 0 0 0 0 -1 2129984 0 0"));
+            // Tên có dấu cách và ngoặc không làm lệch cột
+            assert!(!is_kernel_thread("4321 (my (app) x) S 1 4321 4321 0 -1 4194560 0 0"));
+            assert!(is_kernel_thread("garbage"));
+        }
+
+        #[test]
+        fn sizes_outside_the_allowed_range_are_rejected_first() {
+            let owner = Owner(0);
+            let size_error = |mb: usize| Err(t!("err-helper-size", mb = mb, max = MAX_WORKING_SET_MB));
+
+            let pin = PrivilegedOp::Pin { pid: 0, working_set_mb: 0 };
+            assert_eq!(check_op(&pin, &owner, MAX_WORKING_SET_MB), size_error(0));
+
+            let limit = PrivilegedOp::Limit {
+                pid: 0,
+                max_ws_mb: MAX_WORKING_SET_MB + 1,
+                mode: LimitMode::default(),
+                breach: BreachAction::default(),
+            };
+            assert_eq!(check_op(&limit, &owner, MAX_WORKING_SET_MB), size_error(MAX_WORKING_SET_MB + 1));
+
+            let pin = PrivilegedOp::Pin { pid: 1, working_set_mb: 512 };
+            assert_eq!(check_op(&pin, &owner, MAX_WORKING_SET_MB), Err(t!("err-helper-pid", pid = 1)));
+        }
+
+        #[test]
+        fn pin_and_priority_are_capped_and_trim_is_refused_on_linux() {
+            let owner = Owner(0);
+            let pin = PrivilegedOp::Pin { pid: 0, working_set_mb: 4097 };
+            assert_eq!(check_op(&pin, &owner, 4096), Err(t!("err-helper-pin-size", mb = 4097, max = 4096)));
+            let trim = PrivilegedOp::Trim { pid: std::process::id() };
+            assert_eq!(check_op(&trim, &owner, 4096), Err(t!("err-trim-unsupported")));
+            // nice -20 vượt mức High (-10) mà SetPriority đạt tới
+            let priority = PrivilegedOp::SetOsPriority { pid: 0, value: -20 };
+            assert_eq!(check_op(&priority, &owner, 4096), Err(t!("err-helper-priority", value = -20)));
+        }
+
+        #[test]
+        fn negative_oom_score_adj_is_refused_unless_it_is_the_original() {
+            let pid = std::process::id();
+            let current = oom::score_adj(pid).unwrap();
+            let originals = OomOriginals::default();
+            if current > oom::SCORE_ADJ_MIN {
+                let lower = PrivilegedOp::SetOomScoreAdj { pid, value: oom::SCORE_ADJ_MIN };
+                assert_eq!(
+                    execute(&lower, &originals),
+                    Err(t!("err-helper-oom-negative", pid = pid, value = oom::SCORE_ADJ_MIN))
+                );
+            }
+            // Giá trị gốc ghi nhận từ trước thì được đặt lại, kể cả khi âm
+            originals.lock().unwrap().insert((pid, super::super::platform::start_time(pid)), -500);
+            let restore = PrivilegedOp::SetOomScoreAdj { pid, value: -400 };
+            assert_eq!(
+                execute(&restore, &originals),
+                Err(t!("err-helper-oom-negative", pid = pid, value = -400))
+            );
+        }
+
+        #[test]
+        fn start_time_is_read_after_the_process_name() {
+            let stat = "4321 (my (app) x) S 1 4321 4321 0 -1 4194560 100 0 0 0 5 3 0 0 20 0 4 0 12345 1000 50";
+            assert_eq!(stat_start_time(stat), Some(12345));
+            assert_eq!(stat_start_time("garbage"), None);
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use serde::{Deserialize, Serialize};
+use unic_langid::LanguageIdentifier;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    Vi,
+    En,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::Vi, Language::En];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::Vi => "vi",
+            Language::En => "en",
+        }
+    }
+
+    // Tên ngôn ngữ viết bằng chính ngôn ngữ đó, dùng cho menu chọn ngôn ngữ
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::Vi => "Tiếng Việt",
+            Language::En => "English",
+        }
+    }
+
+    fn source(&self) -> &'static str {
+        match self {
+            Language::Vi => include_str!("../locales/vi.ftl"),
+            Language::En => include_str!("../locales/en.ftl"),
+        }
+    }
+
+    // Theo ngôn ngữ của hệ điều hành: tiếng Việt nếu locale là vi-*, còn lại dùng tiếng Anh
+    pub fn detect() -> Language {
+        match sys_locale::get_locale() {
+            Some(locale) if locale.to_lowercase().starts_with("vi") => Language::Vi,
+            _ => Language::En,
+        }
+    }
+
+    fn index(&self) -> u8 {
+        match self {
+            Language::Vi => 0,
+            Language::En => 1,
+        }
+    }
+}
+
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+static BUNDLES: OnceLock<Vec<(Language, FluentBundle<FluentResource>)>> = OnceLock::new();
+
+pub fn set_language(language: Language) {
+    CURRENT.store(language.index(), Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    Language::ALL
+        .into_iter()
+        .find(|l| l.index() == CURRENT.load(Ordering::Relaxed))
+        .unwrap_or_default()
+}
+
+fn bundles() -> &'static [(Language, FluentBundle<FluentResource>)] {
+    BUNDLES.get_or_init(|| {
+        Language::ALL
+            .into_iter()
+            .map(|language| {
+                let resource = FluentResource::try_new(language.source().to_string())
+                    .unwrap_or_else(|(_, e)| panic!("Lỗi cú pháp trong locales/{}.ftl: {:?}", language.code(), e));
+                let langid: LanguageIdentifier = language.code().parse().expect("mã ngôn ngữ không hợp lệ");
+                let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+                // egui không hiển thị được ký tự Unicode isolation quanh tham số
+                bundle.set_use_isolating(false);
+                bundle
+                    .add_resource(resource)
+                    .unwrap_or_else(|e| panic!("Trùng key trong locales/{}.ftl: {:?}", language.code(), e));
+                (language, bundle)
+            })
+            .collect()
+    })
+}
+
+fn format(language: Language, key: &str, args: Option<&FluentArgs>) -> Option<String> {
+    let (_, bundle) = bundles().iter().find(|(l, _)| *l == language)?;
+    let pattern = bundle.get_message(key)?.value()?;
+    let mut errors = Vec::new();
+    Some(bundle.format_pattern(pattern, args, &mut errors).into_owned())
+}
+
+// Key thiếu trong ngôn ngữ hiện tại thì lấy bản tiếng Việt, thiếu nữa thì trả về chính key
+pub fn tr(key: &str, args: Option<&FluentArgs>) -> String {
+    format(language(), key, args)
+        .or_else(|| format(Language::Vi, key, args))
+        .unwrap_or_else(|| key.to_string())
+}
+
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::tr($key, None)
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = fluent_bundle::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::i18n::tr($key, Some(&args))
+    }};
+}
+pub(crate) use t;
+
+// Số MB/%/GB hiển thị với 1 chữ số thập phân, Fluent không tự làm tròn
+pub fn num(value: f64) -> String {
+    format!("{:.1}", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use super::{format, Language};
+
+    // Message id là các dòng bắt đầu ở cột 0 bằng chữ cái, dạng `key = ...`
+    fn message_ids(language: Language) -> Vec<String> {
+        language
+            .source()
+            .lines()
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_alphabetic()))
+            .filter_map(|line| line.split_once('=').map(|(id, _)| id.trim().to_string()))
+            .collect()
+    }
+
+    // Các key có trong ngôn ngữ này nhưng thiếu ở ngôn ngữ khác, dạng "en: key"
+    fn missing_keys() -> Vec<String> {
+        let catalogs: Vec<(Language, Vec<String>)> =
+            Language::ALL.into_iter().map(|l| (l, message_ids(l))).collect();
+
+        let mut missing = Vec::new();
+        for (language, ids) in catalogs.iter() {
+            for (_, other_ids) in catalogs.iter().filter(|(l, _)| l != language) {
+                for id in other_ids.iter().filter(|id| !ids.contains(id)) {
+                    let entry = format!("{}: {}", language.code(), id);
+                    if !missing.contains(&entry) {
+                        missing.push(entry);
+                    }
+                }
+            }
+        }
+        missing
+    }
+
+    // Key dạng chuỗi literal ngay sau `t!(` trong mọi file .rs của src/ (bỏ qua `format!(`, `print!(`...)
+    fn keys_used_in_code() -> Vec<(String, String)> {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let mut keys = Vec::new();
+        for entry in fs::read_dir(&src).unwrap().filter_map(Result::ok) {
+            let path = entry.path();
+            if path.extension().is_none_or(|ext| ext != "rs") {
+                continue;
+            }
+            let text = fs::read_to_string(&path).unwrap();
+            for (i, m) in text.match_indices("t!(\"") {
+                if text[..i].ends_with(|c: char| c.is_alphanumeric() || c == '_') {
+                    continue;
+                }
+                let rest = &text[i + m.len()..];
+                if let Some(end) = rest.find('"') {
+                    let file = path.file_name().unwrap().to_string_lossy().into_owned();
+                    keys.push((file, rest[..end].to_string()));
+                }
+            }
+        }
+        keys
+    }
+
+    #[test]
+    fn catalogs_have_the_same_keys() {
+        assert_eq!(missing_keys(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn every_key_used_in_code_resolves_in_all_languages() {
+        let keys = keys_used_in_code();
+        assert!(!keys.is_empty());
+        let unresolved: Vec<String> = keys
+            .iter()
+            .flat_map(|(file, key)| {
+                Language::ALL
+                    .into_iter()
+                    .filter(|language| format(*language, key, None).is_none())
+                    .map(move |language| format!("{} ({}): {}", file, language.code(), key))
+            })
+            .collect();
+        assert_eq!(unresolved, Vec::<String>::new());
+    }
+}
@@ -0,0 +1,378 @@
+use std::process::{Command, ExitStatus};
+use std::thread;
+use std::time::SystemTime;
+use crate::i18n::t;
+use crate::ram_manager::Priority;
+
+// Giới hạn đặt cho chương trình ngay từ lúc khởi chạy; None = không giới hạn / giữ mặc định
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LaunchPolicy {
+    pub memory_max_mb: Option<u64>,
+    // memory.high và memory.swap.max chỉ có trên Linux (cgroup v2)
+    pub memory_high_mb: Option<u64>,
+    pub swap_max_mb: Option<u64>,
+    pub priority: Option<Priority>,
+}
+
+#[derive(Clone, Debug)]
+pub struct LaunchRequest {
+    pub command: String,
+    pub policy: LaunchPolicy,
+}
+
+#[derive(Clone, Debug)]
+pub struct LaunchExit {
+    pub at: SystemTime,
+    pub pid: u32,
+    pub command: String,
+    pub status: Result<ExitStatus, String>,
+}
+
+impl LaunchExit {
+    pub fn message(&self) -> String {
+        match &self.status {
+            Ok(status) => match status.code() {
+                Some(code) => t!("launch-exited", pid = self.pid, command = self.command.as_str(), code = code),
+                // Bị kết thúc bằng signal (Linux), VD: OOM killer của cgroup gửi SIGKILL
+                None => t!(
+                    "launch-signaled",
+                    pid = self.pid,
+                    command = self.command.as_str(),
+                    status = status.to_string()
+                ),
+            },
+            Err(e) => t!("launch-wait-failed", pid = self.pid, command = self.command.as_str(), error = e.as_str()),
+        }
+    }
+}
+
+// Tách dòng lệnh theo khoảng trắng, giữ nguyên phần trong "..." hoặc '...'.
+// Không coi \ là ký tự escape để đường dẫn Windows dùng được nguyên dạng
+pub fn split_command(command: &str) -> Result<Vec<String>, String> {
+    let mut args = Vec::new();
+    let mut current: Option<String> = None;
+    let mut quote = None;
+    for c in command.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => current.get_or_insert_with(String::new).push(c),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                current.get_or_insert_with(String::new);
+            }
+            (None, c) if c.is_whitespace() => args.extend(current.take()),
+            (None, c) => current.get_or_insert_with(String::new).push(c),
+        }
+    }
+    if quote.is_some() {
+        return Err(t!("err-launch-quote"));
+    }
+    args.extend(current);
+    if args.is_empty() {
+        return Err(t!("err-launch-empty"));
+    }
+    Ok(args)
+}
+
+// Chương trình đã khởi chạy cùng cgroup / job object chứa nó. Drop dọn cgroup hoặc đóng job
+pub struct Launched {
+    pub pid: u32,
+    sandbox: platform::Sandbox,
+}
+
+impl Launched {
+    // Restore: bỏ mọi giới hạn nhưng vẫn để tiến trình trong cgroup / job để theo dõi đến khi thoát
+    pub fn release_limits(&self) -> Result<(), String> {
+        self.sandbox.release_limits()
+    }
+}
+
+// Chạy luôn trong tiến trình GUI (không qua helper) để chương trình mới chạy dưới quyền người dùng hiện tại.
+// on_exit được gọi từ thread riêng khi chương trình thoát
+pub fn spawn(request: &LaunchRequest, on_exit: impl FnOnce(LaunchExit) + Send + 'static) -> Result<Launched, String> {
+    let args = split_command(&request.command)?;
+    let mut command = Command::new(&args[0]);
+    command.args(&args[1..]);
+
+    let sandbox = platform::Sandbox::create(&request.policy)?;
+    let mut child = sandbox.spawn(&mut command, request.policy.priority)?;
+    let pid = child.id();
+
+    let text = request.command.clone();
+    thread::Builder::new()
+        .name(format!("ram-manager-launch-{}", pid))
+        .spawn(move || {
+            let status = child.wait().map_err(|e| e.to_string());
+            on_exit(LaunchExit {
+                at: SystemTime::now(),
+                pid,
+                command: text,
+                status,
+            });
+        })
+        .map_err(|e| t!("err-launch-spawn", error = e.to_string()))?;
+    Ok(Launched { pid, sandbox })
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::ffi::CString;
+    use std::fs;
+    use std::io;
+    use std::os::unix::ffi::OsStrExt;
+    use std::os::unix::process::CommandExt;
+    use std::path::{Path, PathBuf};
+    use std::process::{Child, Command};
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use super::LaunchPolicy;
+    use crate::cgroup;
+    use crate::i18n::t;
+    use crate::ram_manager::Priority;
+
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    const LIMIT_FILES: [&str; 3] = ["memory.max", "memory.high", "memory.swap.max"];
+
+    pub struct Sandbox {
+        dir: PathBuf,
+    }
+
+    impl Sandbox {
+        // Cgroup mới nằm cạnh cgroup của chương trình này (cgroup đang chứa tiến trình thì không được có cgroup con
+        // dùng controller memory). Leo dần lên trên tới khi gặp cgroup cho phép tạo con có memory.max
+        pub fn create(policy: &LaunchPolicy) -> Result<Sandbox, String> {
+            let own = cgroup::of_pid(std::process::id()).ok_or_else(|| t!("err-cgroup-v2", pid = std::process::id()))?;
+            let root = Path::new(cgroup::ROOT);
+            let parents: Vec<&Path> = if own == root {
+                vec![root]
+            } else {
+                own.ancestors().skip(1).take_while(|dir| dir.starts_with(root)).collect()
+            };
+
+            let name = format!("ram-manager-launch-{}-{}", std::process::id(), COUNTER.fetch_add(1, Ordering::Relaxed));
+            let mut last_error = t!("err-cgroup-v2", pid = std::process::id());
+            for parent in parents {
+                let dir = parent.join(&name);
+                if let Err(e) = fs::create_dir(&dir) {
+                    last_error = t!("err-create-dir", path = dir.display().to_string(), error = e.to_string());
+                    continue;
+                }
+                let sandbox = Sandbox { dir };
+                if !sandbox.dir.join("memory.max").exists() {
+                    last_error = t!("err-cgroup-no-memory-controller", path = parent.display().to_string());
+                    continue;
+                }
+                sandbox.set_limits(policy)?;
+                return Ok(sandbox);
+            }
+            Err(last_error)
+        }
+
+        fn set_limits(&self, policy: &LaunchPolicy) -> Result<(), String> {
+            let limits = [policy.memory_max_mb, policy.memory_high_mb, policy.swap_max_mb];
+            for (file, mb) in LIMIT_FILES.into_iter().zip(limits) {
+                if mb.is_some() {
+                    cgroup::write(&self.dir, file, &cgroup::limit_value(mb)?)?;
+                }
+            }
+            Ok(())
+        }
+
+        // Tiến trình con tự ghi mình vào cgroup.procs và đặt nice trước khi exec,
+        // nên chương trình chạy trong giới hạn ngay từ lệnh đầu tiên
+        pub fn spawn(&self, command: &mut Command, priority: Option<Priority>) -> Result<Child, String> {
+            let procs = CString::new(self.dir.join("cgroup.procs").as_os_str().as_bytes())
+                .map_err(|e| t!("err-launch-spawn", error = e.to_string()))?;
+            let nice = priority.map(|priority| priority.nice());
+            // Trong pre_exec chỉ được gọi hàm async-signal-safe: open/write/close/setpriority, không cấp phát
+            unsafe {
+                command.pre_exec(move || {
+                    let fd = libc::open(procs.as_ptr(), libc::O_WRONLY | libc::O_CLOEXEC);
+                    if fd < 0 {
+                        return Err(io::Error::last_os_error());
+                    }
+                    let written = libc::write(fd, b"0".as_ptr().cast(), 1);
+                    let error = io::Error::last_os_error();
+                    libc::close(fd);
+                    if written < 0 {
+                        return Err(error);
+                    }
+                    if let Some(nice) = nice {
+                        if libc::setpriority(libc::PRIO_PROCESS, 0, nice) != 0 {
+                            return Err(io::Error::last_os_error());
+                        }
+                    }
+                    Ok(())
+                });
+            }
+            command.spawn().map_err(|e| t!("err-launch-spawn", error = e.to_string()))
+        }
+
+        pub fn release_limits(&self) -> Result<(), String> {
+            for file in LIMIT_FILES {
+                // Kernel không bật swap accounting thì không có memory.swap.max
+                if self.dir.join(file).exists() {
+                    cgroup::write(&self.dir, file, "max")?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    // Chỉ xoá được khi cgroup đã trống; chương trình còn tiến trình con đang chạy thì để lại
+    impl Drop for Sandbox {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir(&self.dir);
+        }
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::ffi::c_void;
+    use std::mem;
+    use std::os::windows::io::AsRawHandle;
+    use std::os::windows::process::CommandExt;
+    use std::process::{Child, Command};
+    use windows::core::PCWSTR;
+    use windows::Win32::Foundation::{CloseHandle, HANDLE};
+    use windows::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, JobObjectExtendedLimitInformation, SetInformationJobObject,
+        JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JOB_OBJECT_LIMIT_JOB_MEMORY, JOB_OBJECT_LIMIT_PRIORITY_CLASS,
+    };
+    use windows::Win32::System::Threading::CREATE_SUSPENDED;
+    use super::LaunchPolicy;
+    use crate::freeze;
+    use crate::i18n::t;
+    use crate::memory_cap;
+    use crate::ram_manager::Priority;
+
+    pub struct Sandbox {
+        job: HANDLE,
+    }
+
+    impl Sandbox {
+        pub fn create(policy: &LaunchPolicy) -> Result<Sandbox, String> {
+            let job = unsafe { CreateJobObjectW(None, PCWSTR::null()) }
+                .map_err(|e| t!("err-job-object", error = format!("{:?}", e)))?;
+            let sandbox = Sandbox { job };
+
+            let mut info = JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default();
+            if let Some(mb) = policy.memory_max_mb {
+                info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_JOB_MEMORY;
+                info.JobMemoryLimit = memory_cap::mb_to_bytes(mb)?;
+            }
+            if let Some(priority) = policy.priority {
+                info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_PRIORITY_CLASS;
+                info.BasicLimitInformation.PriorityClass = priority.priority_class().0;
+            }
+            sandbox.set_limits(&info)?;
+            Ok(sandbox)
+        }
+
+        fn set_limits(&self, info: &JOBOBJECT_EXTENDED_LIMIT_INFORMATION) -> Result<(), String> {
+            unsafe {
+                SetInformationJobObject(
+                    self.job,
+                    JobObjectExtendedLimitInformation,
+                    info as *const _ as *const c_void,
+                    mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+                )
+            }
+            .map_err(|e| t!("err-job-object", error = format!("{:?}", e)))
+        }
+
+        // Tạo ở trạng thái suspended, gán vào job rồi mới cho chạy; priority được job đặt khi gán
+        pub fn spawn(&self, command: &mut Command, _priority: Option<Priority>) -> Result<Child, String> {
+            let mut child = command
+                .creation_flags(CREATE_SUSPENDED.0)
+                .spawn()
+                .map_err(|e| t!("err-launch-spawn", error = e.to_string()))?;
+            let process = HANDLE(child.as_raw_handle() as isize);
+            let started = unsafe { AssignProcessToJobObject(self.job, process) }
+                .map_err(|e| t!("err-job-object", error = format!("{:?}", e)))
+                .and_then(|_| freeze::thaw(child.id()));
+            if let Err(e) = started {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(e);
+            }
+            Ok(child)
+        }
+
+        pub fn release_limits(&self) -> Result<(), String> {
+            self.set_limits(&JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default())
+        }
+    }
+
+    // Không đặt KILL_ON_JOB_CLOSE: đóng RAM Manager không kết thúc chương trình đã khởi chạy
+    impl Drop for Sandbox {
+        fn drop(&mut self) {
+            let _ = unsafe { CloseHandle(self.job) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+    use crate::i18n::t;
+    use super::{split_command, LaunchExit};
+
+    fn exit(status: Result<std::process::ExitStatus, String>) -> LaunchExit {
+        LaunchExit { at: SystemTime::now(), pid: 42, command: "make -j8".to_string(), status }
+    }
+
+    #[test]
+    fn quoted_parts_stay_one_argument() {
+        assert_eq!(split_command("\"a b\" c").unwrap(), vec!["a b", "c"]);
+        assert_eq!(split_command("  make   -j8 ").unwrap(), vec!["make", "-j8"]);
+        assert_eq!(split_command("echo 'it \"works\"'").unwrap(), vec!["echo", "it \"works\""]);
+        // Nối liền với phần ngoài ngoặc như shell
+        assert_eq!(split_command("--name=\"x y\"").unwrap(), vec!["--name=x y"]);
+    }
+
+    #[test]
+    fn empty_quotes_are_an_empty_argument() {
+        assert_eq!(split_command("prog \"\" last").unwrap(), vec!["prog", "", "last"]);
+        assert_eq!(split_command("''").unwrap(), vec![""]);
+    }
+
+    #[test]
+    fn backslashes_are_kept_for_windows_paths() {
+        assert_eq!(
+            split_command(r#""C:\Program Files\App\app.exe" C:\temp\in.txt"#).unwrap(),
+            vec![r"C:\Program Files\App\app.exe", r"C:\temp\in.txt"]
+        );
+    }
+
+    #[test]
+    fn unterminated_quotes_and_empty_input_are_rejected() {
+        assert_eq!(split_command("prog \"unterminated"), Err(t!("err-launch-quote")));
+        assert_eq!(split_command(""), Err(t!("err-launch-empty")));
+        assert_eq!(split_command(" \t "), Err(t!("err-launch-empty")));
+    }
+
+    #[test]
+    fn exit_message_reports_the_wait_error() {
+        assert_eq!(
+            exit(Err("gone".to_string())).message(),
+            t!("launch-wait-failed", pid = 42, command = "make -j8", error = "gone")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn exit_message_distinguishes_exit_codes_and_signals() {
+        use std::os::unix::process::ExitStatusExt;
+        let exited = std::process::ExitStatus::from_raw(3 << 8);
+        assert_eq!(exit(Ok(exited)).message(), t!("launch-exited", pid = 42, command = "make -j8", code = 3));
+
+        // SIGKILL, VD: OOM killer của cgroup
+        let killed = std::process::ExitStatus::from_raw(9);
+        assert_eq!(
+            exit(Ok(killed)).message(),
+            t!("launch-signaled", pid = 42, command = "make -j8", status = killed.to_string())
+        );
+    }
+}
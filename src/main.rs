@@ -1,19 +1,84 @@
 #![windows_subsystem = "windows"] // Ẩn console window khi release
 
+mod config;
+mod fonts;
+mod i18n;
+mod profile;
 mod ram_manager;
+mod memory_map;
+mod system_memory;
+mod swap;
+mod page_faults;
+mod effect;
+mod pageout;
+mod freeze;
+mod memory_cap;
+mod oom;
+mod early_oom;
+mod launch;
+mod alerts;
+mod privileged;
+mod helper;
+mod api;
+mod permissions;
+#[cfg(target_os = "linux")]
+mod cgroup;
+mod cgroup_tree;
+#[cfg(windows)]
+mod pdh;
+mod process_tree;
+mod process_group;
+mod batch;
+mod worker;
 mod gui;
 
 use eframe::egui;
 use std::env;
+use i18n::Language;
+use profile::ProfileCommand;
 
 fn main() -> Result<(), eframe::Error> {
-    // Check for admin privileges
-    if !is_elevated() {
+    // Ngôn ngữ theo hệ điều hành, GUI đổi lại theo config.toml nếu có khai báo
+    i18n::set_language(Language::detect());
+
+    // --helper: chạy tiến trình helper có quyền thay vì GUI
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("--helper") {
+        if let Err(e) = helper::serve(&args[1..]) {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // --api <yêu cầu>: gửi yêu cầu tới GUI đang chạy (bật [api]) và in kết quả JSON
+    if args.first().map(String::as_str) == Some("--api") {
+        match api::request(&args[1..]) {
+            Ok(output) => println!("{}", output),
+            Err(e) => {
+                eprintln!("❌ {}", e);
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    // Check for admin privileges (không cần khi các thao tác được gửi qua helper)
+    let helper_enabled = config::Config::load(&config::Config::path())
+        .map(|config| config.helper.enabled)
+        .unwrap_or(false);
+    if !is_elevated() && !helper_enabled {
         show_admin_warning();
     }
 
     // Setup logging
     env::set_var("RUST_LOG", "info");
+
+    // --profile <tên> | --revert-profile
+    let profile_command = ProfileCommand::from_args(&args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        None
+    });
     
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
@@ -26,11 +91,8 @@ fn main() -> Result<(), eframe::Error> {
     eframe::run_native(
         "Advanced RAM Manager v1.0.0",
         options,
-        Box::new(|cc| {
-            // Configure fonts
-            configure_fonts(&cc.egui_ctx);
-            Ok(Box::new(gui::RamManagerApp::default()))
-        }),
+        // Font và UI scale được cấu hình trong RamManagerApp::new sau khi đọc config
+        Box::new(|cc| Ok(Box::new(gui::RamManagerApp::new(cc, profile_command)))),
     )
 }
 
@@ -66,6 +128,10 @@ fn is_elevated() -> bool {
             }
         }
     }
+    #[cfg(target_os = "linux")]
+    if permissions::Permissions::detect().effective_uid == Some(0) {
+        return true;
+    }
     false
 }
 
@@ -73,11 +139,16 @@ fn show_admin_warning() {
     #[cfg(target_os = "windows")]
     {
         use std::process::Command;
+        use crate::i18n::t;
+        let message = format!(
+            "echo {} && echo. && echo {} && echo {} && echo {} && pause",
+            t!("admin-required"),
+            t!("admin-please"),
+            t!("admin-step-close"),
+            t!("admin-step-run-as")
+        );
         let _ = Command::new("cmd")
-            .args(&[
-                "/C",
-                "echo Tool cần quyền Administrator! && echo. && echo Vui lòng: && echo 1. Đóng tool && echo 2. Chuột phải - Run as Administrator && pause"
-            ])
+            .args(&["/C", &message])
             .spawn();
     }
 }
@@ -115,9 +186,4 @@ fn load_icon() -> egui::IconData {
             }
         }
     }
-}
-
-fn configure_fonts(_ctx: &egui::Context) {
-    // Cấu hình fonts tùy chỉnh nếu cần
-    // Ví dụ: thêm font Vietnamese
 }
\ No newline at end of file
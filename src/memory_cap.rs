@@ -0,0 +1,449 @@
+#[cfg(target_os = "linux")]
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use crate::i18n::t;
+
+// Cách Limit giữ bộ nhớ của tiến trình, chọn riêng cho từng hành động / rule của profile
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitMode {
+    // Windows: working set tối đa cứng (QUOTA_LIMITS_HARDWS_MAX_ENABLE), phần vượt bị đẩy ra pagefile.
+    // Linux: memory.high, kernel reclaim và làm chậm tiến trình khi vượt
+    #[default]
+    WorkingSet,
+    // Giới hạn tổng bộ nhớ: job object trên Windows, memory.max trên Linux
+    Hard,
+}
+
+impl LimitMode {
+    pub const ALL: [LimitMode; 2] = [LimitMode::WorkingSet, LimitMode::Hard];
+
+    pub fn label(&self) -> String {
+        match self {
+            LimitMode::WorkingSet => t!("limit-mode-working-set"),
+            LimitMode::Hard => t!("limit-mode-hard"),
+        }
+    }
+
+    pub fn hint(&self) -> String {
+        match self {
+            LimitMode::WorkingSet => t!("limit-mode-working-set-hint"),
+            LimitMode::Hard => t!("limit-mode-hard-hint"),
+        }
+    }
+}
+
+// Điều gì xảy ra khi tiến trình chạm giới hạn cứng
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreachAction {
+    // Windows: cấp phát vượt giới hạn thất bại. Linux: kernel reclaim/swap, chỉ OOM kill khi không thu hồi được
+    #[default]
+    Deny,
+    // Windows: kết thúc cả job ngay khi vượt. Linux: không cho swap (memory.swap.max = 0)
+    // và memory.oom.group = 1 để OOM killer kết thúc cả cgroup. Deny giữ nguyên giới hạn swap đang có (SwapLimit)
+    Terminate,
+}
+
+impl BreachAction {
+    pub const ALL: [BreachAction; 2] = [BreachAction::Deny, BreachAction::Terminate];
+
+    pub fn label(&self) -> String {
+        match self {
+            BreachAction::Deny => t!("breach-deny"),
+            BreachAction::Terminate => t!("breach-terminate"),
+        }
+    }
+
+    pub fn hint(&self) -> String {
+        match self {
+            BreachAction::Deny => t!("breach-deny-hint"),
+            BreachAction::Terminate => t!("breach-terminate-hint"),
+        }
+    }
+}
+
+// MB sang byte cho cgroup/Win32; số MB đến từ config hoặc qua socket của helper nên không được để tràn
+pub fn mb_to_bytes<T: TryFrom<u64>>(mb: u64) -> Result<T, String> {
+    mb.checked_mul(1024 * 1024)
+        .and_then(|bytes| T::try_from(bytes).ok())
+        .ok_or_else(|| t!("err-size-too-large", mb = mb))
+}
+
+pub fn apply(pid: u32, limit_mb: usize, mode: LimitMode, breach: BreachAction) -> Result<(), String> {
+    platform::apply(pid, limit_mb, mode, breach)
+}
+
+// Giới hạn swap (và zswap) của tiến trình; None là không giới hạn, 0 là không bao giờ swap.
+// Chỉ có trên Linux (cgroup v2), Windows không cho giới hạn pagefile theo tiến trình
+pub fn limit_swap(pid: u32, swap_max_mb: Option<u64>, zswap_max_mb: Option<u64>) -> Result<(), String> {
+    platform::limit_swap(pid, swap_max_mb, zswap_max_mb)
+}
+
+// Hiện giới hạn swap trong thông báo: bỏ trống là không giới hạn
+pub fn swap_limit_label(mb: Option<u64>) -> String {
+    match mb {
+        Some(0) => t!("swap-limit-never"),
+        Some(mb) => t!("swap-limit-mb", mb = mb),
+        None => t!("swap-limit-unlimited"),
+    }
+}
+
+// Pin trên Linux: memory.min ở cùng cgroup riêng với Limit, kernel không reclaim bộ nhớ của tiến trình dưới mức này
+#[cfg(target_os = "linux")]
+pub fn protect(pid: u32, min_mb: usize) -> Result<(), String> {
+    platform::protect(pid, min_mb)
+}
+
+// Cgroup riêng mà apply/limit_swap/protect sẽ ghi cho PID này, dùng để dò quyền trước khi làm
+#[cfg(target_os = "linux")]
+pub struct LimitTarget {
+    pub dir: PathBuf,
+    // Tiến trình đã ở cgroup riêng (dir đã tồn tại)
+    pub owned: bool,
+    // Cgroup gốc mà release trả tiến trình về; None với cgroup của chương trình khởi chạy có giới hạn
+    pub original: Option<PathBuf>,
+}
+
+#[cfg(target_os = "linux")]
+pub fn limit_target(pid: u32) -> Option<LimitTarget> {
+    platform::limit_target(pid)
+}
+
+// Bỏ giới hạn do apply/limit_swap/protect đặt; tiến trình chưa bị giới hạn thì không làm gì
+pub fn release(pid: u32) -> Result<(), String> {
+    platform::release(pid)
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use super::{BreachAction, LimitMode, LimitTarget};
+    use crate::cgroup;
+    use crate::i18n::t;
+
+    // Cgroup tạo bởi chương trình này (Limit hoặc khởi chạy có giới hạn) thì được ghi giới hạn trực tiếp
+    const OWNED_PREFIX: &str = "ram-manager-";
+    const LIMIT_PREFIX: &str = "ram-manager-limit-";
+
+    // Cgroup riêng cho PID nằm cạnh cgroup gốc của nó và mang theo tên cgroup gốc:
+    // `ram-manager-limit-<pid>.<tên cũ>` (hoặc `ram-manager-limit-<pid>` khi tiến trình ở cgroup gốc),
+    // nhờ vậy Restore biết trả tiến trình về đâu mà không phải lưu gì (kể cả khi chạy qua helper)
+    fn original_cgroup(dir: &Path, pid: u32) -> Option<PathBuf> {
+        let name = dir.file_name()?.to_str()?;
+        let rest = name.strip_prefix(LIMIT_PREFIX)?.strip_prefix(pid.to_string().as_str())?;
+        let parent = dir.parent()?;
+        match rest.strip_prefix('.') {
+            Some(original) => Some(parent.join(original)),
+            None if rest.is_empty() => Some(parent.to_path_buf()),
+            None => None,
+        }
+    }
+
+    fn is_owned(dir: &Path) -> bool {
+        dir.file_name().and_then(|name| name.to_str()).is_some_and(|name| name.starts_with(OWNED_PREFIX))
+    }
+
+    fn sibling(current: &Path, pid: u32) -> PathBuf {
+        let root = Path::new(cgroup::ROOT);
+        match current.parent().filter(|_| current != root) {
+            Some(parent) => {
+                let name = current.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+                parent.join(format!("{}{}.{}", LIMIT_PREFIX, pid, name))
+            }
+            None => root.join(format!("{}{}", LIMIT_PREFIX, pid)),
+        }
+    }
+
+    pub fn limit_target(pid: u32) -> Option<LimitTarget> {
+        let current = cgroup::of_pid(pid)?;
+        if is_owned(&current) {
+            let original = original_cgroup(&current, pid);
+            return Some(LimitTarget { dir: current, owned: true, original });
+        }
+        Some(LimitTarget { dir: sibling(&current, pid), owned: false, original: None })
+    }
+
+    // Cgroup đang chứa tiến trình khác thì không bật được controller memory cho cgroup con,
+    // nên chuyển tiến trình sang một cgroup anh em mới
+    fn limit_cgroup(pid: u32) -> Result<PathBuf, String> {
+        let current = cgroup::of_pid(pid).ok_or_else(|| t!("err-cgroup-v2", pid = pid))?;
+        if is_owned(&current) {
+            return Ok(current);
+        }
+        let root = Path::new(cgroup::ROOT);
+        let dir = sibling(&current, pid);
+        if !dir.exists() {
+            fs::create_dir(&dir)
+                .map_err(|e| t!("err-create-dir", path = dir.display().to_string(), error = e.to_string()))?;
+        }
+        if !dir.join("memory.max").exists() {
+            let _ = fs::remove_dir(&dir);
+            let parent = dir.parent().unwrap_or(root);
+            return Err(t!("err-cgroup-no-memory-controller", path = parent.display().to_string()));
+        }
+        if let Err(e) = cgroup::write(&dir, "cgroup.procs", &pid.to_string()) {
+            let _ = fs::remove_dir(&dir);
+            return Err(e);
+        }
+        Ok(dir)
+    }
+
+    pub fn apply(pid: u32, limit_mb: usize, mode: LimitMode, breach: BreachAction) -> Result<(), String> {
+        let dir = limit_cgroup(pid)?;
+        let limit = cgroup::limit_value(Some(limit_mb as u64))?;
+        let (high, max) = match mode {
+            LimitMode::WorkingSet => (limit.as_str(), "max"),
+            LimitMode::Hard => ("max", limit.as_str()),
+        };
+        cgroup::write(&dir, "memory.high", high)?;
+        cgroup::write(&dir, "memory.max", max)?;
+
+        let terminate = mode == LimitMode::Hard && breach == BreachAction::Terminate;
+        // Kernel không bật swap accounting thì không có memory.swap.max
+        if terminate && dir.join("memory.swap.max").exists() {
+            cgroup::write(&dir, "memory.swap.max", "0")?;
+        }
+        cgroup::write(&dir, "memory.oom.group", if terminate { "1" } else { "0" })
+    }
+
+    pub fn limit_swap(pid: u32, swap_max_mb: Option<u64>, zswap_max_mb: Option<u64>) -> Result<(), String> {
+        cgroup::write_swap_limits(&limit_cgroup(pid)?, swap_max_mb, zswap_max_mb)
+    }
+
+    pub fn protect(pid: u32, min_mb: usize) -> Result<(), String> {
+        let min = cgroup::limit_value(Some(min_mb as u64))?;
+        cgroup::write(&limit_cgroup(pid)?, "memory.min", &min)
+    }
+
+    pub fn release(pid: u32) -> Result<(), String> {
+        let Some(dir) = cgroup::of_pid(pid).filter(|dir| is_owned(dir)) else {
+            return Ok(());
+        };
+        let Some(original) = original_cgroup(&dir, pid) else {
+            // Cgroup của chương trình khởi chạy có giới hạn: chỉ bỏ giới hạn, tiến trình vẫn ở đó
+            cgroup::write(&dir, "memory.min", "0")?;
+            for file in ["memory.high", "memory.max", "memory.swap.max", "memory.zswap.max"] {
+                if dir.join(file).exists() {
+                    cgroup::write(&dir, file, "max")?;
+                }
+            }
+            return cgroup::write(&dir, "memory.oom.group", "0");
+        };
+        // Đưa cả các tiến trình con sinh ra sau khi Limit về cgroup cũ rồi xoá cgroup riêng
+        for member in cgroup::read(&dir, "cgroup.procs")?.lines() {
+            cgroup::write(&original, "cgroup.procs", member)?;
+        }
+        fs::remove_dir(&dir).map_err(|e| t!("err-remove-dir", path = dir.display().to_string(), error = e.to_string()))
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::collections::HashSet;
+    use std::ffi::c_void;
+    use std::mem;
+    use std::sync::Mutex;
+    use std::thread;
+    use windows::core::HSTRING;
+    use windows::Win32::Foundation::{CloseHandle, BOOL, ERROR_ALREADY_EXISTS, GetLastError, HANDLE};
+    use windows::Win32::System::IO::{CreateIoCompletionPort, GetQueuedCompletionStatus, OVERLAPPED};
+    use windows::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, IsProcessInJob, JobObjectAssociateCompletionPortInformation,
+        JobObjectExtendedLimitInformation, OpenJobObjectW, SetInformationJobObject, TerminateJobObject,
+        JOBOBJECT_ASSOCIATE_COMPLETION_PORT, JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JOB_OBJECT_LIMIT_JOB_MEMORY,
+    };
+    use windows::Win32::System::Memory::{SetProcessWorkingSetSizeEx, QUOTA_LIMITS_HARDWS_MAX_ENABLE, QUOTA_LIMITS_HARDWS_MIN_DISABLE};
+    use windows::Win32::System::SystemServices::{
+        JOB_OBJECT_MSG_ACTIVE_PROCESS_ZERO, JOB_OBJECT_MSG_JOB_MEMORY_LIMIT, JOB_OBJECT_SET_ATTRIBUTES,
+    };
+    use windows::Win32::System::Threading::{OpenProcess, INFINITE, PROCESS_QUERY_INFORMATION, PROCESS_SET_QUOTA, PROCESS_TERMINATE};
+    use super::{mb_to_bytes, BreachAction, LimitMode};
+    use crate::i18n::t;
+
+    // PID có job đang đặt BreachAction::Terminate; thread theo dõi job đọc lại mỗi khi nhận thông báo
+    static TERMINATE_ON_BREACH: Mutex<Option<HashSet<u32>>> = Mutex::new(None);
+
+    fn set_terminate_on_breach(pid: u32, enabled: bool) {
+        let mut pids = TERMINATE_ON_BREACH.lock().unwrap();
+        let pids = pids.get_or_insert_with(HashSet::new);
+        if enabled {
+            pids.insert(pid);
+        } else {
+            pids.remove(&pid);
+        }
+    }
+
+    // Job có tên theo PID nên Restore mở lại được mà không phải giữ handle (kể cả khi chạy qua helper)
+    fn job_name(pid: u32) -> HSTRING {
+        HSTRING::from(format!("Local\\ram-manager-limit-{}", pid))
+    }
+
+    fn job_error(e: windows::core::Error) -> String {
+        t!("err-job-object", error = format!("{:?}", e))
+    }
+
+    fn set_job_limits(job: HANDLE, info: &JOBOBJECT_EXTENDED_LIMIT_INFORMATION) -> Result<(), String> {
+        unsafe {
+            SetInformationJobObject(
+                job,
+                JobObjectExtendedLimitInformation,
+                info as *const _ as *const c_void,
+                mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+            )
+        }
+        .map_err(job_error)
+    }
+
+    pub fn apply(pid: u32, limit_mb: usize, mode: LimitMode, breach: BreachAction) -> Result<(), String> {
+        match mode {
+            LimitMode::WorkingSet => {
+                // Bỏ giới hạn cứng của lần Limit trước (nếu có) để hai chế độ không chồng lên nhau
+                release(pid)?;
+                working_set(pid, limit_mb)
+            }
+            LimitMode::Hard => hard_cap(pid, limit_mb, breach),
+        }
+    }
+
+    fn working_set(pid: u32, max_ws_mb: usize) -> Result<(), String> {
+        let max_size: usize = mb_to_bytes(max_ws_mb as u64)?;
+        let min_size: usize = mb_to_bytes(max_ws_mb as u64 / 2)?;
+        unsafe {
+            let handle = OpenProcess(PROCESS_SET_QUOTA | PROCESS_QUERY_INFORMATION, false, pid)
+                .map_err(|e| t!("err-open-process", error = format!("{:?}", e)))?;
+
+            // Không có QUOTA_LIMITS_HARDWS_MAX_ENABLE thì max chỉ là gợi ý, tiến trình vẫn phình ra được
+            let result = SetProcessWorkingSetSizeEx(
+                handle,
+                min_size,
+                max_size,
+                QUOTA_LIMITS_HARDWS_MAX_ENABLE | QUOTA_LIMITS_HARDWS_MIN_DISABLE,
+            )
+            .map_err(|e| t!("err-limit-working-set", error = format!("{:?}", e)));
+            let _ = CloseHandle(handle);
+            result
+        }
+    }
+
+    // JOB_MEMORY thay vì PROCESS_MEMORY: tiến trình con sinh ra sau đó cũng vào job và cùng chịu một giới hạn
+    fn hard_cap(pid: u32, limit_mb: usize, breach: BreachAction) -> Result<(), String> {
+        let limit: usize = mb_to_bytes(limit_mb as u64)?;
+        unsafe {
+            let job = CreateJobObjectW(None, &job_name(pid)).map_err(job_error)?;
+            let created = !matches!(GetLastError(), Err(e) if e.code() == ERROR_ALREADY_EXISTS.to_hresult());
+            let process = match OpenProcess(PROCESS_SET_QUOTA | PROCESS_TERMINATE | PROCESS_QUERY_INFORMATION, false, pid) {
+                Ok(process) => process,
+                Err(e) => {
+                    let _ = CloseHandle(job);
+                    return Err(t!("err-open-process", error = format!("{:?}", e)));
+                }
+            };
+
+            let result = (|| {
+                let mut in_job = BOOL(0);
+                IsProcessInJob(process, job, &mut in_job).map_err(job_error)?;
+                if !in_job.as_bool() {
+                    AssignProcessToJobObject(job, process).map_err(job_error)?;
+                }
+                let mut info = JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default();
+                info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_JOB_MEMORY;
+                info.JobMemoryLimit = limit;
+                set_job_limits(job, &info)?;
+                set_terminate_on_breach(pid, breach == BreachAction::Terminate);
+                // Một job chỉ gắn được một completion port nên chỉ theo dõi khi job vừa được tạo
+                if created {
+                    watch(job, pid)?;
+                }
+                Ok(())
+            })();
+
+            let _ = CloseHandle(process);
+            let _ = CloseHandle(job);
+            result
+        }
+    }
+
+    // Thread nhận thông báo của job: vượt giới hạn thì kết thúc cả job nếu đang đặt Terminate,
+    // dừng khi trong job không còn tiến trình nào
+    fn watch(job: HANDLE, pid: u32) -> Result<(), String> {
+        unsafe {
+            let port = CreateIoCompletionPort(HANDLE(-1), None, 0, 1).map_err(job_error)?;
+            let association = JOBOBJECT_ASSOCIATE_COMPLETION_PORT {
+                CompletionKey: std::ptr::null_mut(),
+                CompletionPort: port,
+            };
+            let associated = SetInformationJobObject(
+                job,
+                JobObjectAssociateCompletionPortInformation,
+                &association as *const _ as *const c_void,
+                mem::size_of::<JOBOBJECT_ASSOCIATE_COMPLETION_PORT>() as u32,
+            );
+            // Thread giữ handle riêng của job vì handle của hard_cap bị đóng ngay sau đó
+            let job = match associated.and_then(|_| CreateJobObjectW(None, &job_name(pid))) {
+                Ok(job) => job,
+                Err(e) => {
+                    let _ = CloseHandle(port);
+                    return Err(job_error(e));
+                }
+            };
+            thread::Builder::new()
+                .name(format!("ram-manager-job-{}", pid))
+                .spawn(move || {
+                    let mut message = 0u32;
+                    let mut key = 0usize;
+                    let mut overlapped: *mut OVERLAPPED = std::ptr::null_mut();
+                    while GetQueuedCompletionStatus(port, &mut message, &mut key, &mut overlapped, INFINITE).is_ok() {
+                        if message == JOB_OBJECT_MSG_ACTIVE_PROCESS_ZERO {
+                            break;
+                        }
+                        let terminate = TERMINATE_ON_BREACH.lock().unwrap().as_ref().is_some_and(|pids| pids.contains(&pid));
+                        if message == JOB_OBJECT_MSG_JOB_MEMORY_LIMIT && terminate {
+                            let _ = TerminateJobObject(job, 1);
+                        }
+                    }
+                    set_terminate_on_breach(pid, false);
+                    let _ = CloseHandle(job);
+                    let _ = CloseHandle(port);
+                })
+                .map(|_| ())
+                .map_err(|e| t!("err-job-object", error = e.to_string()))
+        }
+    }
+
+    pub fn limit_swap(_pid: u32, _swap_max_mb: Option<u64>, _zswap_max_mb: Option<u64>) -> Result<(), String> {
+        Err(t!("err-swap-limit-unsupported"))
+    }
+
+    pub fn release(pid: u32) -> Result<(), String> {
+        set_terminate_on_breach(pid, false);
+        // Không có job tên này: tiến trình chưa từng bị giới hạn cứng
+        let Ok(job) = (unsafe { OpenJobObjectW(JOB_OBJECT_SET_ATTRIBUTES, false, &job_name(pid)) }) else {
+            return Ok(());
+        };
+        let result = set_job_limits(job, &JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default());
+        let _ = unsafe { CloseHandle(job) };
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::i18n::t;
+    use super::mb_to_bytes;
+
+    #[test]
+    fn sizes_convert_to_bytes() {
+        assert_eq!(mb_to_bytes::<u64>(0), Ok(0));
+        assert_eq!(mb_to_bytes::<u64>(1), Ok(1024 * 1024));
+        assert_eq!(mb_to_bytes::<u32>(4095), Ok(4095 * 1024 * 1024));
+    }
+
+    #[test]
+    fn sizes_that_overflow_the_target_type_are_rejected() {
+        // Vượt u64 khi nhân, và vượt u32 (VD: usize trên Windows 32-bit) khi chuyển kiểu
+        assert_eq!(mb_to_bytes::<u64>(u64::MAX / 1024), Err(t!("err-size-too-large", mb = u64::MAX / 1024)));
+        assert_eq!(mb_to_bytes::<u32>(4096), Err(t!("err-size-too-large", mb = 4096)));
+    }
+}
@@ -0,0 +1,479 @@
+use std::cmp::Ordering;
+use serde::{Deserialize, Serialize};
+use crate::i18n::t;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RegionKind {
+    Heap,
+    Stack,
+    Anonymous,
+    FileBacked,
+    SharedLibrary,
+    Other,
+}
+
+impl RegionKind {
+    pub const ALL: [RegionKind; 6] = [
+        RegionKind::Heap,
+        RegionKind::Stack,
+        RegionKind::Anonymous,
+        RegionKind::FileBacked,
+        RegionKind::SharedLibrary,
+        RegionKind::Other,
+    ];
+
+    pub fn label(&self) -> String {
+        match self {
+            RegionKind::Heap => t!("region-heap"),
+            RegionKind::Stack => t!("region-stack"),
+            RegionKind::Anonymous => t!("region-anonymous"),
+            RegionKind::FileBacked => t!("region-file"),
+            RegionKind::SharedLibrary => t!("region-library"),
+            RegionKind::Other => t!("region-other"),
+        }
+    }
+
+    pub fn color(&self) -> [u8; 3] {
+        match self {
+            RegionKind::Heap => [231, 76, 60],
+            RegionKind::Stack => [155, 89, 182],
+            RegionKind::Anonymous => [230, 126, 34],
+            RegionKind::FileBacked => [52, 152, 219],
+            RegionKind::SharedLibrary => [46, 204, 113],
+            RegionKind::Other => [128, 128, 128],
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MemoryRegion {
+    pub start: u64,
+    pub size_kb: u64,
+    pub kind: RegionKind,
+    pub permissions: String,
+    pub path: Option<String>,
+    pub rss_kb: u64,
+    // Windows không có PSS/swap theo vùng: PSS ước lượng từ share count, swap là None
+    pub pss_kb: Option<u64>,
+    pub swap_kb: Option<u64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RegionSummary {
+    pub kind: RegionKind,
+    pub regions: usize,
+    pub size_kb: u64,
+    pub rss_kb: u64,
+    pub pss_kb: Option<u64>,
+    pub swap_kb: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RegionSort {
+    Address,
+    Kind,
+    Size,
+    Rss,
+    Pss,
+    Swap,
+    Path,
+}
+
+impl RegionSort {
+    // Cột số mặc định sắp giảm dần (vùng lớn nhất lên đầu), cột chữ sắp tăng dần
+    pub fn default_descending(&self) -> bool {
+        matches!(self, RegionSort::Size | RegionSort::Rss | RegionSort::Pss | RegionSort::Swap)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MemoryMap {
+    pub pid: u32,
+    pub regions: Vec<MemoryRegion>,
+}
+
+impl MemoryMap {
+    pub fn read(pid: u32) -> Result<MemoryMap, String> {
+        platform::read_regions(pid).map(|regions| MemoryMap { pid, regions })
+    }
+
+    // Tổng theo loại ánh xạ, bỏ qua loại không có vùng nào
+    pub fn summary(&self) -> Vec<RegionSummary> {
+        RegionKind::ALL
+            .into_iter()
+            .filter_map(|kind| {
+                let regions: Vec<&MemoryRegion> = self.regions.iter().filter(|r| r.kind == kind).collect();
+                if regions.is_empty() {
+                    return None;
+                }
+                Some(RegionSummary {
+                    kind,
+                    regions: regions.len(),
+                    size_kb: regions.iter().map(|r| r.size_kb).sum(),
+                    rss_kb: regions.iter().map(|r| r.rss_kb).sum(),
+                    pss_kb: regions.iter().map(|r| r.pss_kb).sum(),
+                    swap_kb: regions.iter().map(|r| r.swap_kb).sum(),
+                })
+            })
+            .collect()
+    }
+
+    pub fn sort(&mut self, by: RegionSort, descending: bool) {
+        self.regions.sort_by(|a, b| {
+            let ordering = match by {
+                RegionSort::Address => a.start.cmp(&b.start),
+                RegionSort::Kind => a.kind.cmp(&b.kind),
+                RegionSort::Size => a.size_kb.cmp(&b.size_kb),
+                RegionSort::Rss => a.rss_kb.cmp(&b.rss_kb),
+                RegionSort::Pss => a.pss_kb.cmp(&b.pss_kb),
+                RegionSort::Swap => a.swap_kb.cmp(&b.swap_kb),
+                RegionSort::Path => a.path.cmp(&b.path),
+            };
+            let ordering = if descending { ordering.reverse() } else { ordering };
+            // Cùng giá trị thì giữ thứ tự theo địa chỉ cho bảng không nhảy khi đọc lại
+            match ordering {
+                Ordering::Equal => a.start.cmp(&b.start),
+                other => other,
+            }
+        })
+    }
+}
+
+pub fn format_kb(kb: u64) -> String {
+    if kb >= 1024 * 1024 {
+        format!("{:.1} GB", kb as f64 / (1024.0 * 1024.0))
+    } else if kb >= 1024 {
+        format!("{:.1} MB", kb as f64 / 1024.0)
+    } else {
+        format!("{} kB", kb)
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::fs;
+    use super::{MemoryRegion, RegionKind};
+    use crate::i18n::t;
+
+    pub fn read_regions(pid: u32) -> Result<Vec<MemoryRegion>, String> {
+        let path = format!("/proc/{}/smaps", pid);
+        let text = fs::read_to_string(&path)
+            .map_err(|e| t!("err-read-file", path = path.as_str(), error = e.to_string()))?;
+        Ok(parse_smaps(&text))
+    }
+
+    // Mỗi vùng bắt đầu bằng dòng `start-end perms offset dev inode [path]`,
+    // theo sau là các dòng `Key: value kB`
+    pub fn parse_smaps(text: &str) -> Vec<MemoryRegion> {
+        let mut regions: Vec<MemoryRegion> = Vec::new();
+        for line in text.lines() {
+            if let Some(region) = parse_header(line) {
+                regions.push(region);
+                continue;
+            }
+            let (Some(region), Some((key, value))) = (regions.last_mut(), line.split_once(':')) else {
+                continue;
+            };
+            let Some(kb) = value.trim().strip_suffix("kB").and_then(|v| v.trim().parse::<u64>().ok()) else {
+                continue;
+            };
+            match key {
+                "Size" => region.size_kb = kb,
+                "Rss" => region.rss_kb = kb,
+                "Pss" => region.pss_kb = Some(kb),
+                "Swap" => region.swap_kb = Some(kb),
+                _ => {}
+            }
+        }
+        regions
+    }
+
+    fn parse_header(line: &str) -> Option<MemoryRegion> {
+        // Các trường cách nhau một dấu cách, trước path có thêm khoảng trắng để căn cột;
+        // path có thể chứa khoảng trắng nên để nguyên trường cuối
+        let mut fields = line.splitn(6, ' ');
+        let (start, end) = fields.next()?.split_once('-')?;
+        let start = u64::from_str_radix(start, 16).ok()?;
+        let end = u64::from_str_radix(end, 16).ok()?;
+        let permissions = fields.next()?.to_string();
+        // offset, dev, inode
+        fields.nth(2)?;
+        let path = fields.next().map(str::trim).filter(|path| !path.is_empty()).map(str::to_string);
+
+        Some(MemoryRegion {
+            start,
+            size_kb: end.saturating_sub(start) / 1024,
+            kind: classify(path.as_deref().unwrap_or("")),
+            permissions,
+            path,
+            rss_kb: 0,
+            pss_kb: None,
+            swap_kb: None,
+        })
+    }
+
+    fn classify(path: &str) -> RegionKind {
+        match path {
+            "" => RegionKind::Anonymous,
+            "[heap]" => RegionKind::Heap,
+            p if p.starts_with("[stack") => RegionKind::Stack,
+            p if p.starts_with("[anon") => RegionKind::Anonymous,
+            p if p.starts_with('[') => RegionKind::Other,
+            p if is_shared_library(p) => RegionKind::SharedLibrary,
+            _ => RegionKind::FileBacked,
+        }
+    }
+
+    // libc.so.6, libfoo.so, ld-linux-x86-64.so.2
+    fn is_shared_library(path: &str) -> bool {
+        let name = path.rsplit('/').next().unwrap_or(path);
+        name.match_indices(".so")
+            .any(|(i, _)| matches!(name[i + 3..].chars().next(), None | Some('.') | Some(' ')))
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::collections::HashSet;
+    use std::ffi::c_void;
+    use std::mem;
+    use windows::Win32::Foundation::{CloseHandle, HANDLE};
+    use windows::Win32::System::Diagnostics::ToolHelp::{
+        CreateToolhelp32Snapshot, Heap32ListFirst, Heap32ListNext, HEAPLIST32, TH32CS_SNAPHEAPLIST,
+    };
+    use windows::Win32::System::Memory::{
+        VirtualQueryEx, MEMORY_BASIC_INFORMATION, MEM_COMMIT, MEM_IMAGE, MEM_MAPPED, MEM_PRIVATE, PAGE_GUARD,
+    };
+    use windows::Win32::System::ProcessStatus::{GetMappedFileNameW, QueryWorkingSet};
+    use windows::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_VM_READ};
+    use super::{MemoryRegion, RegionKind};
+    use crate::i18n::t;
+
+    // x86/x64 đều dùng page 4 KB cho working set
+    const PAGE_SIZE: u64 = 4096;
+
+    struct WorkingSetPage {
+        address: u64,
+        share_count: u64,
+        shared: bool,
+    }
+
+    pub fn read_regions(pid: u32) -> Result<Vec<MemoryRegion>, String> {
+        unsafe {
+            let handle = OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, false, pid)
+                .map_err(|e| t!("err-open-process", error = format!("{:?}", e)))?;
+            let result = query_working_set(handle).map(|pages| walk_regions(handle, pid, &pages));
+            let _ = CloseHandle(handle);
+            result
+        }
+    }
+
+    // Duyệt toàn bộ không gian địa chỉ bằng VirtualQueryEx, chỉ giữ các vùng đã commit
+    unsafe fn walk_regions(handle: HANDLE, pid: u32, pages: &[WorkingSetPage]) -> Vec<MemoryRegion> {
+        let mut infos = Vec::new();
+        let mut address = 0usize;
+        loop {
+            let mut info = MEMORY_BASIC_INFORMATION::default();
+            let written = VirtualQueryEx(
+                handle,
+                Some(address as *const c_void),
+                &mut info,
+                mem::size_of::<MEMORY_BASIC_INFORMATION>(),
+            );
+            if written == 0 || info.RegionSize == 0 {
+                break;
+            }
+            address = info.BaseAddress as usize + info.RegionSize;
+            infos.push(info);
+        }
+
+        // Stack của mỗi thread có guard page nằm trong cùng allocation
+        let stacks: HashSet<usize> = infos
+            .iter()
+            .filter(|i| i.Type == MEM_PRIVATE && i.Protect.0 & PAGE_GUARD.0 != 0)
+            .map(|i| i.AllocationBase as usize)
+            .collect();
+        let heaps = heap_bases(pid);
+
+        infos
+            .iter()
+            .filter(|i| i.State == MEM_COMMIT)
+            .map(|info| {
+                let start = info.BaseAddress as u64;
+                let end = start + info.RegionSize as u64;
+                let base = info.AllocationBase as usize;
+                let path = if info.Type == MEM_IMAGE || info.Type == MEM_MAPPED {
+                    mapped_file_name(handle, info.BaseAddress)
+                } else {
+                    None
+                };
+                let kind = if info.Type == MEM_IMAGE {
+                    match &path {
+                        Some(p) if p.to_lowercase().ends_with(".dll") => RegionKind::SharedLibrary,
+                        _ => RegionKind::FileBacked,
+                    }
+                } else if info.Type == MEM_MAPPED {
+                    if path.is_some() { RegionKind::FileBacked } else { RegionKind::Anonymous }
+                } else if heaps.contains(&base) {
+                    RegionKind::Heap
+                } else if stacks.contains(&base) {
+                    RegionKind::Stack
+                } else {
+                    RegionKind::Anonymous
+                };
+
+                let from = pages.partition_point(|p| p.address < start);
+                let to = pages.partition_point(|p| p.address < end);
+                let resident = &pages[from..to];
+                let pss_bytes: u64 = resident
+                    .iter()
+                    .map(|p| if p.shared { PAGE_SIZE / p.share_count.max(1) } else { PAGE_SIZE })
+                    .sum();
+
+                MemoryRegion {
+                    start,
+                    size_kb: info.RegionSize as u64 / 1024,
+                    kind,
+                    permissions: permissions(info),
+                    path,
+                    rss_kb: resident.len() as u64 * PAGE_SIZE / 1024,
+                    pss_kb: Some(pss_bytes / 1024),
+                    swap_kb: None,
+                }
+            })
+            .collect()
+    }
+
+    // Buffer có dạng PSAPI_WORKING_SET_INFORMATION: NumberOfEntries rồi tới từng block,
+    // block: bit 0-4 protection, 5-7 share count, 8 shared, 12+ địa chỉ page
+    unsafe fn query_working_set(handle: HANDLE) -> Result<Vec<WorkingSetPage>, String> {
+        let mut buffer = vec![0usize; 64 * 1024];
+        loop {
+            let bytes = (buffer.len() * mem::size_of::<usize>()) as u32;
+            match QueryWorkingSet(handle, buffer.as_mut_ptr() as *mut c_void, bytes) {
+                Ok(()) => break,
+                // Working set lớn hơn buffer: NumberOfEntries cho biết cần bao nhiêu, cộng thêm chỗ vì nó còn thay đổi
+                Err(_) if buffer[0] + 1 > buffer.len() => buffer = vec![0usize; buffer[0] + 1 + 4096],
+                Err(e) => return Err(t!("err-query-working-set", error = format!("{:?}", e))),
+            }
+        }
+
+        let count = buffer[0].min(buffer.len() - 1);
+        let mut pages: Vec<WorkingSetPage> = buffer[1..=count]
+            .iter()
+            .map(|&flags| WorkingSetPage {
+                address: (flags & !0xFFF) as u64,
+                share_count: ((flags >> 5) & 0x7) as u64,
+                shared: (flags >> 8) & 0x1 != 0,
+            })
+            .collect();
+        pages.sort_by_key(|p| p.address);
+        Ok(pages)
+    }
+
+    // Heap ID trong Toolhelp chính là địa chỉ gốc của heap; đọc lỗi thì coi như không có heap
+    unsafe fn heap_bases(pid: u32) -> HashSet<usize> {
+        let mut bases = HashSet::new();
+        let Ok(snapshot) = CreateToolhelp32Snapshot(TH32CS_SNAPHEAPLIST, pid) else {
+            return bases;
+        };
+        let mut entry = HEAPLIST32 {
+            dwSize: mem::size_of::<HEAPLIST32>(),
+            ..Default::default()
+        };
+        if Heap32ListFirst(snapshot, &mut entry).is_ok() {
+            loop {
+                bases.insert(entry.th32HeapID);
+                if Heap32ListNext(snapshot, &mut entry).is_err() {
+                    break;
+                }
+            }
+        }
+        let _ = CloseHandle(snapshot);
+        bases
+    }
+
+    unsafe fn mapped_file_name(handle: HANDLE, address: *mut c_void) -> Option<String> {
+        let mut buffer = [0u16; 1024];
+        let len = GetMappedFileNameW(handle, address, &mut buffer) as usize;
+        (len > 0).then(|| String::from_utf16_lossy(&buffer[..len]))
+    }
+
+    // Hiển thị giống cột quyền của smaps: rwx + p (private) / s (shared)
+    fn permissions(info: &MEMORY_BASIC_INFORMATION) -> String {
+        let rwx = match info.Protect.0 & 0xFF {
+            0x02 => "r--",
+            0x04 | 0x08 => "rw-",
+            0x10 => "--x",
+            0x20 => "r-x",
+            0x40 | 0x80 => "rwx",
+            _ => "---",
+        };
+        let sharing = if info.Type == MEM_PRIVATE { 'p' } else { 's' };
+        format!("{}{}", rwx, sharing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn parse_smaps_reads_regions_and_classifies_paths() {
+        use super::platform::parse_smaps;
+        use super::RegionKind;
+
+        // Trích từ /proc/<pid>/smaps thật, bỏ bớt các dòng không dùng
+        let smaps = "\
+55d4c2a00000-55d4c2c21000 rw-p 00000000 00:00 0                          [heap]
+Size:               2180 kB
+Rss:                1964 kB
+Pss:                1964 kB
+Swap:                  0 kB
+7f3a1c000000-7f3a1c400000 rw-p 00000000 00:00 0 
+Size:               4096 kB
+Rss:                 812 kB
+Pss:                 812 kB
+Swap:                128 kB
+7f3a1e428000-7f3a1e450000 r--p 00000000 103:02 1836710                   /usr/lib/x86_64-linux-gnu/libc.so.6
+Size:                160 kB
+Rss:                 160 kB
+Pss:                   6 kB
+Swap:                  0 kB
+VmFlags: rd mr mw me sd 
+7f3a1f000000-7f3a1f001000 r--s 00000000 103:02 2621443                   /home/user/My Documents/cache 0.bin (deleted)
+Size:                  4 kB
+Rss:                   4 kB
+7ffd5e3f0000-7ffd5e411000 rw-p 00000000 00:00 0                          [stack]
+Size:                132 kB
+Rss:                  24 kB
+";
+        let regions = parse_smaps(smaps);
+        assert_eq!(regions.len(), 5);
+
+        let heap = &regions[0];
+        assert_eq!(heap.kind, RegionKind::Heap);
+        assert_eq!(heap.start, 0x55d4c2a00000);
+        assert_eq!(heap.permissions, "rw-p");
+        assert_eq!(heap.path.as_deref(), Some("[heap]"));
+        assert_eq!((heap.size_kb, heap.rss_kb, heap.pss_kb, heap.swap_kb), (2180, 1964, Some(1964), Some(0)));
+
+        let anonymous = &regions[1];
+        assert_eq!(anonymous.kind, RegionKind::Anonymous);
+        assert_eq!(anonymous.path, None);
+        assert_eq!((anonymous.rss_kb, anonymous.swap_kb), (812, Some(128)));
+
+        let libc = &regions[2];
+        assert_eq!(libc.kind, RegionKind::SharedLibrary);
+        assert_eq!(libc.path.as_deref(), Some("/usr/lib/x86_64-linux-gnu/libc.so.6"));
+        assert_eq!(libc.pss_kb, Some(6));
+
+        // Đường dẫn có khoảng trắng được giữ nguyên
+        let spaced = &regions[3];
+        assert_eq!(spaced.kind, RegionKind::FileBacked);
+        assert_eq!(spaced.path.as_deref(), Some("/home/user/My Documents/cache 0.bin (deleted)"));
+        assert_eq!((spaced.size_kb, spaced.pss_kb, spaced.swap_kb), (4, None, None));
+
+        assert_eq!(regions[4].kind, RegionKind::Stack);
+    }
+}
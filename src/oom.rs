@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+use crate::i18n::t;
+
+// Giới hạn của /proc/<pid>/oom_score_adj: -1000 = không bao giờ bị OOM killer chọn, 1000 = bị chọn đầu tiên
+pub const SCORE_ADJ_MIN: i32 = -1000;
+pub const SCORE_ADJ_MAX: i32 = 1000;
+
+// Đổi oom_score_adj khi Pin/Limit trên Linux; giá trị cũ được trả lại khi Restore
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OomConfig {
+    pub protect_pinned: bool,
+    pub pinned_score_adj: i32,
+    pub expose_limited: bool,
+    pub limited_score_adj: i32,
+}
+
+impl Default for OomConfig {
+    fn default() -> Self {
+        // Không dùng -1000: tiến trình bị Pin mà rò rỉ bộ nhớ thì OOM killer vẫn phải giết được
+        OomConfig {
+            protect_pinned: true,
+            pinned_score_adj: -900,
+            expose_limited: true,
+            limited_score_adj: 500,
+        }
+    }
+}
+
+impl OomConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        for value in [self.pinned_score_adj, self.limited_score_adj] {
+            if !(SCORE_ADJ_MIN..=SCORE_ADJ_MAX).contains(&value) {
+                return Err(t!(
+                    "cfg-oom-score-adj",
+                    value = value.to_string(),
+                    min = SCORE_ADJ_MIN.to_string(),
+                    max = SCORE_ADJ_MAX.to_string()
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OomScore {
+    // Điểm kernel dùng để chọn tiến trình bị giết (0-2000), đã tính cả adj
+    pub score: i32,
+    pub adj: i32,
+}
+
+// None trên Windows hoặc khi tiến trình đã thoát
+pub fn read(pid: u32) -> Option<OomScore> {
+    platform::read(pid)
+}
+
+pub fn score_adj(pid: u32) -> Result<i32, String> {
+    platform::score_adj(pid)
+}
+
+// Hạ adj xuống dưới giá trị nhỏ nhất từng đặt cho tiến trình cần CAP_SYS_RESOURCE, tăng thì không
+pub fn set_score_adj(pid: u32, value: i32) -> Result<(), String> {
+    platform::set_score_adj(pid, value)
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::fs;
+    use super::OomScore;
+    use crate::i18n::t;
+
+    fn read_value(pid: u32, file: &str) -> Result<i32, String> {
+        let path = format!("/proc/{}/{}", pid, file);
+        let text = fs::read_to_string(&path).map_err(|e| t!("err-read-file", path = path.as_str(), error = e.to_string()))?;
+        text.trim()
+            .parse()
+            .map_err(|_| t!("err-read-file", path = path.as_str(), error = text.trim()))
+    }
+
+    pub fn read(pid: u32) -> Option<OomScore> {
+        Some(OomScore {
+            score: read_value(pid, "oom_score").ok()?,
+            adj: read_value(pid, "oom_score_adj").ok()?,
+        })
+    }
+
+    pub fn score_adj(pid: u32) -> Result<i32, String> {
+        read_value(pid, "oom_score_adj")
+    }
+
+    pub fn set_score_adj(pid: u32, value: i32) -> Result<(), String> {
+        let path = format!("/proc/{}/oom_score_adj", pid);
+        fs::write(&path, value.to_string()).map_err(|e| t!("err-oom-score-adj", pid = pid, error = e.to_string()))
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod platform {
+    use super::OomScore;
+    use crate::i18n::t;
+
+    pub fn read(_pid: u32) -> Option<OomScore> {
+        None
+    }
+
+    pub fn score_adj(_pid: u32) -> Result<i32, String> {
+        Err(t!("err-oom-unsupported"))
+    }
+
+    pub fn set_score_adj(_pid: u32, _value: i32) -> Result<(), String> {
+        Err(t!("err-oom-unsupported"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::i18n::t;
+    use super::{OomConfig, SCORE_ADJ_MAX, SCORE_ADJ_MIN};
+
+    #[test]
+    fn validate_accepts_the_kernel_range() {
+        assert!(OomConfig::default().validate().is_ok());
+        let edges = OomConfig { pinned_score_adj: SCORE_ADJ_MIN, limited_score_adj: SCORE_ADJ_MAX, ..OomConfig::default() };
+        assert!(edges.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_values_outside_the_kernel_range() {
+        let error = |value: i32| {
+            t!("cfg-oom-score-adj", value = value.to_string(), min = SCORE_ADJ_MIN.to_string(), max = SCORE_ADJ_MAX.to_string())
+        };
+        let pinned = OomConfig { pinned_score_adj: -1001, ..OomConfig::default() };
+        assert_eq!(pinned.validate(), Err(error(-1001)));
+        let limited = OomConfig { limited_score_adj: 1001, ..OomConfig::default() };
+        assert_eq!(limited.validate(), Err(error(1001)));
+    }
+
+    // Tiến trình con riêng để không đổi oom_score_adj của chính tiến trình test
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn score_adj_can_be_saved_raised_and_restored() {
+        use super::{read, score_adj, set_score_adj};
+
+        let mut child = std::process::Command::new("sleep").arg("30").spawn().unwrap();
+        let pid = child.id();
+        let original = score_adj(pid).unwrap();
+        let raised = (original + 100).min(SCORE_ADJ_MAX);
+        set_score_adj(pid, raised).unwrap();
+        assert_eq!(read(pid).map(|score| score.adj), Some(raised));
+        // Về lại giá trị gốc không cần quyền vì không thấp hơn giá trị được thừa hưởng
+        set_score_adj(pid, original).unwrap();
+        assert_eq!(score_adj(pid), Ok(original));
+
+        let _ = child.kill();
+        let _ = child.wait();
+        assert!(read(pid).is_none());
+    }
+}
@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+// Số page fault tích luỹ của một tiến trình; Windows chỉ có tổng (gồm cả soft fault) nên major là None
+#[derive(Clone, Copy, Debug)]
+pub struct FaultCount {
+    pub total: u64,
+    pub major: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FaultRates {
+    pub faults_per_sec: f64,
+    pub major_per_sec: Option<f64>,
+}
+
+// Số fault của lần sample trước cho từng PID để tính tốc độ, thuộc về RamManager
+pub struct FaultTracker {
+    previous: HashMap<u32, (Instant, FaultCount)>,
+}
+
+impl FaultTracker {
+    pub fn new() -> Self {
+        FaultTracker {
+            previous: HashMap::new(),
+        }
+    }
+
+    // None ở lần đầu thấy PID (chưa có mốc) hoặc khi không đọc được counter
+    pub fn sample(&mut self, pid: u32, now: Instant) -> Option<FaultRates> {
+        let current = platform::process_faults(pid)?;
+        self.record(pid, now, current)
+    }
+
+    // Counter giảm nghĩa là PID đã được dùng lại cho tiến trình khác: lấy làm mốc mới
+    fn record(&mut self, pid: u32, now: Instant, current: FaultCount) -> Option<FaultRates> {
+        let (at, before) = self.previous.insert(pid, (now, current))?;
+        let secs = now.duration_since(at).as_secs_f64();
+        if secs <= 0.0 || current.total < before.total {
+            return None;
+        }
+        Some(FaultRates {
+            faults_per_sec: (current.total - before.total) as f64 / secs,
+            major_per_sec: current
+                .major
+                .zip(before.major)
+                .map(|(now, before)| now.saturating_sub(before) as f64 / secs),
+        })
+    }
+
+    // Bỏ mốc của các PID đã thoát để PID dùng lại không bị tính nhầm
+    pub fn retain(&mut self, alive: impl Fn(u32) -> bool) {
+        self.previous.retain(|pid, _| alive(*pid));
+    }
+}
+
+// Counter tích luỹ hiện tại, dùng khi cần so sánh hai thời điểm xa nhau (VD: đo hiệu quả hành động)
+pub fn process_faults(pid: u32) -> Option<FaultCount> {
+    platform::process_faults(pid)
+}
+
+// Page fault và tốc độ kernel quét/thu hồi page trên toàn hệ thống
+#[derive(Clone, Debug, Default)]
+pub struct SystemFaultRates {
+    pub faults_per_sec: Option<f64>,
+    pub major_per_sec: Option<f64>,
+    pub pgscan_per_sec: Option<f64>,
+    pub pgsteal_per_sec: Option<f64>,
+}
+
+pub struct SystemFaultMonitor {
+    counters: platform::SystemCounters,
+}
+
+impl SystemFaultMonitor {
+    pub fn new() -> Self {
+        SystemFaultMonitor {
+            counters: platform::SystemCounters::new(),
+        }
+    }
+
+    pub fn sample(&mut self) -> SystemFaultRates {
+        self.counters.sample()
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::collections::HashMap;
+    use std::fs;
+    use std::time::Instant;
+    use super::{FaultCount, SystemFaultRates};
+    use crate::system_memory;
+
+    pub fn process_faults(pid: u32) -> Option<FaultCount> {
+        parse_stat(&fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?)
+    }
+
+    // Sau tên tiến trình trong ngoặc: state ppid pgrp session tty_nr tpgid flags minflt cminflt majflt ...
+    // Tên có thể chứa `)` và khoảng trắng nên tách ở dấu `)` cuối cùng
+    pub fn parse_stat(text: &str) -> Option<FaultCount> {
+        let (_, fields) = text.rsplit_once(')')?;
+        let fields: Vec<&str> = fields.split_whitespace().collect();
+        let minor: u64 = fields.get(7)?.parse().ok()?;
+        let major: u64 = fields.get(9)?.parse().ok()?;
+        Some(FaultCount {
+            total: minor + major,
+            major: Some(major),
+        })
+    }
+
+    const FAULTS: &[&str] = &["pgfault"];
+    const MAJOR: &[&str] = &["pgmajfault"];
+    const PGSCAN: &[&str] = &["pgscan_kswapd", "pgscan_direct", "pgscan_khugepaged"];
+    const PGSTEAL: &[&str] = &["pgsteal_kswapd", "pgsteal_direct", "pgsteal_khugepaged"];
+
+    pub struct SystemCounters {
+        previous: Option<(Instant, HashMap<String, u64>)>,
+    }
+
+    impl SystemCounters {
+        pub fn new() -> Self {
+            SystemCounters { previous: None }
+        }
+
+        pub fn sample(&mut self) -> SystemFaultRates {
+            let Some(current) = system_memory::read_vmstat() else {
+                return SystemFaultRates::default();
+            };
+            let now = Instant::now();
+            let Some((at, before)) = self.previous.replace((now, current.clone())) else {
+                return SystemFaultRates::default();
+            };
+            let secs = now.duration_since(at).as_secs_f64();
+            // Tổng các counter cùng nhóm (kswapd + direct...), None nếu kernel không có counter nào
+            let rate = |names: &[&str]| {
+                let sum = |values: &HashMap<String, u64>| -> Option<u64> {
+                    names.iter().filter_map(|name| values.get(*name)).copied().reduce(|a, b| a + b)
+                };
+                let delta = sum(&current)?.saturating_sub(sum(&before)?);
+                (secs > 0.0).then(|| delta as f64 / secs)
+            };
+            SystemFaultRates {
+                faults_per_sec: rate(FAULTS),
+                major_per_sec: rate(MAJOR),
+                pgscan_per_sec: rate(PGSCAN),
+                pgsteal_per_sec: rate(PGSTEAL),
+            }
+        }
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::mem;
+    use windows::core::w;
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::ProcessStatus::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS};
+    use windows::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION};
+    use super::{FaultCount, SystemFaultRates};
+    use crate::pdh::PdhCounters;
+
+    // PageFaultCount của PROCESS_MEMORY_COUNTERS gồm cả soft fault lẫn hard fault
+    pub fn process_faults(pid: u32) -> Option<FaultCount> {
+        unsafe {
+            let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+            let mut pmc = PROCESS_MEMORY_COUNTERS::default();
+            let result = GetProcessMemoryInfo(handle, &mut pmc, mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32);
+            let _ = CloseHandle(handle);
+            result.ok()?;
+            Some(FaultCount {
+                total: pmc.PageFaultCount as u64,
+                major: None,
+            })
+        }
+    }
+
+    // "Page Reads/sec" là số lần đọc đĩa để xử lý hard fault, gần với major fault của Linux.
+    // Windows không có counter tương ứng pgscan/pgsteal
+    pub struct SystemCounters {
+        pdh: Option<PdhCounters>,
+    }
+
+    impl SystemCounters {
+        pub fn new() -> Self {
+            SystemCounters {
+                pdh: PdhCounters::open(&[w!("\\Memory\\Page Faults/sec"), w!("\\Memory\\Page Reads/sec")]),
+            }
+        }
+
+        pub fn sample(&mut self) -> SystemFaultRates {
+            let Some(values) = self.pdh.as_mut().and_then(|pdh| pdh.sample()) else {
+                return SystemFaultRates::default();
+            };
+            SystemFaultRates {
+                faults_per_sec: values[0],
+                major_per_sec: values[1],
+                pgscan_per_sec: None,
+                pgsteal_per_sec: None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+    use super::{FaultCount, FaultTracker};
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn parse_stat_handles_names_with_parentheses_and_spaces() {
+        use super::platform::parse_stat;
+
+        let stat = "4242 (Web Content (x) ) S 4200 4200 4200 0 -1 4194560 15230 87 412 3 1200 350 0 0 20 0 31 0 98765 3221225472 52000 18446744073709551615";
+        let faults = parse_stat(stat).unwrap();
+        assert_eq!(faults.major, Some(412));
+        assert_eq!(faults.total, 15230 + 412);
+        assert!(parse_stat("4242 (truncated) S 1").is_none());
+    }
+
+    #[test]
+    fn tracker_computes_rates_between_samples() {
+        let mut tracker = FaultTracker::new();
+        let start = Instant::now();
+        let count = |total: u64, major: u64| FaultCount { total, major: Some(major) };
+
+        // Lần đầu chưa có mốc
+        assert!(tracker.record(1, start, count(1000, 10)).is_none());
+        let rates = tracker.record(1, start + Duration::from_secs(2), count(1600, 30)).unwrap();
+        assert_eq!(rates.faults_per_sec, 300.0);
+        assert_eq!(rates.major_per_sec, Some(10.0));
+
+        // Windows không có major fault
+        assert!(tracker.record(2, start, FaultCount { total: 50, major: None }).is_none());
+        let rates = tracker.record(2, start + Duration::from_secs(1), FaultCount { total: 80, major: None }).unwrap();
+        assert_eq!((rates.faults_per_sec, rates.major_per_sec), (30.0, None));
+    }
+
+    #[test]
+    fn tracker_restarts_when_a_pid_is_reused() {
+        let mut tracker = FaultTracker::new();
+        let start = Instant::now();
+        let count = |total: u64| FaultCount { total, major: Some(0) };
+
+        assert!(tracker.record(7, start, count(90_000)).is_none());
+        // Tiến trình mới cùng PID có counter nhỏ hơn: không báo tốc độ, lấy làm mốc mới
+        assert!(tracker.record(7, start + Duration::from_secs(1), count(200)).is_none());
+        let rates = tracker.record(7, start + Duration::from_secs(2), count(700)).unwrap();
+        assert_eq!(rates.faults_per_sec, 500.0);
+
+        tracker.retain(|pid| pid != 7);
+        assert!(tracker.record(7, start + Duration::from_secs(3), count(800)).is_none());
+    }
+}
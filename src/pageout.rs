@@ -0,0 +1,284 @@
+use serde::{Deserialize, Serialize};
+use crate::i18n::t;
+use crate::memory_map::{MemoryMap, RegionKind, RegionSummary};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PageoutHint {
+    // MADV_COLD: chỉ đánh dấu để kernel reclaim trước khi thiếu RAM
+    Cold,
+    // MADV_PAGEOUT: reclaim ngay (ghi ra swap / bỏ page cache), gần với EmptyWorkingSet
+    Pageout,
+}
+
+impl PageoutHint {
+    pub const ALL: [PageoutHint; 2] = [PageoutHint::Cold, PageoutHint::Pageout];
+
+    pub fn label(&self) -> String {
+        match self {
+            PageoutHint::Cold => t!("pageout-hint-cold"),
+            PageoutHint::Pageout => t!("pageout-hint-pageout"),
+        }
+    }
+}
+
+// Cách Trim hoạt động trên Linux: gửi gợi ý process_madvise cho các vùng nhớ thuộc loại đã chọn
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PageoutConfig {
+    pub hint: PageoutHint,
+    pub regions: Vec<RegionKind>,
+}
+
+impl Default for PageoutConfig {
+    fn default() -> Self {
+        // Thư viện dùng chung và stack thường bị nạp lại ngay nên mặc định không đụng tới
+        PageoutConfig {
+            hint: PageoutHint::Pageout,
+            regions: vec![RegionKind::Heap, RegionKind::Anonymous, RegionKind::FileBacked],
+        }
+    }
+}
+
+impl PageoutConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.regions.is_empty() {
+            return Err(t!("cfg-pageout-regions"));
+        }
+        Ok(())
+    }
+}
+
+// Một dòng của báo cáo, tính bằng kB
+#[derive(Clone, Debug)]
+pub struct PageoutRow {
+    pub kind: RegionKind,
+    pub rss_before_kb: u64,
+    pub rss_after_kb: u64,
+    pub swap_before_kb: Option<u64>,
+    pub swap_after_kb: Option<u64>,
+}
+
+// RSS/swap theo loại vùng nhớ trước và sau khi page-out
+#[derive(Clone, Debug)]
+pub struct PageoutReport {
+    pub pid: u32,
+    pub hint: PageoutHint,
+    pub regions: Vec<RegionKind>,
+    pub before: Vec<RegionSummary>,
+    pub after: Vec<RegionSummary>,
+}
+
+impl PageoutReport {
+    pub fn new(pid: u32, config: &PageoutConfig, before: &MemoryMap, after: &MemoryMap) -> PageoutReport {
+        PageoutReport {
+            pid,
+            hint: config.hint,
+            regions: config.regions.clone(),
+            before: before.summary(),
+            after: after.summary(),
+        }
+    }
+
+    // Chỉ các loại vùng nhớ đã chọn
+    pub fn rows(&self) -> Vec<PageoutRow> {
+        let find = |summaries: &[RegionSummary], kind: RegionKind| summaries.iter().find(|s| s.kind == kind).cloned();
+        RegionKind::ALL
+            .into_iter()
+            .filter(|kind| self.regions.contains(kind))
+            .filter_map(|kind| {
+                let before = find(&self.before, kind);
+                let after = find(&self.after, kind);
+                if before.is_none() && after.is_none() {
+                    return None;
+                }
+                Some(PageoutRow {
+                    kind,
+                    rss_before_kb: before.as_ref().map_or(0, |s| s.rss_kb),
+                    rss_after_kb: after.as_ref().map_or(0, |s| s.rss_kb),
+                    swap_before_kb: before.and_then(|s| s.swap_kb),
+                    swap_after_kb: after.and_then(|s| s.swap_kb),
+                })
+            })
+            .collect()
+    }
+
+    pub fn freed_kb(&self) -> u64 {
+        self.rows().iter().map(|row| row.rss_before_kb.saturating_sub(row.rss_after_kb)).sum()
+    }
+}
+
+// Chạy với quyền của tiến trình gọi (in-process hoặc helper): cần CAP_SYS_NICE và quyền ptrace lên tiến trình đích
+pub fn advise(pid: u32, hint: PageoutHint, regions: &[RegionKind]) -> Result<(), String> {
+    platform::advise(pid, hint, regions)
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::io;
+    use super::PageoutHint;
+    use crate::i18n::t;
+    use crate::memory_map::{MemoryMap, MemoryRegion, RegionKind};
+
+    // pidfd mở trước khi đọc smaps: nếu PID bị tái sử dụng giữa hai bước thì pidfd vẫn trỏ tới tiến trình cũ,
+    // pidfd_send_signal(0) sau khi đọc sẽ báo ESRCH thay vì madvise nhầm vào tiến trình mới
+    pub fn advise(pid: u32, hint: PageoutHint, regions: &[RegionKind]) -> Result<(), String> {
+        let pidfd = unsafe { libc::syscall(libc::SYS_pidfd_open, pid as libc::pid_t, 0u32) };
+        if pidfd < 0 {
+            return Err(t!("err-pidfd-open", pid = pid, error = io::Error::last_os_error().to_string()));
+        }
+        let pidfd = pidfd as libc::c_int;
+        let result = advise_pidfd(pidfd, pid, hint, regions);
+        unsafe { libc::close(pidfd) };
+        result
+    }
+
+    // Vùng thuộc loại đã chọn và còn page trong RAM; vùng RSS = 0 không có gì để đẩy ra
+    pub fn selected<'a>(map: &'a MemoryMap, regions: &[RegionKind]) -> Vec<&'a MemoryRegion> {
+        map.regions.iter().filter(|r| regions.contains(&r.kind) && r.rss_kb > 0).collect()
+    }
+
+    pub fn advice(hint: PageoutHint) -> libc::c_int {
+        match hint {
+            PageoutHint::Cold => libc::MADV_COLD,
+            PageoutHint::Pageout => libc::MADV_PAGEOUT,
+        }
+    }
+
+    fn advise_pidfd(pidfd: libc::c_int, pid: u32, hint: PageoutHint, regions: &[RegionKind]) -> Result<(), String> {
+        let map = MemoryMap::read(pid)?;
+        let ranges: Vec<libc::iovec> = selected(&map, regions)
+            .into_iter()
+            .map(|r| libc::iovec {
+                iov_base: r.start as *mut libc::c_void,
+                iov_len: (r.size_kb * 1024) as usize,
+            })
+            .collect();
+        if ranges.is_empty() {
+            return Ok(());
+        }
+        let alive = unsafe {
+            libc::syscall(libc::SYS_pidfd_send_signal, pidfd, 0, std::ptr::null::<libc::siginfo_t>(), 0u32)
+        };
+        if alive != 0 {
+            return Err(t!("err-pidfd-gone", pid = pid, error = io::Error::last_os_error().to_string()));
+        }
+
+        let advice = advice(hint);
+
+        // Mỗi vùng một lệnh: kernel dừng ở vùng lỗi đầu tiên (VD: vùng vừa bị unmap) nên gộp nhiều vùng sẽ bỏ sót phần sau
+        let mut advised = 0;
+        let mut last_error = None;
+        for range in ranges.iter() {
+            let result = unsafe { libc::syscall(libc::SYS_process_madvise, pidfd, range as *const libc::iovec, 1usize, advice, 0u32) };
+            if result >= 0 {
+                advised += 1;
+                continue;
+            }
+            let error = io::Error::last_os_error();
+            match error.raw_os_error() {
+                // Kernel cũ hơn 5.10 hoặc thiếu quyền: vùng nào cũng sẽ lỗi như nhau
+                Some(libc::ENOSYS) | Some(libc::EPERM) => {
+                    last_error = Some(error);
+                    break;
+                }
+                _ => last_error = Some(error),
+            }
+        }
+        match last_error {
+            Some(error) if advised == 0 => Err(match error.raw_os_error() {
+                Some(libc::ENOSYS) => t!("err-process-madvise-unsupported"),
+                _ => t!("err-process-madvise", pid = pid, error = error.to_string()),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod platform {
+    use super::PageoutHint;
+    use crate::i18n::t;
+    use crate::memory_map::RegionKind;
+
+    pub fn advise(_pid: u32, _hint: PageoutHint, _regions: &[RegionKind]) -> Result<(), String> {
+        Err(t!("err-process-madvise-unsupported"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::memory_map::{MemoryMap, MemoryRegion, RegionKind};
+    use super::{PageoutConfig, PageoutHint, PageoutReport};
+
+    fn region(start: u64, kind: RegionKind, rss_kb: u64, swap_kb: u64) -> MemoryRegion {
+        MemoryRegion {
+            start,
+            size_kb: 4096,
+            kind,
+            permissions: "rw-p".to_string(),
+            path: None,
+            rss_kb,
+            pss_kb: Some(rss_kb),
+            swap_kb: Some(swap_kb),
+        }
+    }
+
+    fn map(regions: Vec<MemoryRegion>) -> MemoryMap {
+        MemoryMap { pid: 42, regions }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn only_resident_regions_of_the_chosen_kinds_are_advised() {
+        use super::platform::{advice, selected};
+
+        let before = map(vec![
+            region(0x1000, RegionKind::Heap, 2048, 0),
+            region(0x2000, RegionKind::Anonymous, 0, 512),
+            region(0x3000, RegionKind::Anonymous, 1024, 0),
+            region(0x4000, RegionKind::SharedLibrary, 800, 0),
+            region(0x5000, RegionKind::Stack, 64, 0),
+        ]);
+        let starts = |regions: &[RegionKind]| selected(&before, regions).iter().map(|r| r.start).collect::<Vec<u64>>();
+        assert_eq!(starts(&PageoutConfig::default().regions), vec![0x1000, 0x3000]);
+        assert_eq!(starts(&[RegionKind::SharedLibrary, RegionKind::Stack]), vec![0x4000, 0x5000]);
+        assert!(starts(&[RegionKind::Other]).is_empty());
+
+        assert_eq!(advice(PageoutHint::Cold), libc::MADV_COLD);
+        assert_eq!(advice(PageoutHint::Pageout), libc::MADV_PAGEOUT);
+    }
+
+    #[test]
+    fn report_counts_only_the_chosen_kinds() {
+        let before = map(vec![
+            region(0x1000, RegionKind::Heap, 2048, 0),
+            region(0x2000, RegionKind::Anonymous, 1024, 0),
+            region(0x3000, RegionKind::Anonymous, 512, 0),
+            region(0x4000, RegionKind::SharedLibrary, 800, 0),
+        ]);
+        let after = map(vec![
+            region(0x1000, RegionKind::Heap, 48, 2000),
+            region(0x2000, RegionKind::Anonymous, 24, 1000),
+            // Vùng bị ghi lại ngay sau khi đẩy ra: RSS tăng không làm tổng âm
+            region(0x3000, RegionKind::Anonymous, 600, 0),
+            region(0x4000, RegionKind::SharedLibrary, 100, 0),
+        ]);
+        let config = PageoutConfig { hint: PageoutHint::Cold, regions: vec![RegionKind::Heap, RegionKind::Anonymous] };
+        let report = PageoutReport::new(42, &config, &before, &after);
+        assert_eq!(report.hint, PageoutHint::Cold);
+
+        let rows = report.rows();
+        assert_eq!(rows.iter().map(|row| row.kind).collect::<Vec<_>>(), vec![RegionKind::Heap, RegionKind::Anonymous]);
+        assert_eq!((rows[0].rss_before_kb, rows[0].rss_after_kb), (2048, 48));
+        assert_eq!((rows[1].swap_before_kb, rows[1].swap_after_kb), (Some(0), Some(1000)));
+        // Heap 2000 + Anonymous (1536 - 624), thư viện dùng chung không được tính
+        assert_eq!(report.freed_kb(), 2000 + 912);
+
+        // Loại không có vùng nào ở cả hai lần đọc thì không có dòng
+        let config = PageoutConfig { hint: PageoutHint::Pageout, regions: vec![RegionKind::Stack] };
+        let report = PageoutReport::new(42, &config, &before, &after);
+        assert!(report.rows().is_empty());
+        assert_eq!(report.freed_kb(), 0);
+    }
+}
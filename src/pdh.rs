@@ -0,0 +1,65 @@
+use windows::core::PCWSTR;
+use windows::Win32::System::Performance::{
+    PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData, PdhGetFormattedCounterValue, PdhOpenQueryW,
+    PDH_FMT_COUNTERVALUE, PDH_FMT_DOUBLE,
+};
+
+const ERROR_SUCCESS: u32 = 0;
+
+// Một PDH query gồm nhiều counter dạng rate (".../sec"), dùng tên tiếng Anh để chạy được trên Windows bản địa hoá
+pub struct PdhCounters {
+    query: isize,
+    counters: Vec<isize>,
+    collected: bool,
+}
+
+impl PdhCounters {
+    pub fn open(paths: &[PCWSTR]) -> Option<PdhCounters> {
+        unsafe {
+            let mut query = 0isize;
+            if PdhOpenQueryW(PCWSTR::null(), 0, &mut query) != ERROR_SUCCESS {
+                return None;
+            }
+            // Thêm counter lỗi thì PdhCounters bị drop và query được đóng lại
+            let mut pdh = PdhCounters { query, counters: Vec::new(), collected: false };
+            for path in paths {
+                let mut counter = 0isize;
+                if PdhAddEnglishCounterW(query, *path, 0, &mut counter) != ERROR_SUCCESS {
+                    return None;
+                }
+                pdh.counters.push(counter);
+            }
+            Some(pdh)
+        }
+    }
+
+    // Giá trị theo đúng thứ tự `paths`; counter rate cần 2 lần collect nên lần đầu trả về None
+    pub fn sample(&mut self) -> Option<Vec<Option<f64>>> {
+        unsafe {
+            if PdhCollectQueryData(self.query) != ERROR_SUCCESS {
+                return None;
+            }
+            if !self.collected {
+                self.collected = true;
+                return None;
+            }
+            Some(self.counters.iter().map(|&counter| counter_value(counter)).collect())
+        }
+    }
+}
+
+impl Drop for PdhCounters {
+    fn drop(&mut self) {
+        unsafe {
+            PdhCloseQuery(self.query);
+        }
+    }
+}
+
+unsafe fn counter_value(counter: isize) -> Option<f64> {
+    let mut value = PDH_FMT_COUNTERVALUE::default();
+    if PdhGetFormattedCounterValue(counter, PDH_FMT_DOUBLE, None, &mut value) != ERROR_SUCCESS {
+        return None;
+    }
+    Some(value.Anonymous.doubleValue)
+}
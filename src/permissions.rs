@@ -0,0 +1,503 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use crate::i18n::t;
+use crate::ram_manager::{Priority, ProcessAction};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Capability {
+    Nice,
+    Resource,
+    Ptrace,
+}
+
+impl Capability {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Capability::Nice => "CAP_SYS_NICE",
+            Capability::Resource => "CAP_SYS_RESOURCE",
+            Capability::Ptrace => "CAP_SYS_PTRACE",
+        }
+    }
+
+    pub fn purpose(&self) -> String {
+        match self {
+            Capability::Nice => t!("perm-cap-sys-nice"),
+            Capability::Resource => t!("perm-cap-sys-resource"),
+            Capability::Ptrace => t!("perm-cap-sys-ptrace"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CgroupFile {
+    pub name: &'static str,
+    // Không có file nghĩa là controller memory chưa được bật cho cgroup này
+    pub exists: bool,
+    pub writable: bool,
+}
+
+impl CgroupFile {
+    pub fn label(&self) -> String {
+        if !self.exists {
+            t!("perm-file-missing")
+        } else if self.writable {
+            t!("perm-file-writable")
+        } else {
+            t!("perm-file-read-only")
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CgroupAccess {
+    pub path: PathBuf,
+    // Gốc của nhánh cgroup thuộc về người dùng hiện tại (systemd Delegate=yes chown nhánh này)
+    pub delegated_root: Option<PathBuf>,
+    pub files: Vec<CgroupFile>,
+}
+
+// Cgroup riêng mà Limit/SwapLimit/Pin ghi (memory_cap tạo cạnh cgroup hiện tại của tiến trình)
+#[derive(Clone, Debug)]
+pub struct LimitCgroupAccess {
+    pub path: PathBuf,
+    pub exists: bool,
+    // Các file ghi được khi cgroup riêng đã có
+    pub writable: Vec<&'static str>,
+    // Chưa có thì phải tạo được thư mục, chuyển được tiến trình sang (cgroup.procs của cgroup cha)
+    // và cgroup cha phải bật controller memory cho cgroup con
+    pub can_create: bool,
+    // Restore trả tiến trình về cgroup gốc rồi xoá cgroup riêng; None khi không có gì để trả về
+    pub can_release: Option<bool>,
+}
+
+// Những gì cần biết về tiến trình đích để biết hành động có chạy được không
+#[derive(Clone, Debug, Default)]
+pub struct TargetAccess {
+    // UID thực, hiệu dụng và saved của tiến trình đích khi cả ba giống nhau
+    pub uid: Option<u32>,
+    pub nice: Option<i32>,
+    pub limit_cgroup: Option<LimitCgroupAccess>,
+    // cgroup.freeze ghi được (chỉ khi tiến trình ở một mình trong cgroup của nó)
+    pub freeze_cgroup: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Requirement {
+    Capability(Capability),
+    // Cùng người dùng với tiến trình đích (hoặc là root): signal, ptrace, setpriority không cần capability
+    SameUser,
+    LimitCgroup(&'static str),
+    ReleaseCgroup,
+    FreezeCgroup,
+}
+
+impl Requirement {
+    pub fn describe(&self) -> String {
+        match self {
+            Requirement::Capability(cap) => cap.name().to_string(),
+            Requirement::SameUser => t!("perm-req-same-user"),
+            Requirement::LimitCgroup(file) => t!("perm-req-cgroup", file = *file),
+            Requirement::ReleaseCgroup => t!("perm-req-cgroup-release"),
+            Requirement::FreezeCgroup => t!("perm-req-cgroup", file = "cgroup.freeze"),
+        }
+    }
+}
+
+// Mỗi hành động có một hoặc vài cách làm trên Linux, chỉ cần đủ quyền cho một cách.
+// Theo đúng các lời gọi ở privileged.rs, memory_cap.rs, freeze.rs và pageout.rs
+fn action_requirements(action: &ProcessAction, target: &TargetAccess) -> Vec<Vec<Requirement>> {
+    use Capability::*;
+    use Requirement::{Capability as Cap, FreezeCgroup, LimitCgroup, ReleaseCgroup, SameUser};
+    match action {
+        // memory.min ở cgroup riêng và setpriority(-10)
+        ProcessAction::Pin { .. } => vec![vec![LimitCgroup("memory.min"), Cap(Nice)]],
+        // process_madvise cần CAP_SYS_NICE và quyền ptrace lên tiến trình đích
+        ProcessAction::Trim => vec![vec![Cap(Nice), SameUser], vec![Cap(Nice), Cap(Ptrace)]],
+        // Đóng băng rồi Trim
+        ProcessAction::Hibernate => vec![vec![Cap(Nice), SameUser], vec![Cap(Nice), Cap(Ptrace), FreezeCgroup]],
+        ProcessAction::Limit { .. } => vec![vec![LimitCgroup("memory.high"), LimitCgroup("memory.max")]],
+        ProcessAction::SwapLimit { .. } => vec![vec![LimitCgroup("memory.swap.max")]],
+        // SIGSTOP lên tiến trình cùng người dùng, hoặc cgroup.freeze của cgroup riêng của nó
+        ProcessAction::Freeze => vec![vec![SameUser], vec![FreezeCgroup]],
+        // Rã đông, bỏ cgroup riêng và đưa nice về 0: chỉ cần CAP_SYS_NICE khi nice đang dương
+        // (hạ nice) hoặc tiến trình thuộc người dùng khác
+        ProcessAction::Restore => {
+            let mut same_user = vec![SameUser];
+            let mut other_user = vec![Cap(Nice)];
+            if target.nice.is_some_and(|nice| nice > 0) {
+                same_user.push(Cap(Nice));
+            }
+            if target.limit_cgroup.as_ref().is_some_and(|c| c.can_release.is_some()) {
+                same_user.push(ReleaseCgroup);
+                other_user.push(ReleaseCgroup);
+            }
+            vec![same_user, other_user]
+        }
+    }
+}
+
+// Kết quả dò quyền của tiến trình này; trên Windows không dò (dùng quyền admin / helper)
+#[derive(Clone, Debug)]
+pub struct Permissions {
+    pub supported: bool,
+    pub effective_uid: Option<u32>,
+    pub capabilities: Vec<(Capability, bool)>,
+    pub cgroup: Result<Option<CgroupAccess>, String>,
+    // Dò sẵn cho các tiến trình đang hiện/đang chọn trên GUI, PID khác thì dò khi cần
+    pub targets: HashMap<u32, TargetAccess>,
+}
+
+impl Default for Permissions {
+    fn default() -> Self {
+        Permissions {
+            supported: false,
+            effective_uid: None,
+            capabilities: Vec::new(),
+            cgroup: Ok(None),
+            targets: HashMap::new(),
+        }
+    }
+}
+
+impl Permissions {
+    pub fn detect() -> Permissions {
+        platform::detect()
+    }
+
+    pub fn detect_targets(&mut self, pids: impl IntoIterator<Item = u32>) {
+        if !self.supported {
+            return;
+        }
+        self.targets = pids.into_iter().map(|pid| (pid, platform::target(pid))).collect();
+    }
+
+    pub fn has(&self, cap: Capability) -> bool {
+        self.capabilities.iter().any(|(c, present)| *c == cap && *present)
+    }
+
+    fn satisfied(&self, requirement: &Requirement, target: &TargetAccess) -> bool {
+        match requirement {
+            Requirement::Capability(cap) => self.has(*cap),
+            Requirement::SameUser => self.effective_uid == Some(0) || (target.uid.is_some() && target.uid == self.effective_uid),
+            Requirement::LimitCgroup(file) => target.limit_cgroup.as_ref().is_some_and(|cgroup| {
+                if cgroup.exists {
+                    cgroup.writable.contains(file)
+                } else {
+                    cgroup.can_create
+                }
+            }),
+            Requirement::ReleaseCgroup => {
+                target.limit_cgroup.as_ref().is_some_and(|cgroup| cgroup.can_release == Some(true))
+            }
+            Requirement::FreezeCgroup => target.freeze_cgroup,
+        }
+    }
+
+    // Err là lời giải thích thiếu quyền gì với tiến trình này, hiện khi rê chuột lên nút bị khoá
+    pub fn check(&self, action: &ProcessAction, pid: u32) -> Result<(), String> {
+        if !self.supported {
+            return Ok(());
+        }
+        let detected;
+        let target = match self.targets.get(&pid) {
+            Some(target) => target,
+            None => {
+                detected = platform::target(pid);
+                &detected
+            }
+        };
+        let options = action_requirements(action, target);
+        if options.iter().any(|option| option.iter().all(|r| self.satisfied(r, target))) {
+            return Ok(());
+        }
+        let missing: Vec<String> = options
+            .iter()
+            .map(|option| {
+                option
+                    .iter()
+                    .filter(|r| !self.satisfied(r, target))
+                    .map(|r| r.describe())
+                    .collect::<Vec<_>>()
+                    .join(" + ")
+            })
+            .collect();
+        Err(t!("perm-action-missing", action = action.label(), missing = missing.join(t!("perm-or").as_str())))
+    }
+
+    // Hành động lên nhiều tiến trình (chọn nhiều, nhóm): lỗi của PID đầu tiên thiếu quyền
+    pub fn check_all(&self, action: &ProcessAction, pids: &[u32]) -> Result<(), String> {
+        for &pid in pids {
+            self.check(action, pid).map_err(|reason| t!("perm-pid-missing", pid = pid, reason = reason))?;
+        }
+        Ok(())
+    }
+
+    // Giảm priority (tăng nice) thì không cần quyền, tăng thì cần CAP_SYS_NICE
+    pub fn check_priority(&self, priority: Priority) -> Result<(), String> {
+        if !self.supported || matches!(priority, Priority::Idle | Priority::BelowNormal) || self.has(Capability::Nice) {
+            return Ok(());
+        }
+        Err(t!("perm-priority-missing", priority = priority.as_str()))
+    }
+
+    // Tăng oom_score_adj thì không cần quyền, hạ xuống thì cần CAP_SYS_RESOURCE
+    pub fn check_oom_lower(&self) -> Result<(), String> {
+        if !self.supported || self.has(Capability::Resource) {
+            return Ok(());
+        }
+        Err(t!("perm-oom-missing"))
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::collections::HashMap;
+    use std::fs;
+    use std::os::unix::fs::MetadataExt;
+    use std::path::{Path, PathBuf};
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+    use super::{CgroupAccess, CgroupFile, Capability, LimitCgroupAccess, Permissions, TargetAccess};
+    use crate::cgroup;
+    use crate::freeze;
+    use crate::i18n::t;
+    use crate::memory_cap;
+
+    // Các file trong cgroup hiện tại mà các hành động trên Linux cần ghi
+    const CGROUP_FILES: [&str; 6] =
+        ["cgroup.procs", "memory.min", "memory.high", "memory.max", "memory.swap.max", "memory.reclaim"];
+    // Các file memory_cap ghi trong cgroup riêng của tiến trình
+    const LIMIT_FILES: [&str; 5] = ["memory.min", "memory.high", "memory.max", "memory.swap.max", "memory.oom.group"];
+
+    // Số thứ tự bit trong CapEff (linux/capability.h)
+    fn cap_bit(cap: Capability) -> u32 {
+        match cap {
+            Capability::Nice => 23,
+            Capability::Resource => 24,
+            Capability::Ptrace => 19,
+        }
+    }
+
+    pub fn detect() -> Permissions {
+        let status = fs::read_to_string("/proc/self/status").unwrap_or_default();
+        let cap_eff = status_field(&status, "CapEff:").and_then(|v| u64::from_str_radix(v, 16).ok()).unwrap_or(0);
+        // Uid: thực, hiệu dụng, saved, fs
+        let effective_uid = status_field(&status, "Uid:")
+            .and_then(|v| v.split_whitespace().nth(1))
+            .and_then(|v| v.parse().ok());
+        Permissions {
+            supported: true,
+            effective_uid,
+            capabilities: [Capability::Nice, Capability::Resource, Capability::Ptrace]
+                .into_iter()
+                .map(|cap| (cap, cap_eff & (1 << cap_bit(cap)) != 0))
+                .collect(),
+            cgroup: detect_cgroup(effective_uid),
+            targets: HashMap::new(),
+        }
+    }
+
+    pub fn target(pid: u32) -> TargetAccess {
+        let status = fs::read_to_string(format!("/proc/{}/status", pid)).unwrap_or_default();
+        let uids: Vec<u32> = status_field(&status, "Uid:")
+            .map(|v| v.split_whitespace().take(3).filter_map(|uid| uid.parse().ok()).collect())
+            .unwrap_or_default();
+        let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).unwrap_or_default();
+        TargetAccess {
+            uid: uids.first().copied().filter(|uid| uids.len() == 3 && uids.iter().all(|u| u == uid)),
+            nice: nice(&stat),
+            limit_cgroup: memory_cap::limit_target(pid).map(|target| limit_cgroup(&target)),
+            freeze_cgroup: freeze::own_cgroup(pid).is_some_and(|dir| writable_file(&dir.join("cgroup.freeze"))),
+        }
+    }
+
+    // Trường thứ 19 của /proc/<pid>/stat, đếm sau dấu `)` cuối (tên tiến trình có thể chứa khoảng trắng)
+    pub fn nice(stat: &str) -> Option<i32> {
+        let (_, rest) = stat.rsplit_once(')')?;
+        rest.split_whitespace().nth(16)?.parse().ok()
+    }
+
+    fn limit_cgroup(target: &memory_cap::LimitTarget) -> LimitCgroupAccess {
+        let parent = target.dir.parent();
+        let can_create = parent.is_some_and(|parent| {
+            writable_dir(parent)
+                && writable_file(&parent.join("cgroup.procs"))
+                && cgroup::read(parent, "cgroup.subtree_control").is_ok_and(|v| v.split_whitespace().any(|c| c == "memory"))
+        });
+        let can_release = target.original.as_ref().filter(|_| target.owned).map(|original| {
+            writable_file(&original.join("cgroup.procs")) && parent.is_some_and(writable_dir)
+        });
+        LimitCgroupAccess {
+            path: target.dir.clone(),
+            exists: target.owned,
+            writable: LIMIT_FILES.into_iter().filter(|file| writable_file(&target.dir.join(file))).collect(),
+            can_create,
+            can_release,
+        }
+    }
+
+    fn writable_file(path: &Path) -> bool {
+        fs::OpenOptions::new().write(true).open(path).is_ok()
+    }
+
+    // Tạo/xoá thư mục con: quyền ghi theo UID hiệu dụng (AT_EACCESS), tính cả ACL và root
+    fn writable_dir(path: &Path) -> bool {
+        let Ok(path) = CString::new(path.as_os_str().as_bytes()) else {
+            return false;
+        };
+        unsafe { libc::faccessat(libc::AT_FDCWD, path.as_ptr(), libc::W_OK, libc::AT_EACCESS) == 0 }
+    }
+
+    fn status_field<'a>(status: &'a str, key: &str) -> Option<&'a str> {
+        status.lines().find_map(|line| line.strip_prefix(key)).map(str::trim)
+    }
+
+    fn detect_cgroup(effective_uid: Option<u32>) -> Result<Option<CgroupAccess>, String> {
+        let text = fs::read_to_string("/proc/self/cgroup")
+            .map_err(|e| t!("err-read-file", path = "/proc/self/cgroup", error = e.to_string()))?;
+        let Some(path) = cgroup::parse(&text) else {
+            return Ok(None);
+        };
+        let files = CGROUP_FILES.iter().map(|name| cgroup_file(&path, name)).collect();
+        Ok(Some(CgroupAccess {
+            delegated_root: effective_uid.and_then(|uid| delegated_root(&path, uid)),
+            path,
+            files,
+        }))
+    }
+
+    // Mở để ghi (không ghi gì) là cách chắc chắn nhất, tính cả ACL, quyền root và cgroup namespace
+    fn cgroup_file(dir: &Path, name: &'static str) -> CgroupFile {
+        let path = dir.join(name);
+        CgroupFile {
+            name,
+            exists: path.exists(),
+            writable: writable_file(&path),
+        }
+    }
+
+    // Đi ngược lên tới thư mục cao nhất vẫn thuộc sở hữu của người dùng
+    fn delegated_root(path: &Path, uid: u32) -> Option<PathBuf> {
+        let mut root = None;
+        let mut current = Some(path);
+        while let Some(dir) = current.filter(|dir| *dir != Path::new(cgroup::ROOT)) {
+            match fs::metadata(dir) {
+                Ok(meta) if meta.uid() == uid => root = Some(dir.to_path_buf()),
+                _ => break,
+            }
+            current = dir.parent();
+        }
+        root
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod platform {
+    use super::{Permissions, TargetAccess};
+
+    pub fn detect() -> Permissions {
+        Permissions::default()
+    }
+
+    pub fn target(_pid: u32) -> TargetAccess {
+        TargetAccess::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use crate::i18n::t;
+    use crate::memory_cap::{BreachAction, LimitMode};
+    use crate::ram_manager::ProcessAction;
+    use super::{Capability, LimitCgroupAccess, Permissions, TargetAccess};
+
+    const USER: u32 = 1000;
+
+    // Người dùng thường (uid 1000) với các capability cho trước, dò sẵn tiến trình 42
+    fn permissions(capabilities: &[Capability], target: TargetAccess) -> Permissions {
+        Permissions {
+            supported: true,
+            effective_uid: Some(USER),
+            capabilities: capabilities.iter().map(|cap| (*cap, true)).collect(),
+            cgroup: Ok(None),
+            targets: HashMap::from([(42, target)]),
+        }
+    }
+
+    fn owned(uid: u32) -> TargetAccess {
+        TargetAccess { uid: Some(uid), ..TargetAccess::default() }
+    }
+
+    fn limit_cgroup(exists: bool, writable: Vec<&'static str>, can_create: bool) -> Option<LimitCgroupAccess> {
+        Some(LimitCgroupAccess {
+            path: PathBuf::from("/sys/fs/cgroup/user.slice/app.scope-ram-manager"),
+            exists,
+            writable,
+            can_create,
+            can_release: None,
+        })
+    }
+
+    #[test]
+    fn trim_needs_nice_and_either_the_same_user_or_ptrace() {
+        assert!(permissions(&[Capability::Nice], owned(USER)).check(&ProcessAction::Trim, 42).is_ok());
+        assert!(permissions(&[Capability::Nice], owned(0)).check(&ProcessAction::Trim, 42).is_err());
+        assert!(permissions(&[Capability::Nice, Capability::Ptrace], owned(0)).check(&ProcessAction::Trim, 42).is_ok());
+        assert!(permissions(&[], owned(USER)).check(&ProcessAction::Trim, 42).is_err());
+    }
+
+    #[test]
+    fn freeze_works_through_a_signal_or_the_freeze_cgroup() {
+        assert!(permissions(&[], owned(USER)).check(&ProcessAction::Freeze, 42).is_ok());
+        assert!(permissions(&[], owned(0)).check(&ProcessAction::Freeze, 42).is_err());
+        let own_cgroup = TargetAccess { freeze_cgroup: true, ..owned(0) };
+        assert!(permissions(&[], own_cgroup).check(&ProcessAction::Freeze, 42).is_ok());
+    }
+
+    #[test]
+    fn restore_needs_nice_only_to_lower_a_positive_nice() {
+        let niced = TargetAccess { nice: Some(10), ..owned(USER) };
+        assert!(permissions(&[], niced.clone()).check(&ProcessAction::Restore, 42).is_err());
+        assert!(permissions(&[Capability::Nice], niced).check(&ProcessAction::Restore, 42).is_ok());
+        let normal = TargetAccess { nice: Some(0), ..owned(USER) };
+        assert!(permissions(&[], normal).check(&ProcessAction::Restore, 42).is_ok());
+    }
+
+    #[test]
+    fn limit_needs_the_limit_cgroup_files_or_a_way_to_create_it() {
+        let limit = ProcessAction::Limit { max_ws_mb: 512, mode: LimitMode::Hard, breach: BreachAction::Deny };
+        let writable = TargetAccess {
+            limit_cgroup: limit_cgroup(true, vec!["memory.high", "memory.max"], false),
+            ..owned(USER)
+        };
+        assert!(permissions(&[], writable).check(&limit, 42).is_ok());
+
+        let read_only = TargetAccess { limit_cgroup: limit_cgroup(true, vec!["memory.high"], true), ..owned(USER) };
+        assert!(permissions(&[], read_only).check(&limit, 42).is_err());
+
+        let creatable = TargetAccess { limit_cgroup: limit_cgroup(false, Vec::new(), true), ..owned(USER) };
+        assert!(permissions(&[], creatable).check(&limit, 42).is_ok());
+    }
+
+    #[test]
+    fn check_all_names_the_first_pid_missing_permissions() {
+        let mut permissions = permissions(&[], owned(USER));
+        permissions.targets.insert(43, owned(0));
+        let reason = permissions.check(&ProcessAction::Freeze, 43).unwrap_err();
+        assert_eq!(
+            permissions.check_all(&ProcessAction::Freeze, &[42, 43]),
+            Err(t!("perm-pid-missing", pid = 43, reason = reason))
+        );
+        assert_eq!(permissions.check_all(&ProcessAction::Freeze, &[42]), Ok(()));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn nice_is_read_after_the_process_name() {
+        let stat = "4321 (my (app) x) S 1 4321 4321 0 -1 4194560 100 0 0 0 5 3 0 0 20 10 4 0 12345";
+        assert_eq!(super::platform::nice(stat), Some(10));
+        assert_eq!(super::platform::nice("garbage"), None);
+    }
+}
@@ -0,0 +1,268 @@
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+#[cfg(windows)]
+use windows::Win32::Foundation::CloseHandle;
+#[cfg(windows)]
+use windows::Win32::System::Memory::{
+    SetProcessWorkingSetSizeEx, QUOTA_LIMITS_HARDWS_MAX_DISABLE, QUOTA_LIMITS_HARDWS_MIN_DISABLE,
+};
+#[cfg(windows)]
+use windows::Win32::System::ProcessStatus::EmptyWorkingSet;
+#[cfg(windows)]
+use windows::Win32::System::Threading::{
+    OpenProcess, SetPriorityClass, IDLE_PRIORITY_CLASS, NORMAL_PRIORITY_CLASS, PROCESS_ALL_ACCESS,
+    PROCESS_QUERY_INFORMATION, PROCESS_SET_QUOTA,
+};
+use crate::cgroup_tree::{self, CgroupValue};
+use crate::early_oom;
+use crate::freeze;
+use crate::helper::HelperClient;
+use crate::i18n::t;
+use crate::memory_cap::{self, BreachAction, LimitMode};
+use crate::memory_map::RegionKind;
+use crate::oom;
+use crate::pageout::{self, PageoutHint};
+use crate::ram_manager::{Priority, ProcessAction};
+
+// Các thao tác cần quyền admin/root. Đây cũng là toàn bộ những gì helper chấp nhận qua socket,
+// mỗi dòng JSON một lệnh: {"op":"trim","pid":1234}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case", deny_unknown_fields)]
+pub enum PrivilegedOp {
+    Pin { pid: u32, working_set_mb: usize },
+    Trim { pid: u32 },
+    Limit {
+        pid: u32,
+        max_ws_mb: usize,
+        #[serde(default)]
+        mode: LimitMode,
+        #[serde(default)]
+        breach: BreachAction,
+    },
+    // Giới hạn swap/zswap theo cgroup (chỉ Linux); bỏ trống là không giới hạn
+    SwapLimit {
+        pid: u32,
+        #[serde(default)]
+        swap_max_mb: Option<u64>,
+        #[serde(default)]
+        zswap_max_mb: Option<u64>,
+    },
+    Restore { pid: u32 },
+    SetPriority { pid: u32, priority: Priority },
+    // Trả lại priority gốc đọc bằng os_priority() khi hoàn tác profile
+    SetOsPriority { pid: u32, value: i32 },
+    // Trim trên Linux: process_madvise lên các vùng nhớ thuộc loại đã chọn
+    Pageout { pid: u32, hint: PageoutHint, regions: Vec<RegionKind> },
+    Freeze { pid: u32 },
+    Thaw { pid: u32 },
+    SetOomScoreAdj { pid: u32, value: i32 },
+    // Early-OOM: SIGTERM, hoặc SIGKILL khi force
+    Terminate { pid: u32, force: bool },
+    // Hành động áp dụng cho cả cgroup v2 (Linux): {"op":"cgroup","path":"/sys/fs/cgroup/...","action":{"action":"trim"}}
+    Cgroup { path: PathBuf, action: ProcessAction },
+    // Restore cả cgroup: ghi lại các giá trị gốc GUI đã lưu trước lần ghi đầu tiên
+    CgroupRestore { path: PathBuf, values: Vec<CgroupValue> },
+}
+
+impl PrivilegedOp {
+    // None với thao tác lên cả cgroup
+    pub fn pid(&self) -> Option<u32> {
+        let pid = match self {
+            PrivilegedOp::Pin { pid, .. }
+            | PrivilegedOp::Trim { pid }
+            | PrivilegedOp::Limit { pid, .. }
+            | PrivilegedOp::SwapLimit { pid, .. }
+            | PrivilegedOp::Restore { pid }
+            | PrivilegedOp::SetPriority { pid, .. }
+            | PrivilegedOp::SetOsPriority { pid, .. }
+            | PrivilegedOp::Pageout { pid, .. }
+            | PrivilegedOp::Freeze { pid }
+            | PrivilegedOp::Thaw { pid }
+            | PrivilegedOp::SetOomScoreAdj { pid, .. }
+            | PrivilegedOp::Terminate { pid, .. } => pid,
+            PrivilegedOp::Cgroup { .. } | PrivilegedOp::CgroupRestore { .. } => return None,
+        };
+        Some(*pid)
+    }
+}
+
+// Chạy thao tác ngay trong tiến trình này (đang có quyền) hoặc nhờ helper
+pub enum Executor {
+    InProcess,
+    Helper(HelperClient),
+}
+
+impl Executor {
+    pub fn execute(&mut self, op: &PrivilegedOp) -> Result<(), String> {
+        match self {
+            Executor::InProcess => execute(op),
+            Executor::Helper(client) => client.execute(op),
+        }
+    }
+}
+
+pub fn execute(op: &PrivilegedOp) -> Result<(), String> {
+    match *op {
+        PrivilegedOp::Pin { pid, working_set_mb } => pin(pid, working_set_mb),
+        PrivilegedOp::Trim { pid } => trim(pid),
+        PrivilegedOp::Limit { pid, max_ws_mb, mode, breach } => limit(pid, max_ws_mb, mode, breach),
+        PrivilegedOp::SwapLimit { pid, swap_max_mb, zswap_max_mb } => {
+            memory_cap::limit_swap(pid, swap_max_mb, zswap_max_mb)
+        }
+        PrivilegedOp::Restore { pid } => restore(pid),
+        PrivilegedOp::SetPriority { pid, priority } => set_priority(pid, priority),
+        PrivilegedOp::SetOsPriority { pid, value } => set_os_priority(pid, value),
+        PrivilegedOp::Pageout { pid, hint, ref regions } => pageout::advise(pid, hint, regions),
+        PrivilegedOp::Freeze { pid } => freeze::freeze(pid),
+        PrivilegedOp::Thaw { pid } => freeze::thaw(pid),
+        PrivilegedOp::SetOomScoreAdj { pid, value } => oom::set_score_adj(pid, value),
+        PrivilegedOp::Terminate { pid, force } => early_oom::terminate(pid, force),
+        PrivilegedOp::Cgroup { ref path, ref action } => cgroup_tree::apply(path, action),
+        PrivilegedOp::CgroupRestore { ref path, ref values } => cgroup_tree::restore(path, values),
+    }
+}
+
+// Linux: memory.min ở cgroup riêng thay cho working set tối thiểu, nice thay cho HIGH_PRIORITY_CLASS
+#[cfg(target_os = "linux")]
+fn pin(pid: u32, working_set_mb: usize) -> Result<(), String> {
+    memory_cap::protect(pid, working_set_mb)?;
+    set_priority(pid, Priority::High)
+}
+
+#[cfg(windows)]
+fn pin(pid: u32, working_set_mb: usize) -> Result<(), String> {
+    unsafe {
+        let min_size: usize = memory_cap::mb_to_bytes(working_set_mb as u64)?;
+        let max_size: usize = memory_cap::mb_to_bytes((working_set_mb as u64).saturating_mul(2))?;
+        let handle = OpenProcess(PROCESS_SET_QUOTA | PROCESS_QUERY_INFORMATION, false, pid)
+            .map_err(|e| t!("err-open-process", error = format!("{:?}", e)))?;
+
+        // Sử dụng SetProcessWorkingSetSizeEx thay vì SetProcessWorkingSetSize;
+        // bỏ max cứng nếu tiến trình từng bị Limit
+        let result = SetProcessWorkingSetSizeEx(handle, min_size, max_size, QUOTA_LIMITS_HARDWS_MAX_DISABLE)
+            .map_err(|e| t!("err-set-working-set", error = format!("{:?}", e)));
+        let _ = CloseHandle(handle);
+        result?;
+    }
+    set_priority(pid, Priority::High)
+}
+
+// Trên Linux Trim đi qua Pageout (process_madvise), không có working set để làm rỗng
+#[cfg(target_os = "linux")]
+fn trim(_pid: u32) -> Result<(), String> {
+    Err(t!("err-trim-unsupported"))
+}
+
+#[cfg(windows)]
+fn trim(pid: u32) -> Result<(), String> {
+    unsafe {
+        let handle = OpenProcess(PROCESS_ALL_ACCESS, false, pid)
+            .map_err(|e| t!("err-open-process", error = format!("{:?}", e)))?;
+
+        // EmptyWorkingSet nằm trong ProcessStatus module
+        EmptyWorkingSet(handle)
+            .map_err(|e| t!("err-trim-working-set", error = format!("{:?}", e)))?;
+
+        SetPriorityClass(handle, IDLE_PRIORITY_CLASS)
+            .map_err(|e| t!("err-set-priority", error = format!("{:?}", e)))?;
+
+        let _ = CloseHandle(handle);
+        Ok(())
+    }
+}
+
+// Giới hạn bộ nhớ theo mode nằm ở memory_cap.rs; trên Windows hạ thêm priority xuống IDLE
+fn limit(pid: u32, max_ws_mb: usize, mode: LimitMode, breach: BreachAction) -> Result<(), String> {
+    memory_cap::apply(pid, max_ws_mb, mode, breach)?;
+    if cfg!(target_os = "linux") {
+        return Ok(());
+    }
+    set_priority(pid, Priority::Idle)
+}
+
+// Cgroup riêng (memory.min của Pin) đã bị bỏ cùng giới hạn, còn lại nice
+#[cfg(target_os = "linux")]
+fn restore(pid: u32) -> Result<(), String> {
+    memory_cap::release(pid)?;
+    set_priority(pid, Priority::Normal)
+}
+
+#[cfg(windows)]
+fn restore(pid: u32) -> Result<(), String> {
+    memory_cap::release(pid)?;
+    unsafe {
+        let handle = OpenProcess(PROCESS_SET_QUOTA | PROCESS_QUERY_INFORMATION, false, pid)
+            .map_err(|e| t!("err-open-process", error = format!("{:?}", e)))?;
+
+        // Reset working set về auto (-1, -1) và bỏ giới hạn cứng của Limit
+        SetProcessWorkingSetSizeEx(
+            handle,
+            usize::MAX,
+            usize::MAX,
+            QUOTA_LIMITS_HARDWS_MIN_DISABLE | QUOTA_LIMITS_HARDWS_MAX_DISABLE,
+        )
+            .map_err(|e| t!("err-reset-working-set", error = format!("{:?}", e)))?;
+
+        SetPriorityClass(handle, NORMAL_PRIORITY_CLASS)
+            .map_err(|e| t!("err-set-priority", error = format!("{:?}", e)))?;
+
+        let _ = CloseHandle(handle);
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn set_priority(pid: u32, priority: Priority) -> Result<(), String> {
+    set_os_priority(pid, priority.nice())
+}
+
+// Priority hiện tại theo đơn vị của hệ điều hành: nice trên Linux, priority class trên Windows.
+// None khi tiến trình đã thoát hoặc không đọc được
+#[cfg(target_os = "linux")]
+pub fn os_priority(pid: u32) -> Option<i32> {
+    // getpriority trả -1 cả khi thành công nên phải xoá errno trước để phân biệt
+    unsafe {
+        *libc::__errno_location() = 0;
+        let nice = libc::getpriority(libc::PRIO_PROCESS, pid as libc::id_t);
+        (*libc::__errno_location() == 0).then_some(nice)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn set_os_priority(pid: u32, value: i32) -> Result<(), String> {
+    if unsafe { libc::setpriority(libc::PRIO_PROCESS, pid as libc::id_t, value) } != 0 {
+        return Err(t!("err-set-priority", error = std::io::Error::last_os_error().to_string()));
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn set_priority(pid: u32, priority: Priority) -> Result<(), String> {
+    set_os_priority(pid, priority.priority_class().0 as i32)
+}
+
+#[cfg(windows)]
+pub fn os_priority(pid: u32) -> Option<i32> {
+    use windows::Win32::System::Threading::{GetPriorityClass, PROCESS_QUERY_LIMITED_INFORMATION};
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+        let class = GetPriorityClass(handle);
+        let _ = CloseHandle(handle);
+        (class != 0).then_some(class as i32)
+    }
+}
+
+#[cfg(windows)]
+fn set_os_priority(pid: u32, value: i32) -> Result<(), String> {
+    use windows::Win32::System::Threading::{PROCESS_CREATION_FLAGS, PROCESS_SET_INFORMATION};
+    unsafe {
+        let handle = OpenProcess(PROCESS_SET_INFORMATION, false, pid)
+            .map_err(|e| t!("err-open-process", error = format!("{:?}", e)))?;
+
+        let result = SetPriorityClass(handle, PROCESS_CREATION_FLAGS(value as u32))
+            .map_err(|e| t!("err-set-priority", error = format!("{:?}", e)));
+
+        let _ = CloseHandle(handle);
+        result
+    }
+}
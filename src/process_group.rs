@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use crate::i18n::{num, t};
+use crate::ram_manager::{ProcessAction, ProcessInfo};
+
+// Tất cả tiến trình của cùng một ứng dụng (VD: mọi instance chrome.exe)
+#[derive(Clone, Debug)]
+pub struct ProcessGroup {
+    pub key: String,
+    pub name: String,
+    pub processes: Vec<ProcessInfo>,
+    pub total_memory_mb: f64,
+    pub total_cpu_usage: f32,
+}
+
+impl ProcessGroup {
+    pub fn pids(&self) -> Vec<u32> {
+        self.processes.iter().map(|p| p.pid).collect()
+    }
+
+    pub fn instance_count(&self) -> usize {
+        self.processes.len()
+    }
+
+    pub fn matches(&self, filter: &str) -> bool {
+        let filter_lower = filter.to_lowercase();
+        self.name.to_lowercase().contains(&filter_lower)
+            || self.processes.iter().any(|p| p.pid.to_string().contains(filter))
+    }
+}
+
+// "Chrome.exe" và "chrome" được coi là cùng một ứng dụng
+pub fn group_key(name: &str) -> String {
+    let lower = name.to_lowercase();
+    match lower.strip_suffix(".exe") {
+        Some(stripped) => stripped.to_string(),
+        None => lower,
+    }
+}
+
+pub fn group_by_name(processes: &[ProcessInfo]) -> Vec<ProcessGroup> {
+    let mut groups: HashMap<String, ProcessGroup> = HashMap::new();
+
+    for proc in processes {
+        let key = group_key(&proc.name);
+        let group = groups.entry(key.clone()).or_insert_with(|| ProcessGroup {
+            key,
+            name: proc.name.clone(),
+            processes: Vec::new(),
+            total_memory_mb: 0.0,
+            total_cpu_usage: 0.0,
+        });
+        group.total_memory_mb += proc.memory_mb;
+        group.total_cpu_usage += proc.cpu_usage;
+        group.processes.push(proc.clone());
+    }
+
+    let mut groups: Vec<ProcessGroup> = groups.into_values().collect();
+    for group in groups.iter_mut() {
+        group.processes.sort_by(|a, b| b.memory_mb.partial_cmp(&a.memory_mb).unwrap());
+    }
+    groups.sort_by(|a, b| b.total_memory_mb.partial_cmp(&a.total_memory_mb).unwrap());
+    groups
+}
+
+pub fn find_group<'a>(groups: &'a [ProcessGroup], key: &str) -> Option<&'a ProcessGroup> {
+    groups.iter().find(|g| g.key == key)
+}
+
+#[derive(Clone, Debug)]
+pub struct GroupActionEntry {
+    pub pid: u32,
+    pub before_mb: Option<f64>,
+    pub after_mb: Option<f64>,
+    pub error: Option<String>,
+}
+
+// Báo cáo trước/sau khi áp dụng một hành động cho cả nhóm
+#[derive(Clone, Debug)]
+pub struct GroupActionReport {
+    pub group_name: String,
+    pub action: ProcessAction,
+    pub entries: Vec<GroupActionEntry>,
+}
+
+impl GroupActionReport {
+    pub fn succeeded(&self) -> usize {
+        self.entries.iter().filter(|e| e.error.is_none()).count()
+    }
+
+    pub fn total_before_mb(&self) -> f64 {
+        self.entries.iter().filter_map(|e| e.before_mb).sum()
+    }
+
+    pub fn total_after_mb(&self) -> f64 {
+        self.entries.iter().filter_map(|e| e.after_mb).sum()
+    }
+
+    pub fn freed_mb(&self) -> f64 {
+        // Chỉ tính những tiến trình đo được cả trước và sau
+        self.entries
+            .iter()
+            .filter_map(|e| match (e.before_mb, e.after_mb) {
+                (Some(before), Some(after)) => Some((before - after).max(0.0)),
+                _ => None,
+            })
+            .sum()
+    }
+
+    pub fn summary(&self) -> String {
+        let mut msg = t!(
+            "group-summary",
+            icon = if self.succeeded() > 0 { "✅" } else { "❌" },
+            action = self.action.label(),
+            group = self.group_name.as_str(),
+            succeeded = self.succeeded(),
+            total = self.entries.len(),
+            before = num(self.total_before_mb()),
+            after = num(self.total_after_mb()),
+            freed = num(self.freed_mb())
+        );
+        for entry in self.entries.iter() {
+            if let Some(e) = &entry.error {
+                msg.push_str(&format!("\n⚠️ PID {}: {}", entry.pid, e));
+            }
+        }
+        msg
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_group, group_by_name, group_key, GroupActionEntry, GroupActionReport};
+    use crate::ram_manager::{ProcessAction, ProcessInfo};
+
+    #[test]
+    fn names_differing_only_in_case_or_exe_suffix_share_a_group() {
+        assert_eq!(group_key("Chrome.EXE"), "chrome");
+        assert_eq!(group_key("chrome"), "chrome");
+        assert_eq!(group_key("node.exe.bak"), "node.exe.bak");
+    }
+
+    #[test]
+    fn groups_sum_their_instances_and_sort_by_memory() {
+        let processes = [
+            ProcessInfo::sample(1, "chrome.exe", None, 100.0),
+            ProcessInfo::sample(2, "Chrome.exe", None, 300.0),
+            ProcessInfo::sample(3, "code", None, 250.0),
+        ];
+        let groups = group_by_name(&processes);
+
+        assert_eq!(groups.iter().map(|g| g.key.as_str()).collect::<Vec<_>>(), vec!["chrome", "code"]);
+        let chrome = find_group(&groups, "chrome").unwrap();
+        assert_eq!(chrome.instance_count(), 2);
+        assert_eq!(chrome.total_memory_mb, 400.0);
+        assert_eq!(chrome.pids(), vec![2, 1]);
+        assert!(chrome.matches("CHROME"));
+        assert!(!chrome.matches("code"));
+    }
+
+    #[test]
+    fn freed_memory_only_counts_processes_measured_before_and_after() {
+        let entry = |pid, before_mb, after_mb, error: Option<&str>| GroupActionEntry {
+            pid,
+            before_mb,
+            after_mb,
+            error: error.map(str::to_string),
+        };
+        let report = GroupActionReport {
+            group_name: "chrome".to_string(),
+            action: ProcessAction::Trim,
+            entries: vec![
+                entry(1, Some(300.0), Some(100.0), None),
+                entry(2, Some(50.0), Some(80.0), None),
+                entry(3, Some(70.0), None, Some("denied")),
+            ],
+        };
+        assert_eq!(report.succeeded(), 2);
+        assert_eq!(report.total_before_mb(), 420.0);
+        assert_eq!(report.freed_mb(), 200.0);
+    }
+}
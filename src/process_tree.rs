@@ -0,0 +1,167 @@
+use std::collections::{HashMap, HashSet};
+use crate::ram_manager::ProcessInfo;
+
+// Một nút trong cây tiến trình, kèm tổng RAM/CPU của cả nhánh con
+#[derive(Clone, Debug)]
+pub struct ProcessNode {
+    pub info: ProcessInfo,
+    pub children: Vec<ProcessNode>,
+    pub total_memory_mb: f64,
+    pub total_cpu_usage: f32,
+}
+
+impl ProcessNode {
+    // PID của nút này và tất cả tiến trình con cháu
+    pub fn pids(&self) -> Vec<u32> {
+        let mut pids = vec![self.info.pid];
+        for child in &self.children {
+            pids.extend(child.pids());
+        }
+        pids
+    }
+
+    pub fn process_count(&self) -> usize {
+        1 + self.children.iter().map(|c| c.process_count()).sum::<usize>()
+    }
+
+    // true nếu có ít nhất một tiến trình trong nhánh khớp với filter
+    pub fn matches(&self, filter: &str) -> bool {
+        let filter_lower = filter.to_lowercase();
+        self.matches_lower(filter, &filter_lower)
+    }
+
+    fn matches_lower(&self, filter: &str, filter_lower: &str) -> bool {
+        self.info.name.to_lowercase().contains(filter_lower)
+            || self.info.pid.to_string().contains(filter)
+            || self.children.iter().any(|c| c.matches_lower(filter, filter_lower))
+    }
+}
+
+pub fn find_node(roots: &[ProcessNode], pid: u32) -> Option<&ProcessNode> {
+    for node in roots {
+        if node.info.pid == pid {
+            return Some(node);
+        }
+        if let Some(found) = find_node(&node.children, pid) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+pub fn build_tree(processes: &[ProcessInfo]) -> Vec<ProcessNode> {
+    let known: HashSet<u32> = processes.iter().map(|p| p.pid).collect();
+    let mut children: HashMap<u32, Vec<&ProcessInfo>> = HashMap::new();
+    let mut roots = Vec::new();
+
+    for proc in processes {
+        match proc.parent_pid {
+            Some(ppid) if ppid != proc.pid && known.contains(&ppid) => {
+                children.entry(ppid).or_default().push(proc);
+            }
+            _ => roots.push(proc),
+        }
+    }
+
+    let mut visited = HashSet::new();
+    let mut tree: Vec<ProcessNode> = Vec::new();
+    for root in roots {
+        tree.push(build_node(root, &children, &mut visited));
+    }
+
+    // PID bị tái sử dụng có thể tạo vòng lặp cha-con không có gốc, đưa chúng lên cấp cao nhất
+    for proc in processes {
+        if !visited.contains(&proc.pid) {
+            tree.push(build_node(proc, &children, &mut visited));
+        }
+    }
+
+    tree.sort_by(|a, b| b.total_memory_mb.partial_cmp(&a.total_memory_mb).unwrap());
+    tree
+}
+
+fn build_node(
+    info: &ProcessInfo,
+    children: &HashMap<u32, Vec<&ProcessInfo>>,
+    visited: &mut HashSet<u32>,
+) -> ProcessNode {
+    visited.insert(info.pid);
+
+    let mut nodes = Vec::new();
+    if let Some(kids) = children.get(&info.pid) {
+        for kid in kids {
+            if !visited.contains(&kid.pid) {
+                nodes.push(build_node(kid, children, visited));
+            }
+        }
+    }
+    nodes.sort_by(|a, b| b.total_memory_mb.partial_cmp(&a.total_memory_mb).unwrap());
+
+    ProcessNode {
+        total_memory_mb: info.memory_mb + nodes.iter().map(|n| n.total_memory_mb).sum::<f64>(),
+        total_cpu_usage: info.cpu_usage + nodes.iter().map(|n| n.total_cpu_usage).sum::<f32>(),
+        info: info.clone(),
+        children: nodes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_tree, find_node};
+    use crate::ram_manager::ProcessInfo;
+
+    #[test]
+    fn children_are_nested_under_their_parent_with_subtree_totals() {
+        let processes = [
+            ProcessInfo::sample(1, "init", None, 10.0),
+            ProcessInfo::sample(2, "shell", Some(1), 20.0),
+            ProcessInfo::sample(3, "editor", Some(2), 300.0),
+            ProcessInfo::sample(4, "daemon", Some(1), 50.0),
+        ];
+        let tree = build_tree(&processes);
+
+        assert_eq!(tree.len(), 1);
+        let root = &tree[0];
+        assert_eq!(root.info.pid, 1);
+        assert_eq!(root.process_count(), 4);
+        assert_eq!(root.total_memory_mb, 380.0);
+        assert_eq!(root.total_cpu_usage, 4.0);
+        // Nhánh lớn hơn đứng trước
+        assert_eq!(root.children.iter().map(|c| c.info.pid).collect::<Vec<_>>(), vec![2, 4]);
+        assert_eq!(find_node(&tree, 2).unwrap().pids(), vec![2, 3]);
+        assert!(find_node(&tree, 9).is_none());
+    }
+
+    #[test]
+    fn unknown_parents_and_self_parents_become_roots() {
+        let processes = [
+            ProcessInfo::sample(10, "orphan", Some(999), 5.0),
+            ProcessInfo::sample(11, "self", Some(11), 50.0),
+        ];
+        let tree = build_tree(&processes);
+        assert_eq!(tree.iter().map(|n| n.info.pid).collect::<Vec<_>>(), vec![11, 10]);
+    }
+
+    #[test]
+    fn parent_cycles_from_reused_pids_do_not_loop() {
+        let processes = [
+            ProcessInfo::sample(20, "a", Some(21), 1.0),
+            ProcessInfo::sample(21, "b", Some(20), 2.0),
+        ];
+        let tree = build_tree(&processes);
+        let total: usize = tree.iter().map(|n| n.process_count()).sum();
+        assert_eq!(total, 2);
+    }
+
+    #[test]
+    fn filter_matches_any_descendant() {
+        let processes = [
+            ProcessInfo::sample(1, "init", None, 10.0),
+            ProcessInfo::sample(2, "Firefox", Some(1), 20.0),
+        ];
+        let tree = build_tree(&processes);
+        assert!(tree[0].matches("firefox"));
+        assert!(tree[0].matches("2"));
+        assert!(!tree[0].matches("chrome"));
+    }
+}
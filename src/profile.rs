@@ -0,0 +1,393 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use serde::{Deserialize, Serialize};
+use crate::config::Config;
+use crate::i18n::t;
+use crate::memory_cap::{BreachAction, LimitMode};
+use crate::process_group::group_key;
+use crate::ram_manager::{Priority, ProcessAction, ProcessStatus};
+
+// Một kịch bản ("gaming", "compile", "meeting"...) gồm nhiều rule áp dụng cùng lúc
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub rules: Vec<ProfileRule>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProfileRule {
+    pub target: TargetSelector,
+    #[serde(flatten)]
+    pub action: ProcessAction,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Priority>,
+}
+
+// target = { name = "chrome" } | { contains = "steam" } | { pid = 1234 }
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetSelector {
+    Name(String),
+    Contains(String),
+    Pid(u32),
+}
+
+impl TargetSelector {
+    pub fn matches(&self, pid: u32, name: &str) -> bool {
+        match self {
+            TargetSelector::Name(target) => group_key(name) == group_key(target),
+            TargetSelector::Contains(part) => name.to_lowercase().contains(&part.to_lowercase()),
+            TargetSelector::Pid(target) => *target == pid,
+        }
+    }
+}
+
+// Trạng thái của một PID trước khi profile thay đổi nó
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PidSnapshot {
+    pub status: Option<ProcessStatus>,
+    pub action: Option<ProcessAction>,
+    pub priority: Option<Priority>,
+    // Priority thật của tiến trình (nice / priority class), kể cả khi không do chương trình này đặt
+    #[serde(default)]
+    pub os_priority: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProfileChange {
+    pub pid: u32,
+    pub name: String,
+    pub previous: PidSnapshot,
+}
+
+impl ProfileChange {
+    // Khi hoàn tác, PID phải vẫn là tiến trình mà profile đã thay đổi (chưa thoát, chưa bị tái sử dụng)
+    pub fn is_same_process(&self, current_name: Option<&str>) -> bool {
+        current_name == Some(self.name.as_str())
+    }
+}
+
+// Profile đang có hiệu lực và những gì nó đã thay đổi, lưu ra file để hoàn tác được cả sau khi khởi động lại
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProfileSession {
+    pub profile_name: String,
+    pub changes: Vec<ProfileChange>,
+}
+
+impl ProfileSession {
+    pub fn path() -> PathBuf {
+        Config::path().with_file_name("active_profile.toml")
+    }
+
+    pub fn load(path: &Path) -> Result<Option<ProfileSession>, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text)
+                .map(Some)
+                .map_err(|e| t!("err-syntax", path = path.display().to_string(), error = e.to_string())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(t!("err-read-file", path = path.display().to_string(), error = e.to_string())),
+        }
+    }
+
+    // `None` xóa file: không còn profile nào đang chạy
+    pub fn store(session: Option<&ProfileSession>, path: &Path) -> Result<(), String> {
+        match session {
+            Some(session) => {
+                if let Some(dir) = path.parent() {
+                    fs::create_dir_all(dir)
+                        .map_err(|e| t!("err-create-dir", path = dir.display().to_string(), error = e.to_string()))?;
+                }
+                let text = toml::to_string_pretty(session)
+                    .map_err(|e| t!("err-serialize-session", error = e.to_string()))?;
+                fs::write(path, text)
+                    .map_err(|e| t!("err-write-file", path = path.display().to_string(), error = e.to_string()))
+            }
+            None => match fs::remove_file(path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                    Err(t!("err-delete-file", path = path.display().to_string(), error = e.to_string()))
+                }
+                _ => Ok(()),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileEvent {
+    Applied,
+    Reverted,
+}
+
+impl ProfileEvent {
+    pub fn label(&self) -> String {
+        match self {
+            ProfileEvent::Applied => t!("profile-event-applied"),
+            ProfileEvent::Reverted => t!("profile-event-reverted"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ProfileReport {
+    pub profile_name: String,
+    pub event: ProfileEvent,
+    pub at: SystemTime,
+    pub entries: Vec<(u32, String, Result<String, String>)>,
+}
+
+impl ProfileReport {
+    pub fn new(profile_name: &str, event: ProfileEvent) -> Self {
+        ProfileReport {
+            profile_name: profile_name.to_string(),
+            event,
+            at: SystemTime::now(),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn succeeded(&self) -> usize {
+        self.entries.iter().filter(|(_, _, r)| r.is_ok()).count()
+    }
+
+    // Kết quả trả cho API
+    pub fn to_json(&self) -> Result<serde_json::Value, String> {
+        let export = ExportedProfileReport {
+            profile: &self.profile_name,
+            event: self.event,
+            summary: self.summary(),
+            entries: self
+                .entries
+                .iter()
+                .map(|(pid, name, result)| ExportedProfileEntry {
+                    pid: *pid,
+                    name,
+                    ok: result.is_ok(),
+                    message: match result {
+                        Ok(message) | Err(message) => message,
+                    },
+                })
+                .collect(),
+        };
+        serde_json::to_value(export).map_err(|e| e.to_string())
+    }
+
+    pub fn summary(&self) -> String {
+        let failed = self.entries.len() - self.succeeded();
+        t!(
+            "profile-summary",
+            icon = if failed == 0 { "✅" } else { "⚠️" },
+            event = self.event.label(),
+            profile = self.profile_name.as_str(),
+            succeeded = self.succeeded(),
+            failed = failed
+        )
+    }
+}
+
+#[derive(Serialize)]
+struct ExportedProfileReport<'a> {
+    profile: &'a str,
+    event: ProfileEvent,
+    summary: String,
+    entries: Vec<ExportedProfileEntry<'a>>,
+}
+
+#[derive(Serialize)]
+struct ExportedProfileEntry<'a> {
+    pid: u32,
+    name: &'a str,
+    ok: bool,
+    message: &'a str,
+}
+
+// Profile mẫu chỉ chọn tiến trình theo đúng tên executable: `contains` với chuỗi ngắn như "game"
+// hay "docker" dễ Pin/Limit nhầm tiến trình khác
+pub fn default_profiles() -> Vec<Profile> {
+    vec![
+        Profile {
+            name: "gaming".to_string(),
+            description: t!("profile-gaming-desc"),
+            rules: vec![
+                ProfileRule {
+                    target: TargetSelector::Name("chrome".to_string()),
+                    action: ProcessAction::Trim,
+                    priority: None,
+                },
+                ProfileRule {
+                    target: TargetSelector::Name("discord".to_string()),
+                    action: ProcessAction::Trim,
+                    priority: None,
+                },
+                ProfileRule {
+                    target: TargetSelector::Name("steam".to_string()),
+                    action: ProcessAction::Limit {
+                        max_ws_mb: 512,
+                        mode: LimitMode::WorkingSet,
+                        breach: BreachAction::Deny,
+                    },
+                    priority: None,
+                },
+            ],
+        },
+        Profile {
+            name: "compile".to_string(),
+            description: t!("profile-compile-desc"),
+            rules: vec![
+                ProfileRule {
+                    target: TargetSelector::Name("code".to_string()),
+                    action: ProcessAction::Pin { working_set_mb: 1536 },
+                    priority: Some(Priority::AboveNormal),
+                },
+                ProfileRule {
+                    target: TargetSelector::Name("dockerd".to_string()),
+                    action: ProcessAction::Limit {
+                        max_ws_mb: 1024,
+                        mode: LimitMode::WorkingSet,
+                        breach: BreachAction::Deny,
+                    },
+                    priority: Some(Priority::BelowNormal),
+                },
+            ],
+        },
+        Profile {
+            name: "meeting".to_string(),
+            description: t!("profile-meeting-desc"),
+            rules: vec![
+                ProfileRule {
+                    target: TargetSelector::Name("ms-teams".to_string()),
+                    action: ProcessAction::Pin { working_set_mb: 1024 },
+                    priority: Some(Priority::High),
+                },
+                ProfileRule {
+                    target: TargetSelector::Name("zoom".to_string()),
+                    action: ProcessAction::Pin { working_set_mb: 1024 },
+                    priority: Some(Priority::High),
+                },
+                ProfileRule {
+                    target: TargetSelector::Name("steam".to_string()),
+                    action: ProcessAction::Trim,
+                    priority: None,
+                },
+            ],
+        },
+    ]
+}
+
+// Lệnh profile truyền từ command line: `--profile <tên>` hoặc `--revert-profile`
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProfileCommand {
+    Apply(String),
+    Revert,
+}
+
+impl ProfileCommand {
+    pub fn from_args(args: &[String]) -> Result<Option<ProfileCommand>, String> {
+        match args {
+            [] => Ok(None),
+            [flag, name] if flag == "--profile" => Ok(Some(ProfileCommand::Apply(name.clone()))),
+            [flag] if flag == "--revert-profile" => Ok(Some(ProfileCommand::Revert)),
+            _ => Err(t!("err-cli-args", args = args.join(" "))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::ram_manager::{Priority, ProcessAction, ProcessStatus};
+    use super::{PidSnapshot, ProfileChange, ProfileCommand, ProfileEvent, ProfileSession, TargetSelector};
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    fn session(changes: Vec<ProfileChange>) -> ProfileSession {
+        ProfileSession { profile_name: "gaming".to_string(), changes }
+    }
+
+    #[test]
+    fn command_line_flags_parse_into_profile_commands() {
+        assert_eq!(ProfileCommand::from_args(&[]), Ok(None));
+        assert_eq!(
+            ProfileCommand::from_args(&args(&["--profile", "gaming"])),
+            Ok(Some(ProfileCommand::Apply("gaming".to_string())))
+        );
+        assert_eq!(ProfileCommand::from_args(&args(&["--revert-profile"])), Ok(Some(ProfileCommand::Revert)));
+        assert!(ProfileCommand::from_args(&args(&["--profile"])).is_err());
+        assert!(ProfileCommand::from_args(&args(&["--revert-profile", "gaming"])).is_err());
+    }
+
+    #[test]
+    fn name_selectors_match_the_executable_not_a_substring() {
+        let name = TargetSelector::Name("dockerd".to_string());
+        assert!(name.matches(10, "dockerd"));
+        assert!(name.matches(10, "DockerD.exe"));
+        assert!(!name.matches(10, "docker-proxy"));
+
+        let contains = TargetSelector::Contains("Steam".to_string());
+        assert!(contains.matches(10, "steamwebhelper"));
+        assert!(!TargetSelector::Pid(11).matches(10, "steamwebhelper"));
+    }
+
+    #[test]
+    fn session_round_trips_through_its_file() {
+        let dir = std::env::temp_dir().join(format!("ram_manager-profile-{}", std::process::id()));
+        let path = dir.join("active_profile.toml");
+        let stored = session(vec![ProfileChange {
+            pid: 4321,
+            name: "chrome".to_string(),
+            previous: PidSnapshot {
+                status: Some(ProcessStatus::Limited),
+                action: Some(ProcessAction::Trim),
+                priority: Some(Priority::Idle),
+                os_priority: Some(5),
+            },
+        }]);
+
+        ProfileSession::store(Some(&stored), &path).unwrap();
+        let loaded = ProfileSession::load(&path).unwrap().unwrap();
+        assert_eq!(loaded.profile_name, "gaming");
+        assert_eq!(loaded.changes.len(), 1);
+        assert_eq!(loaded.changes[0].pid, 4321);
+        assert_eq!(loaded.changes[0].previous.action, Some(ProcessAction::Trim));
+        assert_eq!(loaded.changes[0].previous.priority, Some(Priority::Idle));
+        assert_eq!(loaded.changes[0].previous.os_priority, Some(5));
+
+        // Hoàn tác xong thì xóa file, lần tải sau không còn profile nào
+        ProfileSession::store(None, &path).unwrap();
+        assert!(ProfileSession::load(&path).unwrap().is_none());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn revert_only_touches_the_process_the_profile_changed() {
+        let change = ProfileChange {
+            pid: 4321,
+            name: "chrome".to_string(),
+            previous: PidSnapshot::default(),
+        };
+        assert!(change.is_same_process(Some("chrome")));
+        // PID đã thoát, hoặc bị tái sử dụng cho tiến trình khác
+        assert!(!change.is_same_process(None));
+        assert!(!change.is_same_process(Some("bash")));
+    }
+
+    #[test]
+    fn report_json_lists_every_entry() {
+        let mut report = super::ProfileReport::new("gaming", ProfileEvent::Applied);
+        report.entries.push((1, "a".to_string(), Ok("ok".to_string())));
+        report.entries.push((2, "b".to_string(), Err("denied".to_string())));
+
+        let json = report.to_json().unwrap();
+        assert_eq!(json["profile"], "gaming");
+        assert_eq!(json["event"], "applied");
+        assert_eq!(json["entries"][1]["pid"], 2);
+        assert_eq!(json["entries"][1]["ok"], false);
+        assert_eq!(json["entries"][1]["message"], "denied");
+    }
+}
@@ -1,43 +1,120 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
 use windows::Win32::Foundation::{HANDLE, CloseHandle};
 use windows::Win32::System::Threading::{
-    OpenProcess, PROCESS_ALL_ACCESS, PROCESS_SET_QUOTA, PROCESS_QUERY_INFORMATION,
-    SetPriorityClass, IDLE_PRIORITY_CLASS, NORMAL_PRIORITY_CLASS, HIGH_PRIORITY_CLASS,
+    OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_VM_READ,
 };
-use windows::Win32::System::Memory::SetProcessWorkingSetSizeEx;
-use windows::Win32::System::ProcessStatus::{
-    GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS, EmptyWorkingSet,
+use windows::Win32::System::ProcessStatus::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS};
+use sysinfo::{Pid, ProcessRefreshKind, System};
+use crate::cgroup_tree::{self, CgroupOriginals};
+use crate::i18n::{num, t};
+use crate::process_group::{GroupActionEntry, GroupActionReport, ProcessGroup};
+use crate::config::Thresholds;
+use crate::effect::{EffectReport, EffectTracker};
+use crate::freeze::FrozenProcesses;
+use crate::page_faults::{self, FaultRates, FaultTracker};
+use crate::helper::HelperStatus;
+use crate::launch::{self, LaunchExit, LaunchRequest, Launched};
+use crate::memory_cap::{self, BreachAction, LimitMode};
+use crate::memory_map::{self, MemoryMap};
+use crate::oom::{self, OomConfig, OomScore};
+use crate::pageout::{PageoutConfig, PageoutReport};
+use crate::permissions::Permissions;
+use crate::privileged::{self, Executor, PrivilegedOp};
+use crate::swap;
+use crate::profile::{
+    PidSnapshot, Profile, ProfileChange, ProfileEvent, ProfileReport, ProfileSession,
 };
-use sysinfo::{System, Process};
 
 #[derive(Clone, Debug)]
 pub struct ProcessInfo {
     pub pid: u32,
     pub name: String,
+    pub parent_pid: Option<u32>,
     pub memory_mb: f64,
     pub working_set_mb: f64,
+    pub swapped_mb: Option<f64>,
+    pub page_faults_per_sec: Option<f64>,
+    pub major_faults_per_sec: Option<f64>,
     pub status: ProcessStatus,
     pub cpu_usage: f32,
+    pub oom: Option<OomScore>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+impl ProcessInfo {
+    // Tiến trình đang bị Limit (hoặc khởi chạy trong giới hạn) mà page fault vẫn vượt ngưỡng: working set quá nhỏ,
+    // page bị đẩy ra rồi đọc lại liên tục. Linux so major fault (phải đọc đĩa), Windows chỉ có tổng page fault nên dùng ngưỡng riêng
+    pub fn is_thrashing(&self, thresholds: &Thresholds) -> bool {
+        if !matches!(self.status, ProcessStatus::Limited | ProcessStatus::Launched) {
+            return false;
+        }
+        match (self.major_faults_per_sec, self.page_faults_per_sec) {
+            (Some(major), _) => major > thresholds.thrashing_major_faults_per_sec,
+            (None, Some(total)) => total > thresholds.thrashing_faults_per_sec,
+            (None, None) => false,
+        }
+    }
+
+    // Giá trị vẽ lịch sử page fault: major fault nếu có, không thì tổng
+    pub fn fault_rate(&self) -> Option<f64> {
+        self.major_faults_per_sec.or(self.page_faults_per_sec)
+    }
+}
+
+// Tiến trình giả cho unit test của các module xử lý danh sách tiến trình
+#[cfg(test)]
+impl ProcessInfo {
+    pub fn sample(pid: u32, name: &str, parent_pid: Option<u32>, memory_mb: f64) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            name: name.to_string(),
+            parent_pid,
+            memory_mb,
+            working_set_mb: memory_mb,
+            swapped_mb: None,
+            page_faults_per_sec: None,
+            major_faults_per_sec: None,
+            status: ProcessStatus::Normal,
+            cpu_usage: 1.0,
+            oom: None,
+        }
+    }
+}
+
+// Giá trị đọc riêng cho từng tiến trình ngoài RAM/CPU của sysinfo, giữ lại giữa các lần sample
+#[derive(Clone, Copy, Debug)]
+struct ProcessExtras {
+    swapped_mb: Option<f64>,
+    faults: Option<FaultRates>,
+    oom: Option<OomScore>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ProcessStatus {
     Normal,
     Pinned,
     Trimmed,
     Limited,
     HighPriority,
+    Frozen,
+    Launched,
+    SwapLimited,
 }
 
 impl ProcessStatus {
-    pub fn as_str(&self) -> &str {
+    pub fn label(&self) -> String {
         match self {
-            ProcessStatus::Normal => "Bình thường",
-            ProcessStatus::Pinned => "Đã ghim",
-            ProcessStatus::Trimmed => "Đã trim",
-            ProcessStatus::Limited => "Giới hạn",
-            ProcessStatus::HighPriority => "Ưu tiên cao",
+            ProcessStatus::Normal => t!("status-normal"),
+            ProcessStatus::Pinned => t!("status-pinned"),
+            ProcessStatus::Trimmed => t!("status-trimmed"),
+            ProcessStatus::Limited => t!("status-limited"),
+            ProcessStatus::HighPriority => t!("status-high-priority"),
+            ProcessStatus::Frozen => t!("status-frozen"),
+            ProcessStatus::Launched => t!("status-launched"),
+            ProcessStatus::SwapLimited => t!("status-swap-limited"),
         }
     }
 
@@ -48,6 +125,9 @@ impl ProcessStatus {
             ProcessStatus::Trimmed => [52, 152, 219],
             ProcessStatus::Limited => [230, 126, 34],
             ProcessStatus::HighPriority => [155, 89, 182],
+            ProcessStatus::Frozen => [93, 173, 226],
+            ProcessStatus::Launched => [26, 188, 156],
+            ProcessStatus::SwapLimited => [241, 196, 15],
         }
     }
 
@@ -58,27 +138,225 @@ impl ProcessStatus {
             ProcessStatus::Trimmed => "🗜️",
             ProcessStatus::Limited => "⚠️",
             ProcessStatus::HighPriority => "⚡",
+            ProcessStatus::Frozen => "🧊",
+            ProcessStatus::Launched => "🚀",
+            ProcessStatus::SwapLimited => "💽",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "lowercase")]
+pub enum ProcessAction {
+    Pin { working_set_mb: usize },
+    Trim,
+    Limit {
+        max_ws_mb: usize,
+        #[serde(default)]
+        mode: LimitMode,
+        #[serde(default)]
+        breach: BreachAction,
+    },
+    // Giới hạn swap/zswap (MB) của tiến trình, 0 là không bao giờ swap, bỏ trống là không giới hạn
+    #[serde(rename = "swap_limit")]
+    SwapLimit {
+        #[serde(default)]
+        swap_max_mb: Option<u64>,
+        #[serde(default)]
+        zswap_max_mb: Option<u64>,
+    },
+    Freeze,
+    // Đóng băng rồi Trim: bộ nhớ bị đẩy ra không bị fault lại cho tới khi Restore
+    Hibernate,
+    Restore,
+}
+
+impl ProcessAction {
+    pub fn label(&self) -> String {
+        match self {
+            ProcessAction::Pin { .. } => t!("action-pin"),
+            ProcessAction::Trim => t!("action-trim"),
+            ProcessAction::Limit { .. } => t!("action-limit"),
+            ProcessAction::SwapLimit { .. } => t!("action-swap-limit"),
+            ProcessAction::Freeze => t!("action-freeze"),
+            ProcessAction::Hibernate => t!("action-hibernate"),
+            ProcessAction::Restore => t!("action-restore"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Priority {
+    Idle,
+    BelowNormal,
+    Normal,
+    AboveNormal,
+    High,
+}
+
+impl Priority {
+    pub const ALL: [Priority; 5] = [
+        Priority::Idle,
+        Priority::BelowNormal,
+        Priority::Normal,
+        Priority::AboveNormal,
+        Priority::High,
+    ];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Priority::Idle => "IDLE",
+            Priority::BelowNormal => "BELOW_NORMAL",
+            Priority::Normal => "NORMAL",
+            Priority::AboveNormal => "ABOVE_NORMAL",
+            Priority::High => "HIGH",
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn priority_class(&self) -> windows::Win32::System::Threading::PROCESS_CREATION_FLAGS {
+        use windows::Win32::System::Threading::{
+            ABOVE_NORMAL_PRIORITY_CLASS, BELOW_NORMAL_PRIORITY_CLASS, HIGH_PRIORITY_CLASS, IDLE_PRIORITY_CLASS,
+            NORMAL_PRIORITY_CLASS,
+        };
+        match self {
+            Priority::Idle => IDLE_PRIORITY_CLASS,
+            Priority::BelowNormal => BELOW_NORMAL_PRIORITY_CLASS,
+            Priority::Normal => NORMAL_PRIORITY_CLASS,
+            Priority::AboveNormal => ABOVE_NORMAL_PRIORITY_CLASS,
+            Priority::High => HIGH_PRIORITY_CLASS,
+        }
+    }
+
+    // Giá trị nice tương ứng trên Linux (âm = ưu tiên cao hơn, cần CAP_SYS_NICE)
+    #[cfg(target_os = "linux")]
+    pub fn nice(&self) -> i32 {
+        match self {
+            Priority::Idle => 19,
+            Priority::BelowNormal => 10,
+            Priority::Normal => 0,
+            Priority::AboveNormal => -5,
+            Priority::High => -10,
         }
     }
+
+    // Priority gốc của hệ điều hành chỉ được đặt lại nếu các mức ở trên cũng đạt tới được
+    // (nice -10..=19, không có REALTIME_PRIORITY_CLASS)
+    #[cfg(target_os = "linux")]
+    pub fn covers_os_value(value: i32) -> bool {
+        (Priority::High.nice()..=Priority::Idle.nice()).contains(&value)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn covers_os_value(value: i32) -> bool {
+        Priority::ALL.iter().any(|priority| priority.priority_class().0 as i32 == value)
+    }
 }
 
+const DEFAULT_FULL_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+const DEFAULT_EFFECT_WINDOW: Duration = Duration::from_secs(30);
+const DEFAULT_EFFECT_INTERVAL: Duration = Duration::from_secs(1);
+
 pub struct RamManager {
     system: System,
     process_states: Arc<Mutex<HashMap<u32, ProcessStatus>>>,
+    full_refresh_interval: Duration,
+    last_full_refresh: Instant,
+    full_refresh_pending: bool,
+    // Hành động và priority đã đặt cho từng PID, dùng để hoàn tác profile chính xác
+    applied_actions: HashMap<u32, ProcessAction>,
+    priority_overrides: HashMap<u32, Priority>,
+    // PID đang bị đóng băng bởi chương trình này, đồng bộ với frozen.toml;
+    // trên Windows mỗi lần suspend phải đúng một lần resume
+    frozen: FrozenProcesses,
+    active_profile: Option<ProfileSession>,
+    profile_history: Vec<ProfileReport>,
+    fault_tracker: FaultTracker,
+    effects: EffectTracker,
+    executor: Executor,
+    // Quyền của chính tiến trình này trên Linux; không áp dụng khi thao tác đi qua helper
+    permissions: Permissions,
+    pageout: PageoutConfig,
+    // Báo cáo của lần Trim gần nhất trên Linux, worker lấy ra gửi cho GUI
+    last_pageout: Option<PageoutReport>,
+    oom: OomConfig,
+    // oom_score_adj trước lần đổi đầu tiên, trả lại khi Restore
+    oom_originals: HashMap<u32, i32>,
+    watched: HashSet<u32>,
+    extras: HashMap<u32, ProcessExtras>,
+    // Giá trị gốc của các cgroup đã ghi từ cây cgroup, đồng bộ với cgroup_originals.toml
+    cgroup_originals: CgroupOriginals,
+    // Chương trình khởi chạy với giới hạn (xem launch.rs), giữ cgroup / job object cho tới khi thoát
+    launched: HashMap<u32, Launched>,
 }
 
 impl RamManager {
     pub fn new() -> Self {
-        let mut sys = System::new_all();
-        sys.refresh_all();
+        let mut sys = System::new();
+        sys.refresh_memory();
+        sys.refresh_processes_specifics(Self::process_refresh_kind());
         RamManager {
             system: sys,
             process_states: Arc::new(Mutex::new(HashMap::new())),
+            full_refresh_interval: DEFAULT_FULL_REFRESH_INTERVAL,
+            last_full_refresh: Instant::now(),
+            full_refresh_pending: false,
+            applied_actions: HashMap::new(),
+            priority_overrides: HashMap::new(),
+            frozen: FrozenProcesses::default(),
+            active_profile: None,
+            profile_history: Vec::new(),
+            fault_tracker: FaultTracker::new(),
+            effects: EffectTracker::new(DEFAULT_EFFECT_WINDOW, DEFAULT_EFFECT_INTERVAL),
+            executor: Executor::InProcess,
+            permissions: Permissions::detect(),
+            pageout: PageoutConfig::default(),
+            last_pageout: None,
+            oom: OomConfig::default(),
+            oom_originals: HashMap::new(),
+            watched: HashSet::new(),
+            extras: HashMap::new(),
+            cgroup_originals: CgroupOriginals::default(),
+            launched: HashMap::new(),
         }
     }
 
-    pub fn refresh(&mut self) {
-        self.system.refresh_all();
+    // Chỉ cần RAM và CPU, không đọc exe/cmd/environ/disk của từng tiến trình
+    fn process_refresh_kind() -> ProcessRefreshKind {
+        ProcessRefreshKind::new().with_memory().with_cpu()
+    }
+
+    // Mỗi lần sample chỉ cập nhật RAM/CPU của các PID đã biết; danh sách tiến trình
+    // (thêm tiến trình mới, bỏ tiến trình đã thoát) được quét lại sau mỗi full_refresh_interval.
+    // Trả về true khi vừa quét lại toàn bộ
+    pub fn refresh(&mut self) -> bool {
+        self.system.refresh_memory();
+
+        if self.full_refresh_pending || self.last_full_refresh.elapsed() >= self.full_refresh_interval {
+            self.system.refresh_processes_specifics(Self::process_refresh_kind());
+            self.last_full_refresh = Instant::now();
+            self.full_refresh_pending = false;
+            true
+        } else {
+            let pids: Vec<Pid> = self.system.processes().keys().copied().collect();
+            self.system.refresh_pids_specifics(&pids, Self::process_refresh_kind());
+            false
+        }
+    }
+
+    // PID đang chọn hoặc đang hiện trên màn hình: swap, page fault và điểm OOM của chúng được đọc ở mỗi lần sample
+    pub fn set_watched(&mut self, pids: HashSet<u32>) {
+        self.watched = pids;
+    }
+
+    // Lần refresh tiếp theo sẽ quét lại toàn bộ danh sách tiến trình
+    pub fn request_full_refresh(&mut self) {
+        self.full_refresh_pending = true;
+    }
+
+    pub fn set_full_refresh_interval(&mut self, interval: Duration) {
+        self.full_refresh_interval = interval;
     }
 
     pub fn get_system_info(&self) -> SystemInfo {
@@ -90,9 +368,13 @@ impl RamManager {
         }
     }
 
+    // Swap, page fault và điểm OOM phải mở vài file /proc (hoặc OpenProcess) cho mỗi tiến trình nên chỉ đọc lại
+    // ở lần quét toàn bộ, còn giữa hai lần đó chỉ đọc cho PID đang xem và tiến trình đang bị tác động
+    // (phát hiện thrashing); các PID khác dùng giá trị của lần đọc trước
     pub fn list_processes(&mut self) -> Vec<ProcessInfo> {
-        self.refresh();
+        let full = self.refresh();
         let states = self.process_states.lock().unwrap();
+        let now = Instant::now();
         
         let mut processes: Vec<ProcessInfo> = self
             .system
@@ -100,114 +382,662 @@ impl RamManager {
             .iter()
             .map(|(pid, proc)| {
                 let pid_u32 = pid.as_u32();
+                let status = states.get(&pid_u32).cloned().unwrap_or(ProcessStatus::Normal);
+                let hot = full || self.watched.contains(&pid_u32) || status != ProcessStatus::Normal;
+                let extras = match self.extras.get(&pid_u32) {
+                    Some(extras) if !hot => *extras,
+                    _ => {
+                        let extras = ProcessExtras {
+                            swapped_mb: swap::process_swapped_kb(pid_u32).map(|kb| kb as f64 / 1024.0),
+                            faults: self.fault_tracker.sample(pid_u32, now),
+                            oom: oom::read(pid_u32),
+                        };
+                        self.extras.insert(pid_u32, extras);
+                        extras
+                    }
+                };
                 ProcessInfo {
                     pid: pid_u32,
                     name: proc.name().to_string(),
+                    parent_pid: proc.parent().map(|p| p.as_u32()),
                     memory_mb: proc.memory() as f64 / 1024.0 / 1024.0,
                     working_set_mb: proc.memory() as f64 / 1024.0 / 1024.0,
-                    status: states.get(&pid_u32).cloned().unwrap_or(ProcessStatus::Normal),
+                    swapped_mb: extras.swapped_mb,
+                    page_faults_per_sec: extras.faults.map(|f| f.faults_per_sec),
+                    major_faults_per_sec: extras.faults.and_then(|f| f.major_per_sec),
+                    status,
                     cpu_usage: proc.cpu_usage(),
+                    oom: extras.oom,
                 }
             })
             .collect();
+        self.fault_tracker.retain(|pid| self.system.process(Pid::from_u32(pid)).is_some());
+        self.extras.retain(|pid, _| self.system.process(Pid::from_u32(*pid)).is_some());
 
         processes.sort_by(|a, b| b.memory_mb.partial_cmp(&a.memory_mb).unwrap());
         processes
     }
 
-    pub fn pin_to_ram(&mut self, pid: u32, working_set_mb: usize) -> Result<String, String> {
-        unsafe {
-            let handle = OpenProcess(PROCESS_SET_QUOTA | PROCESS_QUERY_INFORMATION, false, pid)
-                .map_err(|e| format!("Không thể mở tiến trình: {:?}", e))?;
+    // Các thao tác cần quyền chạy trong tiến trình này hoặc qua helper (xem privileged.rs)
+    pub fn set_executor(&mut self, executor: Executor) {
+        self.executor = executor;
+    }
 
-            let min_size = (working_set_mb * 1024 * 1024) as usize;
-            let max_size = (working_set_mb * 2 * 1024 * 1024) as usize;
+    // Capability không đổi nhưng quyền ghi cgroup có thể đổi (VD: tiến trình bị chuyển cgroup)
+    pub fn refresh_permissions(&mut self) {
+        self.permissions = Permissions::detect();
+        self.permissions.detect_targets(self.watched.iter().copied());
+    }
 
-            // Sử dụng SetProcessWorkingSetSizeEx thay vì SetProcessWorkingSetSize
-            SetProcessWorkingSetSizeEx(handle, min_size, max_size, 0)
-                .map_err(|e| format!("Không thể đặt working set: {:?}", e))?;
+    pub fn permissions(&self) -> Permissions {
+        match self.executor {
+            Executor::InProcess => self.permissions.clone(),
+            Executor::Helper(_) => Permissions::default(),
+        }
+    }
 
-            SetPriorityClass(handle, HIGH_PRIORITY_CLASS)
-                .map_err(|e| format!("Không thể đặt priority: {:?}", e))?;
+    fn ensure_permitted(&self, action: &ProcessAction, pid: u32) -> Result<(), String> {
+        match self.executor {
+            Executor::InProcess => self.permissions.check(action, pid),
+            Executor::Helper(_) => Ok(()),
+        }
+    }
 
-            let _ = CloseHandle(handle);
+    pub fn helper_status(&mut self) -> HelperStatus {
+        match &mut self.executor {
+            Executor::InProcess => HelperStatus::InProcess,
+            Executor::Helper(client) => client.status(),
+        }
+    }
 
-            self.process_states.lock().unwrap().insert(pid, ProcessStatus::Pinned);
-            Ok(format!("✅ Đã ghim PID {} vào RAM ({} MB)", pid, working_set_mb))
+    pub fn pin_to_ram(&mut self, pid: u32, working_set_mb: usize) -> Result<String, String> {
+        self.executor.execute(&PrivilegedOp::Pin { pid, working_set_mb })?;
+        self.process_states.lock().unwrap().insert(pid, ProcessStatus::Pinned);
+        let mut message = t!("msg-pinned", pid = pid, mb = working_set_mb);
+        if cfg!(target_os = "linux") && self.oom.protect_pinned {
+            self.append_oom_result(&mut message, pid, self.oom.pinned_score_adj);
         }
+        Ok(message)
+    }
+
+    pub fn set_oom(&mut self, config: OomConfig) {
+        self.oom = config;
+    }
+
+    fn set_oom_score_adj(&mut self, pid: u32, value: i32) -> Result<(), String> {
+        let original = oom::score_adj(pid)?;
+        self.executor.execute(&PrivilegedOp::SetOomScoreAdj { pid, value })?;
+        self.oom_originals.entry(pid).or_insert(original);
+        Ok(())
+    }
+
+    // Đổi oom_score_adj là phần phụ: lỗi chỉ thành cảnh báo, Pin/Limit vẫn tính là thành công
+    fn append_oom_result(&mut self, message: &mut String, pid: u32, value: i32) {
+        let line = match self.set_oom_score_adj(pid, value) {
+            Ok(()) => t!("msg-oom-adjusted", value = value.to_string()),
+            Err(e) => t!("msg-oom-failed", error = e),
+        };
+        message.push('\n');
+        message.push_str(&line);
+    }
+
+    pub fn set_pageout(&mut self, config: PageoutConfig) {
+        self.pageout = config;
+    }
+
+    pub fn take_pageout_report(&mut self) -> Option<PageoutReport> {
+        self.last_pageout.take()
     }
 
     pub fn trim_working_set(&mut self, pid: u32) -> Result<String, String> {
-        unsafe {
-            let handle = OpenProcess(PROCESS_ALL_ACCESS, false, pid)
-                .map_err(|e| format!("Không thể mở tiến trình: {:?}", e))?;
+        if cfg!(target_os = "linux") {
+            return self.page_out(pid);
+        }
+        let before_ws = self.working_set_mb(pid)?;
+        self.empty_working_set(pid)?;
 
-            let before = self.get_process_memory_info_internal(handle)?;
-            let before_ws = before.WorkingSetSize as f64 / 1024.0 / 1024.0;
+        std::thread::sleep(std::time::Duration::from_millis(300));
 
-            // EmptyWorkingSet nằm trong ProcessStatus module
-            EmptyWorkingSet(handle)
-                .map_err(|e| format!("Không thể trim working set: {:?}", e))?;
+        let after_ws = self.working_set_mb(pid)?;
+        let freed = (before_ws - after_ws).max(0.0);
 
-            SetPriorityClass(handle, IDLE_PRIORITY_CLASS)
-                .map_err(|e| format!("Không thể đặt priority: {:?}", e))?;
+        Ok(t!(
+            "msg-trimmed",
+            pid = pid,
+            before = num(before_ws),
+            after = num(after_ws),
+            freed = num(freed)
+        ))
+    }
 
-            std::thread::sleep(std::time::Duration::from_millis(300));
+    // Đọc memory map trước/sau để báo cáo theo loại vùng nhớ; process_madvise chạy đồng bộ nên không cần chờ
+    fn page_out(&mut self, pid: u32) -> Result<String, String> {
+        let before = MemoryMap::read(pid)?;
+        self.empty_working_set(pid)?;
+        let after = MemoryMap::read(pid)?;
+        let report = PageoutReport::new(pid, &self.pageout, &before, &after);
+        let message = t!(
+            "msg-paged-out",
+            pid = pid,
+            hint = report.hint.label(),
+            freed = memory_map::format_kb(report.freed_kb())
+        );
+        self.last_pageout = Some(report);
+        Ok(message)
+    }
 
-            let after = self.get_process_memory_info_internal(handle)?;
-            let after_ws = after.WorkingSetSize as f64 / 1024.0 / 1024.0;
-            let freed = (before_ws - after_ws).max(0.0);
+    fn empty_working_set(&mut self, pid: u32) -> Result<(), String> {
+        let op = if cfg!(target_os = "linux") {
+            PrivilegedOp::Pageout {
+                pid,
+                hint: self.pageout.hint,
+                regions: self.pageout.regions.clone(),
+            }
+        } else {
+            PrivilegedOp::Trim { pid }
+        };
+        self.executor.execute(&op)?;
+        self.process_states.lock().unwrap().insert(pid, ProcessStatus::Trimmed);
+        Ok(())
+    }
+
+    // Working set hiện tại (MB) của một tiến trình; trên Linux là VmRSS trong /proc/<pid>/status
+    pub fn working_set_mb(&self, pid: u32) -> Result<f64, String> {
+        if cfg!(target_os = "linux") {
+            let path = format!("/proc/{}/status", pid);
+            let status = std::fs::read_to_string(&path)
+                .map_err(|e| t!("err-read-file", path = path.as_str(), error = e.to_string()))?;
+            // Luồng của kernel không có dòng VmRSS
+            let rss_kb: u64 = status
+                .lines()
+                .find_map(|line| line.strip_prefix("VmRSS:"))
+                .and_then(|value| value.trim().trim_end_matches("kB").trim().parse().ok())
+                .ok_or_else(|| t!("err-read-file", path = path.as_str(), error = "VmRSS"))?;
+            return Ok(rss_kb as f64 / 1024.0);
+        }
+        unsafe {
+            let handle = OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, false, pid)
+                .map_err(|e| t!("err-open-process", error = format!("{:?}", e)))?;
 
+            let pmc = self.get_process_memory_info_internal(handle);
             let _ = CloseHandle(handle);
+            Ok(pmc?.WorkingSetSize as f64 / 1024.0 / 1024.0)
+        }
+    }
 
-            self.process_states.lock().unwrap().insert(pid, ProcessStatus::Trimmed);
-            Ok(format!(
-                "✅ Đã trim PID {}\n📉 Trước: {:.1} MB → Sau: {:.1} MB\n💾 Giải phóng: {:.1} MB",
-                pid, before_ws, after_ws, freed
-            ))
+    pub fn limit_resources(
+        &mut self,
+        pid: u32,
+        max_ws_mb: usize,
+        mode: LimitMode,
+        breach: BreachAction,
+    ) -> Result<String, String> {
+        self.executor.execute(&PrivilegedOp::Limit { pid, max_ws_mb, mode, breach })?;
+        self.process_states.lock().unwrap().insert(pid, ProcessStatus::Limited);
+        let mut message = match mode {
+            LimitMode::WorkingSet => t!("msg-limited", pid = pid, mb = max_ws_mb),
+            LimitMode::Hard => t!("msg-limited-hard", pid = pid, mb = max_ws_mb, breach = breach.label()),
+        };
+        if cfg!(target_os = "linux") && self.oom.expose_limited {
+            self.append_oom_result(&mut message, pid, self.oom.limited_score_adj);
         }
+        Ok(message)
     }
 
-    pub fn limit_resources(&mut self, pid: u32, max_ws_mb: usize) -> Result<String, String> {
-        unsafe {
-            let handle = OpenProcess(PROCESS_SET_QUOTA | PROCESS_QUERY_INFORMATION, false, pid)
-                .map_err(|e| format!("Không thể mở tiến trình: {:?}", e))?;
+    pub fn limit_swap(&mut self, pid: u32, swap_max_mb: Option<u64>, zswap_max_mb: Option<u64>) -> Result<String, String> {
+        self.executor.execute(&PrivilegedOp::SwapLimit { pid, swap_max_mb, zswap_max_mb })?;
+        // Limit/khởi chạy có giới hạn vẫn là trạng thái chính, giới hạn swap chỉ thêm vào cgroup đó
+        let mut states = self.process_states.lock().unwrap();
+        if !matches!(states.get(&pid), Some(ProcessStatus::Limited | ProcessStatus::Launched | ProcessStatus::Frozen)) {
+            states.insert(pid, ProcessStatus::SwapLimited);
+        }
+        Ok(t!(
+            "msg-swap-limited",
+            pid = pid,
+            swap = memory_cap::swap_limit_label(swap_max_mb),
+            zswap = memory_cap::swap_limit_label(zswap_max_mb)
+        ))
+    }
+
+    pub fn freeze_process(&mut self, pid: u32) -> Result<String, String> {
+        let newly_frozen = !self.frozen.contains(pid);
+        if newly_frozen {
+            self.executor.execute(&PrivilegedOp::Freeze { pid })?;
+            self.frozen.insert(pid, self.process_name(pid));
+        }
+        self.process_states.lock().unwrap().insert(pid, ProcessStatus::Frozen);
+        // Lưu sau khi đã đánh dấu: lỗi ghi file thì tiến trình vẫn đang bị đóng băng
+        if newly_frozen {
+            self.frozen.store(&FrozenProcesses::path())?;
+        }
+        Ok(t!("msg-frozen", pid = pid))
+    }
 
-            let max_size = (max_ws_mb * 1024 * 1024) as usize;
-            let min_size = (max_ws_mb / 2 * 1024 * 1024) as usize;
+    // Đóng băng trước để tiến trình không kịp fault lại trong lúc Trim
+    pub fn hibernate_process(&mut self, pid: u32) -> Result<String, String> {
+        let was_frozen = self.frozen.contains(pid);
+        self.freeze_process(pid)?;
+        let trimmed = match self.trim_working_set(pid) {
+            Ok(message) => message,
+            Err(e) => {
+                if !was_frozen {
+                    let _ = self.thaw_process(pid);
+                }
+                return Err(e);
+            }
+        };
+        self.process_states.lock().unwrap().insert(pid, ProcessStatus::Frozen);
+        Ok(t!("msg-hibernated", pid = pid, message = trimmed))
+    }
 
-            SetProcessWorkingSetSizeEx(handle, min_size, max_size, 0)
-                .map_err(|e| format!("Không thể giới hạn working set: {:?}", e))?;
+    fn thaw_process(&mut self, pid: u32) -> Result<(), String> {
+        if self.frozen.contains(pid) {
+            self.executor.execute(&PrivilegedOp::Thaw { pid })?;
+            self.frozen.remove(pid);
+            self.process_states.lock().unwrap().remove(&pid);
+            self.frozen.store(&FrozenProcesses::path())?;
+        }
+        Ok(())
+    }
 
-            SetPriorityClass(handle, IDLE_PRIORITY_CLASS)
-                .map_err(|e| format!("Không thể đặt priority: {:?}", e))?;
+    // Khi đóng chương trình: không để lại tiến trình bị đóng băng mà không còn ai rã đông.
+    // PID rã đông lỗi vẫn nằm trong frozen.toml để lần chạy sau xử lý tiếp
+    pub fn thaw_all(&mut self) -> Vec<String> {
+        self.frozen
+            .pids()
+            .into_iter()
+            .filter_map(|pid| self.thaw_process(pid).err())
+            .collect()
+    }
 
-            let _ = CloseHandle(handle);
+    // Early-OOM: tiến trình bị đóng băng phải được rã đông thì mới xử lý được SIGTERM
+    pub fn terminate_process(&mut self, pid: u32, force: bool) -> Result<(), String> {
+        if !force {
+            self.thaw_process(pid)?;
+        }
+        self.executor.execute(&PrivilegedOp::Terminate { pid, force })?;
+        self.forget_process(pid);
+        Ok(())
+    }
+
+    // Không qua executor: chương trình mới phải chạy dưới quyền người dùng hiện tại chứ không phải của helper
+    pub fn launch(
+        &mut self,
+        request: &LaunchRequest,
+        on_exit: impl FnOnce(LaunchExit) + Send + 'static,
+    ) -> Result<String, String> {
+        let launched = launch::spawn(request, on_exit)?;
+        let pid = launched.pid;
+        self.launched.insert(pid, launched);
+        self.process_states.lock().unwrap().insert(pid, ProcessStatus::Launched);
+        if let Some(priority) = request.policy.priority {
+            self.priority_overrides.insert(pid, priority);
+        }
+        self.request_full_refresh();
+        Ok(t!("msg-launched", pid = pid, command = request.command.as_str()))
+    }
+
+    // Chương trình đã thoát: dọn cgroup / job object cùng mọi trạng thái của PID
+    pub fn finish_launch(&mut self, pid: u32) {
+        self.launched.remove(&pid);
+        self.forget_process(pid);
+    }
 
-            self.process_states.lock().unwrap().insert(pid, ProcessStatus::Limited);
-            Ok(format!("✅ Đã giới hạn PID {} (Max: {} MB, Priority: IDLE)", pid, max_ws_mb))
+    fn forget_process(&mut self, pid: u32) {
+        self.process_states.lock().unwrap().remove(&pid);
+        if self.frozen.remove(pid) {
+            let _ = self.frozen.store(&FrozenProcesses::path());
         }
+        self.applied_actions.remove(&pid);
+        self.priority_overrides.remove(&pid);
+        self.oom_originals.remove(&pid);
+        self.request_full_refresh();
     }
 
     pub fn restore_process(&mut self, pid: u32) -> Result<String, String> {
-        unsafe {
-            let handle = OpenProcess(PROCESS_SET_QUOTA | PROCESS_QUERY_INFORMATION, false, pid)
-                .map_err(|e| format!("Không thể mở tiến trình: {:?}", e))?;
+        // Rã đông trước: tiến trình bị đóng băng thì các thay đổi khác không còn ý nghĩa
+        self.thaw_process(pid)?;
+        if let Some(launched) = self.launched.get(&pid) {
+            launched.release_limits()?;
+        }
+        self.executor.execute(&PrivilegedOp::Restore { pid })?;
+        if let Some(&value) = self.oom_originals.get(&pid) {
+            self.executor.execute(&PrivilegedOp::SetOomScoreAdj { pid, value })?;
+            self.oom_originals.remove(&pid);
+        }
+        self.process_states.lock().unwrap().remove(&pid);
+        self.applied_actions.remove(&pid);
+        self.priority_overrides.remove(&pid);
+        Ok(t!("msg-restored", pid = pid))
+    }
 
-            // Reset working set về auto (-1, -1)
-            SetProcessWorkingSetSizeEx(handle, usize::MAX, usize::MAX, 0)
-                .map_err(|e| format!("Không thể reset working set: {:?}", e))?;
+    pub fn apply_action(&mut self, pid: u32, action: &ProcessAction) -> Result<String, String> {
+        self.ensure_permitted(action, pid)?;
+        let before_mb = self.working_set_mb(pid).ok();
+        let before_faults = page_faults::process_faults(pid).map(|f| f.total);
+        let result = match action {
+            ProcessAction::Pin { working_set_mb } => self.pin_to_ram(pid, *working_set_mb),
+            ProcessAction::Trim => self.trim_working_set(pid),
+            ProcessAction::Limit { max_ws_mb, mode, breach } => self.limit_resources(pid, *max_ws_mb, *mode, *breach),
+            ProcessAction::SwapLimit { swap_max_mb, zswap_max_mb } => self.limit_swap(pid, *swap_max_mb, *zswap_max_mb),
+            ProcessAction::Freeze => self.freeze_process(pid),
+            ProcessAction::Hibernate => self.hibernate_process(pid),
+            ProcessAction::Restore => self.restore_process(pid),
+        }?;
 
-            SetPriorityClass(handle, NORMAL_PRIORITY_CLASS)
-                .map_err(|e| format!("Không thể đặt priority: {:?}", e))?;
+        if *action != ProcessAction::Restore {
+            self.applied_actions.insert(pid, action.clone());
+        }
+        // Pin/Trim/Limit tự đặt priority riêng, ghi đè priority trước đó; Freeze và SwapLimit không đụng tới priority
+        if !matches!(action, ProcessAction::Restore | ProcessAction::Freeze | ProcessAction::SwapLimit { .. }) {
+            self.priority_overrides.remove(&pid);
+        }
+        if let Some(before_mb) = before_mb {
+            self.start_effect(pid, action, before_mb, before_faults);
+        }
+        Ok(result)
+    }
 
-            let _ = CloseHandle(handle);
+    fn start_effect(&mut self, pid: u32, action: &ProcessAction, before_mb: f64, before_faults: Option<u64>) {
+        let name = self.process_name(pid);
+        self.effects.start(pid, name, action.clone(), before_mb, before_faults);
+    }
 
-            self.process_states.lock().unwrap().remove(&pid);
-            Ok(format!("✅ Đã khôi phục PID {} về trạng thái bình thường", pid))
+    fn process_name(&self, pid: u32) -> String {
+        self.system
+            .process(Pid::from_u32(pid))
+            .map(|p| p.name().to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn set_effect_window(&mut self, window: Duration, interval: Duration) {
+        self.effects.set_window(window, interval);
+    }
+
+    // Thời điểm sớm nhất cần đo lại một hành động đang theo dõi, None nếu không có gì để đo
+    pub fn next_effect_sample(&self) -> Option<Instant> {
+        self.effects.next_sample()
+    }
+
+    pub fn sample_effects(&mut self) {
+        let now = Instant::now();
+        for pid in self.effects.due(now) {
+            let working_set_mb = self.working_set_mb(pid).ok();
+            let faults = page_faults::process_faults(pid).map(|f| f.total);
+            self.effects.record(pid, now, working_set_mb, faults);
+        }
+    }
+
+    pub fn effect_reports(&self) -> Vec<EffectReport> {
+        self.effects.reports()
+    }
+
+    // Đặt priority mà không đổi working set
+    pub fn set_priority(&mut self, pid: u32, priority: Priority) -> Result<String, String> {
+        self.set_priority_class(pid, priority)?;
+        self.priority_overrides.insert(pid, priority);
+        Ok(t!("msg-priority-set", pid = pid, priority = priority.as_str()))
+    }
+
+    fn set_priority_class(&mut self, pid: u32, priority: Priority) -> Result<(), String> {
+        if let Executor::InProcess = self.executor {
+            self.permissions.check_priority(priority)?;
+        }
+        self.executor.execute(&PrivilegedOp::SetPriority { pid, priority })
+    }
+
+    // Áp dụng profile; nếu đang có profile khác thì hoàn tác nó trước (chuyển profile)
+    pub fn apply_profile(&mut self, profile: &Profile) -> ProfileReport {
+        if self.active_profile.is_some() {
+            self.revert_profile();
+        }
+
+        self.refresh();
+        let targets: Vec<(u32, String)> = self
+            .system
+            .processes()
+            .iter()
+            .map(|(pid, proc)| (pid.as_u32(), proc.name().to_string()))
+            .collect();
+
+        let mut report = ProfileReport::new(&profile.name, ProfileEvent::Applied);
+        let mut session = ProfileSession {
+            profile_name: profile.name.clone(),
+            changes: Vec::new(),
+        };
+
+        for rule in profile.rules.iter() {
+            for (pid, name) in targets.iter() {
+                // Một PID chỉ chịu tác động của rule đầu tiên khớp với nó
+                if !rule.target.matches(*pid, name) || session.changes.iter().any(|c| c.pid == *pid) {
+                    continue;
+                }
+
+                session.changes.push(ProfileChange {
+                    pid: *pid,
+                    name: name.clone(),
+                    previous: self.pid_snapshot(*pid),
+                });
+
+                let mut result = self.apply_action(*pid, &rule.action);
+                if let (Ok(msg), Some(priority)) = (&result, rule.priority) {
+                    result = self
+                        .set_priority(*pid, priority)
+                        .map(|_| t!("msg-with-priority", message = msg.as_str(), priority = priority.as_str()));
+                }
+                report.entries.push((*pid, name.clone(), result));
+            }
+        }
+
+        self.active_profile = Some(session);
+        self.profile_history.push(report.clone());
+        report
+    }
+
+    // Đưa mọi PID mà profile đang chạy đã đụng tới về đúng trạng thái trước đó
+    pub fn revert_profile(&mut self) -> Option<ProfileReport> {
+        let session = self.active_profile.take()?;
+        let mut report = ProfileReport::new(&session.profile_name, ProfileEvent::Reverted);
+
+        self.refresh();
+        for change in session.changes.iter().rev() {
+            let current_name = self
+                .system
+                .process(Pid::from_u32(change.pid))
+                .map(|p| p.name().to_string());
+
+            let result = if !change.is_same_process(current_name.as_deref()) {
+                Err(t!("err-process-gone"))
+            } else {
+                self.restore_snapshot(change.pid, &change.previous)
+            };
+            report.entries.push((change.pid, change.name.clone(), result));
+        }
+
+        self.profile_history.push(report.clone());
+        Some(report)
+    }
+
+    fn pid_snapshot(&self, pid: u32) -> PidSnapshot {
+        PidSnapshot {
+            status: self.process_states.lock().unwrap().get(&pid).cloned(),
+            action: self.applied_actions.get(&pid).cloned(),
+            priority: self.priority_overrides.get(&pid).copied(),
+            os_priority: privileged::os_priority(pid),
+        }
+    }
+
+    fn restore_snapshot(&mut self, pid: u32, snapshot: &PidSnapshot) -> Result<String, String> {
+        self.restore_process(pid)?;
+
+        match &snapshot.action {
+            // Trim không thể "trả lại" bộ nhớ, chỉ giữ lại priority IDLE và trạng thái
+            Some(ProcessAction::Trim) => {
+                self.set_priority_class(pid, Priority::Idle)?;
+                self.applied_actions.insert(pid, ProcessAction::Trim);
+            }
+            Some(action) => {
+                self.apply_action(pid, action)?;
+            }
+            None => {}
+        }
+        // restore_process đưa priority về NORMAL: trả lại đúng giá trị lúc trước (VD: nice 5 do người dùng đặt)
+        match (snapshot.priority, snapshot.os_priority) {
+            (Some(priority), _) => {
+                self.set_priority(pid, priority)?;
+            }
+            (None, Some(value)) => self.executor.execute(&PrivilegedOp::SetOsPriority { pid, value })?,
+            (None, None) => {}
+        }
+
+        let mut states = self.process_states.lock().unwrap();
+        match &snapshot.status {
+            Some(status) => states.insert(pid, status.clone()),
+            None => states.remove(&pid),
+        };
+        Ok(t!("msg-restored-profile", pid = pid))
+    }
+
+    pub fn active_profile(&self) -> Option<&ProfileSession> {
+        self.active_profile.as_ref()
+    }
+
+    // Dùng khi khởi động: nạp lại profile session đã lưu từ lần chạy trước
+    pub fn set_active_profile(&mut self, session: Option<ProfileSession>) {
+        self.active_profile = session;
+    }
+
+    pub fn set_cgroup_originals(&mut self, originals: CgroupOriginals) {
+        self.cgroup_originals = originals;
+    }
+
+    // Tiến trình còn bị đóng băng từ lần chạy trước (bị tắt đột ngột) hiện lại là Frozen để Restore rã đông được
+    pub fn set_frozen(&mut self, mut frozen: FrozenProcesses) -> Result<(), String> {
+        let changed = frozen.retain_running(|pid| self.system.process(Pid::from_u32(pid)).map(|p| p.name().to_string()));
+        let mut states = self.process_states.lock().unwrap();
+        for pid in frozen.pids() {
+            states.insert(pid, ProcessStatus::Frozen);
+        }
+        drop(states);
+        self.frozen = frozen;
+        if changed {
+            self.frozen.store(&FrozenProcesses::path())?;
+        }
+        Ok(())
+    }
+
+    pub fn profile_history(&self) -> &[ProfileReport] {
+        &self.profile_history
+    }
+
+    // Áp dụng một hành động cho nhiều tiến trình (VD: cả cây tiến trình con)
+    pub fn apply_to_pids(&mut self, pids: &[u32], action: &ProcessAction) -> Result<String, String> {
+        let mut failures = Vec::new();
+        for &pid in pids {
+            if let Err(e) = self.apply_action(pid, action) {
+                failures.push(format!("PID {}: {}", pid, e));
+            }
+        }
+
+        let succeeded = pids.len() - failures.len();
+        if succeeded == 0 && !pids.is_empty() {
+            return Err(format!(
+                "{}\n{}",
+                t!("msg-action-failed-all", action = action.label(), count = pids.len()),
+                failures.join("\n")
+            ));
+        }
+
+        let mut msg = t!(
+            "msg-action-summary",
+            action = action.label(),
+            succeeded = succeeded,
+            total = pids.len()
+        );
+        if !failures.is_empty() {
+            msg.push_str(&format!("\n{}\n{}", t!("msg-errors"), failures.join("\n")));
+        }
+        Ok(msg)
+    }
+
+    // Áp dụng hành động cho mọi instance của một ứng dụng, đo RAM trước/sau cho từng tiến trình
+    pub fn apply_to_group(&mut self, group: &ProcessGroup, action: &ProcessAction) -> GroupActionReport {
+        let mut entries: Vec<GroupActionEntry> = group
+            .pids()
+            .into_iter()
+            .map(|pid| GroupActionEntry {
+                pid,
+                before_mb: self.working_set_mb(pid).ok(),
+                after_mb: None,
+                error: None,
+            })
+            .collect();
+
+        for entry in entries.iter_mut() {
+            // Trim từng PID không cần chờ riêng, chỉ chờ một lần cho cả nhóm bên dưới
+            let result = match action {
+                ProcessAction::Trim => {
+                    let before_faults = page_faults::process_faults(entry.pid).map(|f| f.total);
+                    self.ensure_permitted(action, entry.pid).and_then(|_| self.empty_working_set(entry.pid)).map(|_| {
+                        self.applied_actions.insert(entry.pid, ProcessAction::Trim);
+                        if let Some(before_mb) = entry.before_mb {
+                            self.start_effect(entry.pid, action, before_mb, before_faults);
+                        }
+                    })
+                }
+                _ => self.apply_action(entry.pid, action).map(|_| ()),
+            };
+            if let Err(e) = result {
+                entry.error = Some(e);
+            }
+        }
+
+        std::thread::sleep(std::time::Duration::from_millis(300));
+
+        for entry in entries.iter_mut() {
+            entry.after_mb = self.working_set_mb(entry.pid).ok();
+        }
+
+        GroupActionReport {
+            group_name: group.name.clone(),
+            action: action.clone(),
+            entries,
         }
     }
 
+    // Hành động lên cả cgroup (Linux): systemd slice, container... thay vì từng PID
+    pub fn apply_to_cgroup(&mut self, path: &Path, action: &ProcessAction) -> Result<String, String> {
+        let cgroup = path.display().to_string();
+        let originals_path = CgroupOriginals::path();
+        if *action == ProcessAction::Restore {
+            let saved = self
+                .cgroup_originals
+                .get(path)
+                .ok_or_else(|| t!("err-cgroup-nothing-saved", cgroup = cgroup.as_str()))?;
+            self.executor.execute(&PrivilegedOp::CgroupRestore { path: path.to_path_buf(), values: saved.values.clone() })?;
+            self.cgroup_originals.forget(path);
+            self.cgroup_originals.store(&originals_path)?;
+            return Ok(t!("msg-cgroup-restored", cgroup = cgroup));
+        }
+
+        // Lưu giá trị gốc ra file trước khi ghi, để Restore đúng kể cả khi chương trình bị tắt giữa chừng
+        let files = cgroup_tree::files_written(action);
+        if self.cgroup_originals.remember(path, &files, |file| cgroup_tree::read_value(path, file)) {
+            self.cgroup_originals.store(&originals_path)?;
+        }
+        let before_kb = cgroup_tree::current_kb(path);
+        self.executor.execute(&PrivilegedOp::Cgroup { path: path.to_path_buf(), action: action.clone() })?;
+        Ok(match (action, before_kb, cgroup_tree::current_kb(path)) {
+            (ProcessAction::Trim, Some(before), Some(after)) => t!(
+                "msg-cgroup-trimmed",
+                cgroup = cgroup,
+                before = memory_map::format_kb(before),
+                after = memory_map::format_kb(after)
+            ),
+            _ => t!("msg-cgroup-applied", action = action.label(), cgroup = cgroup),
+        })
+    }
+
     fn get_process_memory_info_internal(&self, handle: HANDLE) -> Result<PROCESS_MEMORY_COUNTERS, String> {
         unsafe {
             let mut pmc = PROCESS_MEMORY_COUNTERS::default();
@@ -216,7 +1046,7 @@ impl RamManager {
                 &mut pmc,
                 std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32,
             )
-            .map_err(|e| format!("Không thể lấy thông tin bộ nhớ: {:?}", e))?;
+            .map_err(|e| t!("err-memory-info", error = format!("{:?}", e)))?;
             Ok(pmc)
         }
     }
@@ -227,10 +1057,13 @@ impl RamManager {
             pinned_count: states.values().filter(|s| **s == ProcessStatus::Pinned).count(),
             trimmed_count: states.values().filter(|s| **s == ProcessStatus::Trimmed).count(),
             limited_count: states.values().filter(|s| **s == ProcessStatus::Limited).count(),
+            frozen_count: states.values().filter(|s| **s == ProcessStatus::Frozen).count(),
+            launched_count: self.launched.len(),
         }
     }
 }
 
+#[derive(Clone, Debug, Default)]
 pub struct SystemInfo {
     pub total_ram_gb: f64,
     pub used_ram_gb: f64,
@@ -238,8 +1071,37 @@ pub struct SystemInfo {
     pub process_count: usize,
 }
 
+#[derive(Clone, Debug, Default)]
 pub struct RamStatistics {
     pub pinned_count: usize,
     pub trimmed_count: usize,
     pub limited_count: usize,
-}
\ No newline at end of file
+    pub frozen_count: usize,
+    pub launched_count: usize,
+}
+#[cfg(test)]
+mod tests {
+    // Đổi nhiều lần vẫn giữ giá trị trước lần đổi đầu tiên, Restore trả lại đúng giá trị đó
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn restore_returns_the_original_oom_score_adj() {
+        use crate::oom;
+        use super::RamManager;
+
+        let mut child = std::process::Command::new("sleep").arg("30").spawn().unwrap();
+        let pid = child.id();
+        let original = oom::score_adj(pid).unwrap();
+        let mut manager = RamManager::new();
+
+        manager.set_oom_score_adj(pid, (original + 100).min(oom::SCORE_ADJ_MAX)).unwrap();
+        manager.set_oom_score_adj(pid, (original + 200).min(oom::SCORE_ADJ_MAX)).unwrap();
+        assert_eq!(manager.oom_originals.get(&pid), Some(&original));
+
+        manager.restore_process(pid).unwrap();
+        assert_eq!(oom::score_adj(pid), Ok(original));
+        assert!(!manager.oom_originals.contains_key(&pid));
+
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
@@ -0,0 +1,387 @@
+use crate::ram_manager::ProcessInfo;
+
+const TOP_SWAPPED_PROCESSES: usize = 10;
+
+#[derive(Clone, Debug)]
+pub struct SwapDevice {
+    pub name: String,
+    pub kind: String,
+    pub size_kb: u64,
+    pub used_kb: u64,
+    pub priority: Option<i32>,
+}
+
+// zram, zswap hoặc Memory Compression của Windows
+#[derive(Clone, Debug)]
+pub struct CompressedMemory {
+    pub name: String,
+    // Windows không cho biết dung lượng gốc trước khi nén
+    pub original_kb: Option<u64>,
+    pub compressed_kb: u64,
+    // RAM thực tế dùng cho vùng nén, gồm cả metadata của allocator
+    pub ram_used_kb: Option<u64>,
+}
+
+impl CompressedMemory {
+    pub fn ratio(&self) -> Option<f64> {
+        let original = self.original_kb?;
+        (self.compressed_kb > 0).then(|| original as f64 / self.compressed_kb as f64)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SwappedProcess {
+    pub pid: u32,
+    pub name: String,
+    pub swapped_mb: f64,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SwapStats {
+    pub devices: Vec<SwapDevice>,
+    pub compressed: Vec<CompressedMemory>,
+    // None ở lần sample đầu tiên (chưa có mốc để tính tốc độ) hoặc khi không đọc được counter
+    pub swap_in_kb_per_sec: Option<f64>,
+    pub swap_out_kb_per_sec: Option<f64>,
+    pub top_processes: Vec<SwappedProcess>,
+}
+
+// Giữ counter của lần sample trước để tính tốc độ swap-in/out, thuộc về worker thread
+pub struct SwapMonitor {
+    counters: platform::RateCounters,
+}
+
+impl SwapMonitor {
+    pub fn new() -> Self {
+        SwapMonitor {
+            counters: platform::RateCounters::new(),
+        }
+    }
+
+    pub fn sample(&mut self, processes: &[ProcessInfo]) -> Result<SwapStats, String> {
+        let (swap_in_kb_per_sec, swap_out_kb_per_sec) = self.counters.sample();
+
+        let mut top_processes: Vec<SwappedProcess> = processes
+            .iter()
+            .filter_map(|p| {
+                let swapped_mb = p.swapped_mb.filter(|mb| *mb > 0.0)?;
+                Some(SwappedProcess {
+                    pid: p.pid,
+                    name: p.name.clone(),
+                    swapped_mb,
+                })
+            })
+            .collect();
+        top_processes.sort_by(|a, b| b.swapped_mb.total_cmp(&a.swapped_mb));
+        top_processes.truncate(TOP_SWAPPED_PROCESSES);
+
+        Ok(SwapStats {
+            devices: platform::devices()?,
+            compressed: platform::compressed(processes),
+            swap_in_kb_per_sec,
+            swap_out_kb_per_sec,
+            top_processes,
+        })
+    }
+}
+
+// Dung lượng của tiến trình đang nằm trong swap, None nếu hệ điều hành không cung cấp
+pub fn process_swapped_kb(pid: u32) -> Option<u64> {
+    platform::process_swapped_kb(pid)
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::fs;
+    use std::time::Instant;
+    use super::{CompressedMemory, SwapDevice};
+    use crate::i18n::t;
+    use crate::ram_manager::ProcessInfo;
+    use crate::system_memory;
+
+    // pswpin/pswpout trong /proc/vmstat tính theo page của kernel: 4 KB trên x86, có thể 16/64 KB trên ARM/POWER
+    fn page_kb() -> u64 {
+        let bytes = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+        if bytes > 0 {
+            bytes as u64 / 1024
+        } else {
+            4
+        }
+    }
+
+    pub struct RateCounters {
+        page_kb: u64,
+        previous: Option<(Instant, u64, u64)>,
+    }
+
+    impl RateCounters {
+        pub fn new() -> Self {
+            RateCounters::with_page_kb(page_kb())
+        }
+
+        pub fn with_page_kb(page_kb: u64) -> Self {
+            RateCounters { page_kb, previous: None }
+        }
+
+        pub fn sample(&mut self) -> (Option<f64>, Option<f64>) {
+            let Some((pages_in, pages_out)) = system_memory::read_vmstat()
+                .and_then(|vmstat| Some((*vmstat.get("pswpin")?, *vmstat.get("pswpout")?)))
+            else {
+                return (None, None);
+            };
+            self.record(Instant::now(), pages_in, pages_out)
+        }
+
+        // KB/giây từ lần ghi trước; counter bị reset (giảm) thì tính như không có swap
+        pub fn record(&mut self, now: Instant, pages_in: u64, pages_out: u64) -> (Option<f64>, Option<f64>) {
+            let page_kb = self.page_kb as f64;
+            let rates = self.previous.and_then(|(at, prev_in, prev_out)| {
+                let secs = now.duration_since(at).as_secs_f64();
+                (secs > 0.0).then(|| {
+                    (
+                        pages_in.saturating_sub(prev_in) as f64 * page_kb / secs,
+                        pages_out.saturating_sub(prev_out) as f64 * page_kb / secs,
+                    )
+                })
+            });
+            self.previous = Some((now, pages_in, pages_out));
+            (rates.map(|r| r.0), rates.map(|r| r.1))
+        }
+    }
+
+    pub fn devices() -> Result<Vec<SwapDevice>, String> {
+        let path = "/proc/swaps";
+        let text = fs::read_to_string(path).map_err(|e| t!("err-read-file", path = path, error = e.to_string()))?;
+        Ok(parse_swaps(&text))
+    }
+
+    // Filename Type Size Used Priority, khoảng trắng trong tên file được escape thành \040
+    pub fn parse_swaps(text: &str) -> Vec<SwapDevice> {
+        text.lines()
+            .skip(1)
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                let [name, kind, size, used, priority] = fields.as_slice() else {
+                    return None;
+                };
+                Some(SwapDevice {
+                    name: name.replace("\\040", " "),
+                    kind: kind.to_string(),
+                    size_kb: size.parse().ok()?,
+                    used_kb: used.parse().ok()?,
+                    priority: priority.parse().ok(),
+                })
+            })
+            .collect()
+    }
+
+    pub fn compressed(_processes: &[ProcessInfo]) -> Vec<CompressedMemory> {
+        let mut compressed = zram_devices();
+        compressed.extend(zswap());
+        compressed
+    }
+
+    fn zram_devices() -> Vec<CompressedMemory> {
+        let Ok(entries) = fs::read_dir("/sys/block") else {
+            return Vec::new();
+        };
+        let mut devices: Vec<CompressedMemory> = entries
+            .flatten()
+            .filter(|entry| entry.file_name().to_string_lossy().starts_with("zram"))
+            .filter_map(|entry| {
+                let text = fs::read_to_string(entry.path().join("mm_stat")).ok()?;
+                parse_mm_stat(&entry.file_name().to_string_lossy(), &text)
+            })
+            .collect();
+        devices.sort_by(|a, b| a.name.cmp(&b.name));
+        devices
+    }
+
+    // mm_stat: orig_data_size compr_data_size mem_used_total ... (byte)
+    pub fn parse_mm_stat(name: &str, text: &str) -> Option<CompressedMemory> {
+        let values: Vec<u64> = text.split_whitespace().filter_map(|v| v.parse().ok()).collect();
+        let [original, compressed, ram_used, ..] = values.as_slice() else {
+            return None;
+        };
+        Some(CompressedMemory {
+            name: name.to_string(),
+            original_kb: Some(original / 1024),
+            compressed_kb: compressed / 1024,
+            ram_used_kb: Some(ram_used / 1024),
+        })
+    }
+
+    fn zswap() -> Option<CompressedMemory> {
+        let enabled = fs::read_to_string("/sys/module/zswap/parameters/enabled").ok()?;
+        if enabled.trim() != "Y" {
+            return None;
+        }
+        parse_zswap(&fs::read_to_string("/proc/meminfo").ok()?)
+    }
+
+    // Kernel >= 5.19 báo Zswap (sau nén) và Zswapped (trước nén) trong /proc/meminfo
+    pub fn parse_zswap(meminfo: &str) -> Option<CompressedMemory> {
+        let value = |key: &str| {
+            meminfo.lines().find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                (name == key).then(|| value.trim().trim_end_matches("kB").trim().parse::<u64>().ok())?
+            })
+        };
+        Some(CompressedMemory {
+            name: "zswap".to_string(),
+            original_kb: value("Zswapped"),
+            compressed_kb: value("Zswap")?,
+            ram_used_kb: None,
+        })
+    }
+
+    pub fn process_swapped_kb(pid: u32) -> Option<u64> {
+        let text = fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+        text.lines()
+            .find_map(|line| line.strip_prefix("VmSwap:"))
+            .and_then(|value| value.trim().trim_end_matches("kB").trim().parse().ok())
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::ffi::c_void;
+    use windows::core::{w, PCWSTR};
+    use windows::Win32::Foundation::{BOOL, TRUE};
+    use windows::Win32::System::ProcessStatus::{EnumPageFilesW, ENUM_PAGE_FILE_INFORMATION};
+    use super::{CompressedMemory, SwapDevice};
+    use crate::i18n::t;
+    use crate::pdh::PdhCounters;
+    use crate::ram_manager::ProcessInfo;
+
+    // Kích thước page file và counter PDH tính theo page 4 KB
+    const PAGE_KB: u64 = 4;
+
+    // "Pages Input/sec" / "Pages Output/sec" gồm cả page file lẫn file được ánh xạ,
+    // là số gần nhất với swap-in/out mà Windows cung cấp
+    pub struct RateCounters {
+        pdh: Option<PdhCounters>,
+    }
+
+    impl RateCounters {
+        pub fn new() -> Self {
+            RateCounters {
+                pdh: PdhCounters::open(&[w!("\\Memory\\Pages Input/sec"), w!("\\Memory\\Pages Output/sec")]),
+            }
+        }
+
+        pub fn sample(&mut self) -> (Option<f64>, Option<f64>) {
+            let Some(values) = self.pdh.as_mut().and_then(|pdh| pdh.sample()) else {
+                return (None, None);
+            };
+            let to_kb = |pages: f64| pages * PAGE_KB as f64;
+            (values[0].map(to_kb), values[1].map(to_kb))
+        }
+    }
+
+    pub fn devices() -> Result<Vec<SwapDevice>, String> {
+        let mut devices: Vec<SwapDevice> = Vec::new();
+        unsafe {
+            EnumPageFilesW(Some(page_file_callback), &mut devices as *mut _ as *mut c_void)
+                .map_err(|e| t!("err-page-files", error = format!("{:?}", e)))?;
+        }
+        Ok(devices)
+    }
+
+    unsafe extern "system" fn page_file_callback(
+        context: *mut c_void,
+        info: *mut ENUM_PAGE_FILE_INFORMATION,
+        file_name: PCWSTR,
+    ) -> BOOL {
+        let devices = &mut *(context as *mut Vec<SwapDevice>);
+        let info = &*info;
+        devices.push(SwapDevice {
+            name: file_name.to_string().unwrap_or_default(),
+            kind: "file".to_string(),
+            size_kb: info.TotalSize as u64 * PAGE_KB,
+            used_kb: info.TotalInUse as u64 * PAGE_KB,
+            priority: None,
+        });
+        TRUE
+    }
+
+    // Working set của tiến trình "Memory Compression" chính là dung lượng vùng nén
+    pub fn compressed(processes: &[ProcessInfo]) -> Vec<CompressedMemory> {
+        processes
+            .iter()
+            .filter(|p| p.name == "Memory Compression" || p.name == "MemCompression")
+            .map(|p| CompressedMemory {
+                name: "Memory Compression".to_string(),
+                original_kb: None,
+                compressed_kb: (p.memory_mb * 1024.0) as u64,
+                ram_used_kb: None,
+            })
+            .collect()
+    }
+
+    // Windows không tách được phần của từng tiến trình đang nằm trong page file
+    pub fn process_swapped_kb(_pid: u32) -> Option<u64> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_os = "linux")]
+    use std::time::{Duration, Instant};
+    #[cfg(target_os = "linux")]
+    use super::platform::{parse_mm_stat, parse_swaps, parse_zswap, RateCounters};
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn parse_swaps_reads_devices_and_unescapes_names() {
+        let swaps = "\
+Filename\t\t\t\tType\t\tSize\t\tUsed\t\tPriority
+/dev/nvme0n1p3                          partition\t8388604\t\t1048576\t\t-2
+/swap\\040file                           file\t\t2097148\t\t0\t\t-3
+/dev/zram0                              partition\t4194300\t\t524288\t\t100
+broken line
+";
+        let devices = parse_swaps(swaps);
+        assert_eq!(devices.len(), 3);
+        assert_eq!(devices[0].name, "/dev/nvme0n1p3");
+        assert_eq!(devices[0].kind, "partition");
+        assert_eq!((devices[0].size_kb, devices[0].used_kb, devices[0].priority), (8388604, 1048576, Some(-2)));
+        assert_eq!(devices[1].name, "/swap file");
+        assert_eq!(devices[1].kind, "file");
+        assert_eq!(devices[2].priority, Some(100));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn parse_mm_stat_converts_bytes_to_kb() {
+        // orig_data_size compr_data_size mem_used_total mem_limit mem_used_max same_pages pages_compacted huge_pages
+        let zram = parse_mm_stat("zram0", "  419430400  104857600  109051904        0  115343360     1204       12        0\n").unwrap();
+        assert_eq!(zram.name, "zram0");
+        assert_eq!((zram.original_kb, zram.compressed_kb, zram.ram_used_kb), (Some(409600), 102400, Some(106496)));
+        assert_eq!(zram.ratio(), Some(4.0));
+        assert!(parse_mm_stat("zram1", "123 456").is_none());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn parse_zswap_reads_meminfo_fields() {
+        let zswap = parse_zswap("MemTotal:       16314204 kB\nZswap:            51200 kB\nZswapped:        153600 kB\n").unwrap();
+        assert_eq!((zswap.original_kb, zswap.compressed_kb), (Some(153600), 51200));
+        assert_eq!(zswap.ratio(), Some(3.0));
+        // Kernel < 5.19 không có dòng Zswap
+        assert!(parse_zswap("MemTotal:       16314204 kB\n").is_none());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn swap_rates_use_the_page_size() {
+        let start = Instant::now();
+        let mut counters = RateCounters::with_page_kb(64);
+        // Lần đầu chưa có mốc
+        assert_eq!(counters.record(start, 100, 200), (None, None));
+        assert_eq!(counters.record(start + Duration::from_secs(2), 110, 200), (Some(320.0), Some(0.0)));
+        // Counter giảm (VD: swapoff/đọc lỗi) không cho ra tốc độ âm
+        assert_eq!(counters.record(start + Duration::from_secs(3), 50, 210), (Some(0.0), Some(640.0)));
+    }
+}
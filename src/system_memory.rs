@@ -0,0 +1,221 @@
+// Phân tích RAM toàn hệ thống chi tiết hơn total/used/available của SystemInfo.
+// Trường nào hệ điều hành không cung cấp thì để None và GUI bỏ qua dòng đó
+#[derive(Clone, Debug, Default)]
+pub struct SystemMemoryBreakdown {
+    pub total_kb: u64,
+    pub free_kb: u64,
+    pub available_kb: u64,
+    pub page_cache_kb: u64,
+    pub buffers_kb: Option<u64>,
+    pub shmem_kb: Option<u64>,
+    // Windows: paged pool được xem như reclaimable, nonpaged pool là unreclaimable
+    pub slab_reclaimable_kb: Option<u64>,
+    pub slab_unreclaimable_kb: Option<u64>,
+    pub anon_kb: Option<u64>,
+    pub swap_total_kb: u64,
+    pub swap_free_kb: u64,
+    pub dirty_kb: Option<u64>,
+    pub writeback_kb: Option<u64>,
+    pub hugepages: Option<HugePages>,
+    pub committed_kb: u64,
+    pub commit_limit_kb: u64,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct HugePages {
+    pub total: u64,
+    pub free: u64,
+    pub page_size_kb: u64,
+}
+
+impl SystemMemoryBreakdown {
+    pub fn read() -> Result<SystemMemoryBreakdown, String> {
+        platform::read()
+    }
+
+    pub fn swap_used_kb(&self) -> u64 {
+        self.swap_total_kb.saturating_sub(self.swap_free_kb)
+    }
+
+    // Tỉ lệ commit / commit limit, > 1.0 nghĩa là đã overcommit
+    pub fn commit_ratio(&self) -> f64 {
+        if self.commit_limit_kb == 0 {
+            return 0.0;
+        }
+        self.committed_kb as f64 / self.commit_limit_kb as f64
+    }
+}
+
+// Các counter tích luỹ trong /proc/vmstat (pswpin, pgscan_kswapd, pgmajfault...), dạng `tên giá_trị`
+#[cfg(target_os = "linux")]
+pub fn read_vmstat() -> Option<std::collections::HashMap<String, u64>> {
+    let text = std::fs::read_to_string("/proc/vmstat").ok()?;
+    Some(
+        text.lines()
+            .filter_map(|line| {
+                let (name, value) = line.split_once(' ')?;
+                Some((name.to_string(), value.trim().parse().ok()?))
+            })
+            .collect(),
+    )
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::collections::HashMap;
+    use std::fs;
+    use super::{HugePages, SystemMemoryBreakdown};
+    use crate::i18n::t;
+
+    const MEMINFO: &str = "/proc/meminfo";
+
+    pub fn read() -> Result<SystemMemoryBreakdown, String> {
+        let text = fs::read_to_string(MEMINFO)
+            .map_err(|e| t!("err-read-file", path = MEMINFO, error = e.to_string()))?;
+        Ok(parse_meminfo(&text))
+    }
+
+    // Dòng dạng `Key:   value kB`, riêng HugePages_* là số page không có đơn vị
+    pub fn parse_meminfo(text: &str) -> SystemMemoryBreakdown {
+        let values: HashMap<&str, u64> = text
+            .lines()
+            .filter_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                let value = value.trim().trim_end_matches("kB").trim().parse().ok()?;
+                Some((key, value))
+            })
+            .collect();
+        let get = |key: &str| values.get(key).copied();
+        let value = |key: &str| get(key).unwrap_or(0);
+
+        SystemMemoryBreakdown {
+            total_kb: value("MemTotal"),
+            free_kb: value("MemFree"),
+            available_kb: value("MemAvailable"),
+            page_cache_kb: value("Cached"),
+            buffers_kb: get("Buffers"),
+            shmem_kb: get("Shmem"),
+            slab_reclaimable_kb: get("SReclaimable"),
+            slab_unreclaimable_kb: get("SUnreclaim"),
+            anon_kb: get("AnonPages"),
+            swap_total_kb: value("SwapTotal"),
+            swap_free_kb: value("SwapFree"),
+            dirty_kb: get("Dirty"),
+            writeback_kb: get("Writeback"),
+            hugepages: get("HugePages_Total").filter(|&total| total > 0).map(|total| HugePages {
+                total,
+                free: value("HugePages_Free"),
+                page_size_kb: value("Hugepagesize"),
+            }),
+            committed_kb: value("Committed_AS"),
+            commit_limit_kb: value("CommitLimit"),
+        }
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::mem;
+    use windows::Win32::System::ProcessStatus::{GetPerformanceInfo, PERFORMANCE_INFORMATION};
+    use super::SystemMemoryBreakdown;
+    use crate::i18n::t;
+
+    pub fn read() -> Result<SystemMemoryBreakdown, String> {
+        let mut info = PERFORMANCE_INFORMATION {
+            cb: mem::size_of::<PERFORMANCE_INFORMATION>() as u32,
+            ..Default::default()
+        };
+        unsafe {
+            GetPerformanceInfo(&mut info, info.cb)
+                .map_err(|e| t!("err-performance-info", error = format!("{:?}", e)))?;
+        }
+
+        // Các giá trị của PERFORMANCE_INFORMATION tính theo page
+        let kb = |pages: usize| pages as u64 * info.PageSize as u64 / 1024;
+        // Page file = commit limit trừ RAM vật lý, phần commit vượt RAM được xem là đang nằm trong page file
+        let swap_total_kb = kb(info.CommitLimit.saturating_sub(info.PhysicalTotal));
+        let swap_used_kb = kb(info.CommitTotal.saturating_sub(info.PhysicalTotal)).min(swap_total_kb);
+
+        Ok(SystemMemoryBreakdown {
+            total_kb: kb(info.PhysicalTotal),
+            free_kb: kb(info.PhysicalAvailable),
+            available_kb: kb(info.PhysicalAvailable),
+            page_cache_kb: kb(info.SystemCache),
+            buffers_kb: None,
+            shmem_kb: None,
+            slab_reclaimable_kb: Some(kb(info.KernelPaged)),
+            slab_unreclaimable_kb: Some(kb(info.KernelNonpaged)),
+            anon_kb: None,
+            swap_total_kb,
+            swap_free_kb: swap_total_kb - swap_used_kb,
+            dirty_kb: None,
+            writeback_kb: None,
+            hugepages: None,
+            committed_kb: kb(info.CommitTotal),
+            commit_limit_kb: kb(info.CommitLimit),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn parse_meminfo_fills_the_breakdown() {
+        use super::platform::parse_meminfo;
+
+        // /proc/meminfo thật của một máy 16 GB, bỏ bớt các dòng không dùng
+        let meminfo = "\
+MemTotal:       16314204 kB
+MemFree:         1203412 kB
+MemAvailable:    9124508 kB
+Buffers:          412356 kB
+Cached:          7512004 kB
+SwapCached:         1024 kB
+Active:          6211420 kB
+Dirty:              1816 kB
+Writeback:             0 kB
+AnonPages:       5830112 kB
+Shmem:            702380 kB
+SReclaimable:     590212 kB
+SUnreclaim:       201876 kB
+SwapTotal:       8388604 kB
+SwapFree:        6291452 kB
+CommitLimit:    16545704 kB
+Committed_AS:   19851236 kB
+HugePages_Total:       4
+HugePages_Free:        1
+Hugepagesize:       2048 kB
+";
+        let breakdown = parse_meminfo(meminfo);
+        assert_eq!(breakdown.total_kb, 16314204);
+        assert_eq!(breakdown.free_kb, 1203412);
+        assert_eq!(breakdown.available_kb, 9124508);
+        assert_eq!(breakdown.page_cache_kb, 7512004);
+        assert_eq!(breakdown.buffers_kb, Some(412356));
+        assert_eq!(breakdown.shmem_kb, Some(702380));
+        assert_eq!(breakdown.slab_reclaimable_kb, Some(590212));
+        assert_eq!(breakdown.slab_unreclaimable_kb, Some(201876));
+        assert_eq!(breakdown.anon_kb, Some(5830112));
+        assert_eq!((breakdown.dirty_kb, breakdown.writeback_kb), (Some(1816), Some(0)));
+        assert_eq!(breakdown.swap_used_kb(), 2097152);
+        assert_eq!((breakdown.committed_kb, breakdown.commit_limit_kb), (19851236, 16545704));
+        assert!(breakdown.commit_ratio() > 1.0);
+
+        let hugepages = breakdown.hugepages.expect("HugePages_Total > 0");
+        assert_eq!((hugepages.total, hugepages.free, hugepages.page_size_kb), (4, 1, 2048));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn missing_meminfo_fields_stay_unknown() {
+        use super::platform::parse_meminfo;
+
+        // Kernel cũ / container có thể thiếu dòng; HugePages_Total = 0 thì không hiện
+        let breakdown = parse_meminfo("MemTotal: 1024 kB\nHugePages_Total: 0\nbroken line\n");
+        assert_eq!(breakdown.total_kb, 1024);
+        assert_eq!((breakdown.buffers_kb, breakdown.shmem_kb, breakdown.anon_kb), (None, None, None));
+        assert!(breakdown.hugepages.is_none());
+        assert_eq!(breakdown.commit_ratio(), 0.0);
+    }
+}
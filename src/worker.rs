@@ -0,0 +1,452 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+use eframe::egui;
+use crate::batch::{BatchJob, BatchResult};
+use crate::alerts::{AlertEvent, AlertMonitor};
+use crate::api::{self, ApiRequest};
+use crate::cgroup_tree::{self, CgroupNode, CgroupOriginals};
+use crate::early_oom::{EarlyOom, EarlyOomConfig, EarlyOomEvent};
+use crate::config::Config;
+use crate::effect::{self, EffectReport};
+use crate::freeze::FrozenProcesses;
+use crate::helper::{HelperClient, HelperStatus};
+use crate::i18n::t;
+use crate::launch::{LaunchExit, LaunchRequest};
+use crate::memory_map::MemoryMap;
+use crate::process_group::{GroupActionReport, ProcessGroup};
+use crate::profile::{Profile, ProfileReport, ProfileSession};
+use crate::ram_manager::{ProcessAction, ProcessInfo, RamManager, RamStatistics, SystemInfo};
+use crate::oom::OomConfig;
+use crate::pageout::{PageoutConfig, PageoutReport};
+use crate::permissions::Permissions;
+use crate::privileged::Executor;
+use crate::page_faults::{SystemFaultMonitor, SystemFaultRates};
+use crate::swap::{SwapMonitor, SwapStats};
+use crate::system_memory::SystemMemoryBreakdown;
+
+// Lệnh GUI gửi sang worker thread
+pub enum WorkerCommand {
+    Refresh,
+    SetAutoRefresh(bool),
+    SetSampleInterval(Duration),
+    // PID đang hiện/đang chọn trên GUI, xem RamManager::set_watched
+    SetWatched(HashSet<u32>),
+    Apply { pids: Vec<u32>, action: ProcessAction },
+    ApplyGroup { group: ProcessGroup, action: ProcessAction },
+    Batch(BatchJob),
+    ApplyProfile(Profile),
+    RevertProfile,
+    InspectMemory(u32),
+    ScanCgroups,
+    ApplyCgroup { path: PathBuf, action: ProcessAction },
+    SetPageout(PageoutConfig),
+    SetOom(OomConfig),
+    SetEarlyOom(EarlyOomConfig),
+    Launch(LaunchRequest),
+    // Gửi từ thread chờ chương trình đã khởi chạy, không phải từ GUI
+    LaunchExited(LaunchExit),
+    // Gửi từ thread của API, kết quả trả qua reply thay vì WorkerEvent
+    Api { request: ApiRequest, reply: Sender<Result<serde_json::Value, String>> },
+    Shutdown,
+}
+
+impl WorkerCommand {
+    // Lệnh đổi trạng thái tiến trình thì gửi snapshot mới ngay. Lệnh cấu hình và tập PID theo dõi
+    // (GUI gửi ở mỗi khung hình cuộn, mỗi lần kéo DragValue) chỉ có hiệu lực từ lần sample kế tiếp
+    fn changes_processes(&self) -> bool {
+        !matches!(
+            self,
+            WorkerCommand::SetAutoRefresh(_)
+                | WorkerCommand::SetSampleInterval(_)
+                | WorkerCommand::SetWatched(_)
+                | WorkerCommand::InspectMemory(_)
+                | WorkerCommand::ScanCgroups
+                | WorkerCommand::SetPageout(_)
+                | WorkerCommand::SetOom(_)
+                | WorkerCommand::SetEarlyOom(_)
+                | WorkerCommand::Api { request: ApiRequest::EffectReports, .. }
+        )
+    }
+}
+
+// Kết quả worker gửi ngược lại GUI
+pub enum WorkerEvent {
+    Snapshot(Box<Snapshot>),
+    ActionDone(Result<String, String>),
+    GroupDone(GroupActionReport),
+    BatchProgress(BatchResult),
+    ProfileDone(Result<ProfileReport, String>),
+    MemoryMap(Result<MemoryMap, String>),
+    Cgroups(Result<CgroupNode, String>),
+    Effects(Vec<EffectReport>),
+    Pageout(PageoutReport),
+    LaunchExited(LaunchExit),
+}
+
+#[derive(Clone)]
+pub struct Snapshot {
+    pub processes: Vec<ProcessInfo>,
+    pub system_info: SystemInfo,
+    pub memory_breakdown: Result<SystemMemoryBreakdown, String>,
+    pub swap: Result<SwapStats, String>,
+    pub fault_rates: SystemFaultRates,
+    pub statistics: RamStatistics,
+    pub active_profile: Option<String>,
+    pub profile_history: Vec<ProfileReport>,
+    pub alert_history: Vec<AlertEvent>,
+    pub early_oom_history: Vec<EarlyOomEvent>,
+    pub helper: HelperStatus,
+    pub permissions: Permissions,
+}
+
+// RamManager thuộc về worker thread, GUI chỉ nhận snapshot và kết quả qua channel
+// nên các thao tác chậm (refresh, trim chờ 300 ms) không làm đứng cửa sổ
+pub struct Worker {
+    commands: Sender<WorkerCommand>,
+    events: Receiver<WorkerEvent>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl Worker {
+    pub fn spawn(ctx: egui::Context, config: Config) -> Self {
+        let (cmd_tx, cmd_rx) = mpsc::channel();
+        let (event_tx, event_rx) = mpsc::channel();
+        let own_tx = cmd_tx.clone();
+
+        let thread = thread::Builder::new()
+            .name("ram-manager-worker".to_string())
+            .spawn(move || run(cmd_rx, own_tx, event_tx, ctx, config))
+            .expect("Không thể khởi tạo worker thread");
+
+        Worker {
+            commands: cmd_tx,
+            events: event_rx,
+            thread: Some(thread),
+        }
+    }
+
+    pub fn send(&self, command: WorkerCommand) {
+        let _ = self.commands.send(command);
+    }
+
+    pub fn poll(&self) -> Vec<WorkerEvent> {
+        self.events.try_iter().collect()
+    }
+}
+
+// Chờ worker rã đông các tiến trình còn bị đóng băng trước khi chương trình thoát
+impl Drop for Worker {
+    fn drop(&mut self) {
+        let _ = self.commands.send(WorkerCommand::Shutdown);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+// own_commands: cho các thread phụ (chờ chương trình đã khởi chạy, API) gửi lệnh về chính worker
+fn run(
+    commands: Receiver<WorkerCommand>,
+    own_commands: Sender<WorkerCommand>,
+    events: Sender<WorkerEvent>,
+    ctx: egui::Context,
+    config: Config,
+) {
+    let mut manager = RamManager::new();
+    let mut monitors = Monitors {
+        swap: SwapMonitor::new(),
+        faults: SystemFaultMonitor::new(),
+        alerts: AlertMonitor::new(config.alerts),
+        early_oom: EarlyOom::new(config.early_oom),
+    };
+    let session_path = ProfileSession::path();
+    let mut startup_errors = Vec::new();
+    match ProfileSession::load(&session_path) {
+        Ok(session) => manager.set_active_profile(session),
+        Err(e) => startup_errors.push(e),
+    }
+    match CgroupOriginals::load(&CgroupOriginals::path()) {
+        Ok(originals) => manager.set_cgroup_originals(originals),
+        Err(e) => startup_errors.push(e),
+    }
+    if let Err(e) = FrozenProcesses::load(&FrozenProcesses::path()).and_then(|frozen| manager.set_frozen(frozen)) {
+        startup_errors.push(e);
+    }
+    if config.helper.enabled {
+        match HelperClient::new(&config.helper) {
+            Ok(client) => manager.set_executor(Executor::Helper(client)),
+            Err(e) => startup_errors.push(e),
+        }
+    }
+    if config.api.enabled {
+        if let Err(e) = api::serve(&config.api, own_commands.clone()) {
+            startup_errors.push(e);
+        }
+    }
+    manager.set_full_refresh_interval(config.sampling.full_refresh_interval());
+    manager.set_effect_window(config.effects.window(), config.effects.sample_interval());
+    manager.set_pageout(config.pageout);
+    manager.set_oom(config.oom);
+    // API áp dụng profile theo tên như khi GUI gửi ApplyProfile
+    let profiles = config.profiles;
+    let mut refresh_interval = config.sampling.refresh_interval();
+    let mut auto_refresh = config.sampling.auto_refresh;
+    let mut next_sample = Instant::now();
+
+    // Trả về false khi GUI đã đóng
+    let emit = |event: WorkerEvent| -> bool {
+        let sent = events.send(event).is_ok();
+        ctx.request_repaint();
+        sent
+    };
+
+    for e in startup_errors {
+        emit(WorkerEvent::ActionDone(Err(e)));
+    }
+
+    loop {
+        if auto_refresh && Instant::now() >= next_sample {
+            if !emit(WorkerEvent::Snapshot(Box::new(take_snapshot(&mut manager, &mut monitors)))) {
+                break;
+            }
+            next_sample = Instant::now() + refresh_interval;
+        }
+
+        // Early-OOM cũng có nhịp riêng: phải phản ứng kịp cả khi auto refresh đang tắt
+        if monitors.early_oom.next_poll().is_some_and(|due| Instant::now() >= due)
+            && run_early_oom(&mut manager, &mut monitors)
+            && !emit(WorkerEvent::Snapshot(Box::new(take_snapshot(&mut manager, &mut monitors))))
+        {
+            break;
+        }
+
+        // Đo hiệu quả hành động theo nhịp riêng, không phụ thuộc auto refresh
+        if manager.next_effect_sample().is_some_and(|due| Instant::now() >= due) {
+            manager.sample_effects();
+            if !emit(WorkerEvent::Effects(manager.effect_reports())) {
+                break;
+            }
+        }
+
+        let mut wake = if auto_refresh {
+            next_sample
+        } else {
+            Instant::now() + Duration::from_secs(3600)
+        };
+        if let Some(due) = manager.next_effect_sample() {
+            wake = wake.min(due);
+        }
+        if let Some(due) = monitors.early_oom.next_poll() {
+            wake = wake.min(due);
+        }
+        let timeout = wake.saturating_duration_since(Instant::now());
+
+        let command = match commands.recv_timeout(timeout) {
+            Ok(command) => command,
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => break,
+        };
+
+        let changes_processes = command.changes_processes();
+        let alive = match command {
+            WorkerCommand::Refresh => {
+                manager.request_full_refresh();
+                true
+            }
+            WorkerCommand::SetAutoRefresh(enabled) => {
+                auto_refresh = enabled;
+                true
+            }
+            WorkerCommand::SetSampleInterval(interval) => {
+                refresh_interval = interval;
+                next_sample = next_sample.min(Instant::now() + interval);
+                true
+            }
+            WorkerCommand::SetWatched(pids) => {
+                manager.set_watched(pids);
+                true
+            }
+            WorkerCommand::Apply { pids, action } => {
+                let result = match pids.as_slice() {
+                    [pid] => manager.apply_action(*pid, &action),
+                    _ => manager.apply_to_pids(&pids, &action),
+                };
+                if let Some(report) = manager.take_pageout_report() {
+                    emit(WorkerEvent::Pageout(report));
+                }
+                emit(WorkerEvent::ActionDone(result))
+            }
+            WorkerCommand::ApplyGroup { group, action } => {
+                emit(WorkerEvent::GroupDone(manager.apply_to_group(&group, &action)))
+            }
+            WorkerCommand::Batch(mut job) => {
+                let mut alive = true;
+                while alive && job.step(&mut manager) {
+                    if let Some(result) = job.results.last() {
+                        alive = emit(WorkerEvent::BatchProgress(result.clone()));
+                    }
+                }
+                alive
+            }
+            WorkerCommand::ApplyProfile(profile) => {
+                let report = manager.apply_profile(&profile);
+                let saved = ProfileSession::store(manager.active_profile(), &session_path);
+                emit(WorkerEvent::ProfileDone(saved.map(|_| report)))
+            }
+            WorkerCommand::RevertProfile => {
+                let result = match manager.revert_profile() {
+                    Some(report) => ProfileSession::store(None, &session_path).map(|_| report),
+                    None => Err(t!("err-no-active-profile")),
+                };
+                emit(WorkerEvent::ProfileDone(result))
+            }
+            WorkerCommand::InspectMemory(pid) => emit(WorkerEvent::MemoryMap(MemoryMap::read(pid))),
+            WorkerCommand::ScanCgroups => emit(WorkerEvent::Cgroups(cgroup_tree::scan())),
+            WorkerCommand::ApplyCgroup { path, action } => {
+                emit(WorkerEvent::ActionDone(manager.apply_to_cgroup(&path, &action)))
+            }
+            WorkerCommand::SetPageout(config) => {
+                manager.set_pageout(config);
+                true
+            }
+            WorkerCommand::SetOom(config) => {
+                manager.set_oom(config);
+                true
+            }
+            WorkerCommand::SetEarlyOom(config) => {
+                monitors.early_oom.set_config(config);
+                true
+            }
+            WorkerCommand::Launch(request) => {
+                let exits = own_commands.clone();
+                let result = manager.launch(&request, move |exit| {
+                    let _ = exits.send(WorkerCommand::LaunchExited(exit));
+                });
+                emit(WorkerEvent::ActionDone(result))
+            }
+            WorkerCommand::LaunchExited(exit) => {
+                manager.finish_launch(exit.pid);
+                emit(WorkerEvent::LaunchExited(exit))
+            }
+            WorkerCommand::Api { request, reply } => {
+                let result = match request {
+                    ApiRequest::EffectReports => effect::to_json(&manager.effect_reports()),
+                    ApiRequest::ApplyProfile { name } => match profiles.iter().find(|p| p.name == name) {
+                        Some(profile) => {
+                            let report = manager.apply_profile(profile);
+                            ProfileSession::store(manager.active_profile(), &session_path).and_then(|_| report.to_json())
+                        }
+                        None => Err(t!("err-profile-not-found", profile = name.as_str())),
+                    },
+                    ApiRequest::RevertProfile => match manager.revert_profile() {
+                        Some(report) => ProfileSession::store(None, &session_path).and_then(|_| report.to_json()),
+                        None => Err(t!("err-no-active-profile")),
+                    },
+                };
+                let _ = reply.send(result);
+                true
+            }
+            WorkerCommand::Shutdown => break,
+        };
+
+        if !alive {
+            break;
+        }
+        // Gửi snapshot mới ngay sau lệnh đổi trạng thái để GUI thấy kết quả
+        if changes_processes {
+            if !emit(WorkerEvent::Snapshot(Box::new(take_snapshot(&mut manager, &mut monitors)))) {
+                break;
+            }
+            next_sample = Instant::now() + refresh_interval;
+        }
+    }
+
+    // GUI đã đóng nên không còn nơi hiện lỗi; PID rã đông lỗi vẫn được lưu lại cho lần chạy sau
+    let _ = manager.thaw_all();
+}
+
+// Các bộ đếm cần giữ mốc giữa hai lần sample để tính tốc độ
+struct Monitors {
+    swap: SwapMonitor,
+    faults: SystemFaultMonitor,
+    alerts: AlertMonitor,
+    early_oom: EarlyOom,
+}
+
+// Trả về true khi đã kết thúc (hoặc cố kết thúc) một tiến trình
+fn run_early_oom(manager: &mut RamManager, monitors: &mut Monitors) -> bool {
+    let Some((pressure, forced)) = monitors.early_oom.check() else {
+        return false;
+    };
+    // Quét lại toàn bộ để không bỏ sót tiến trình vừa mới sinh ra và đang ngốn RAM
+    manager.request_full_refresh();
+    let processes = manager.list_processes();
+    let Some((victim, badness)) = monitors.early_oom.select_victim(&processes) else {
+        return false;
+    };
+    let event = monitors.early_oom.record(EarlyOomEvent {
+        at: SystemTime::now(),
+        pid: victim.pid,
+        name: victim.name.clone(),
+        badness,
+        pressure,
+        forced,
+        result: manager.terminate_process(victim.pid, forced),
+        log_error: None,
+    });
+    let threshold = monitors.early_oom.config().available_percent;
+    monitors
+        .alerts
+        .notify("early-oom", event.message(), Some(victim), pressure.available_percent, threshold);
+    true
+}
+
+fn take_snapshot(manager: &mut RamManager, monitors: &mut Monitors) -> Snapshot {
+    manager.refresh_permissions();
+    let processes = manager.list_processes();
+    let swap = monitors.swap.sample(&processes);
+    let system_info = manager.get_system_info();
+    monitors.alerts.evaluate(&processes, &system_info);
+    Snapshot {
+        processes,
+        system_info,
+        memory_breakdown: SystemMemoryBreakdown::read(),
+        swap,
+        fault_rates: monitors.faults.sample(),
+        statistics: manager.get_statistics(),
+        active_profile: manager.active_profile().map(|s| s.profile_name.clone()),
+        profile_history: manager.profile_history().to_vec(),
+        alert_history: monitors.alerts.history().to_vec(),
+        early_oom_history: monitors.early_oom.history().to_vec(),
+        helper: manager.helper_status(),
+        permissions: manager.permissions(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::sync::mpsc;
+    use std::time::Duration;
+    use crate::api::ApiRequest;
+    use crate::oom::OomConfig;
+    use crate::ram_manager::ProcessAction;
+    use super::WorkerCommand;
+
+    #[test]
+    fn only_commands_that_change_processes_trigger_a_snapshot() {
+        assert!(!WorkerCommand::SetWatched(HashSet::from([1, 2])).changes_processes());
+        assert!(!WorkerCommand::SetSampleInterval(Duration::from_secs(1)).changes_processes());
+        assert!(!WorkerCommand::SetOom(OomConfig::default()).changes_processes());
+        let (reply, _) = mpsc::channel();
+        assert!(!WorkerCommand::Api { request: ApiRequest::EffectReports, reply }.changes_processes());
+
+        assert!(WorkerCommand::Refresh.changes_processes());
+        assert!(WorkerCommand::Apply { pids: vec![42], action: ProcessAction::Trim }.changes_processes());
+        let (reply, _) = mpsc::channel();
+        assert!(WorkerCommand::Api { request: ApiRequest::RevertProfile, reply }.changes_processes());
+    }
+}